@@ -1,6 +1,6 @@
 use std::hash::Hasher;
 
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use rand::{thread_rng, RngCore};
 
 fn fnv(data: &[u8]) -> u64 {
@@ -53,5 +53,96 @@ fn all_benches(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, all_benches);
+/// Writes `bytes` through [`Hasher::write`], which is how [`CityHasher`]
+/// hashed integers before it specialized the integer write functions.
+///
+/// [`CityHasher`]: cityhasher::CityHasher
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = cityhasher::CityHasher::new();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+fn hasher_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hasher");
+    let mut rng = thread_rng();
+    let value = u128::from(rng.next_u64()) << 64 | u128::from(rng.next_u64());
+
+    group.bench_function(BenchmarkId::new("bytes", "u32"), |b| {
+        b.iter(|| hash_bytes(&black_box(value as u32).to_ne_bytes()))
+    });
+    group.bench_function(BenchmarkId::new("integer", "u32"), |b| {
+        b.iter(|| {
+            let mut hasher = cityhasher::CityHasher::new();
+            hasher.write_u32(black_box(value as u32));
+            hasher.finish()
+        })
+    });
+    group.bench_function(BenchmarkId::new("bytes", "u64"), |b| {
+        b.iter(|| hash_bytes(&black_box(value as u64).to_ne_bytes()))
+    });
+    group.bench_function(BenchmarkId::new("integer", "u64"), |b| {
+        b.iter(|| {
+            let mut hasher = cityhasher::CityHasher::new();
+            hasher.write_u64(black_box(value as u64));
+            hasher.finish()
+        })
+    });
+    group.bench_function(BenchmarkId::new("bytes", "u128"), |b| {
+        b.iter(|| hash_bytes(&black_box(value).to_ne_bytes()))
+    });
+    group.bench_function(BenchmarkId::new("integer", "u128"), |b| {
+        b.iter(|| {
+            let mut hasher = cityhasher::CityHasher::new();
+            hasher.write_u128(black_box(value));
+            hasher.finish()
+        })
+    });
+    group.bench_function(BenchmarkId::new("bytes", "(u32, u32)"), |b| {
+        b.iter(|| {
+            let mut hasher = cityhasher::CityHasher::new();
+            hasher.write(&black_box(value as u32).to_ne_bytes());
+            hasher.write(&black_box((value >> 32) as u32).to_ne_bytes());
+            hasher.finish()
+        })
+    });
+    group.bench_function(BenchmarkId::new("integer", "(u32, u32)"), |b| {
+        b.iter(|| {
+            let mut hasher = cityhasher::CityHasher::new();
+            hasher.write_u32(black_box(value as u32));
+            hasher.write_u32(black_box((value >> 32) as u32));
+            hasher.finish()
+        })
+    });
+}
+
+fn batch_benches(c: &mut Criterion) {
+    const KEYS: usize = 1024;
+
+    let mut group = c.benchmark_group("batch");
+    group.throughput(Throughput::Elements(KEYS as u64));
+    let mut rng = thread_rng();
+    let mut hashes = vec![0_u64; KEYS];
+    for size in [8, 16, 24, 48, 96] {
+        let mut data = vec![0; size * KEYS];
+        rng.fill_bytes(&mut data);
+        let keys = data.chunks_exact(size).collect::<Vec<_>>();
+
+        group.bench_function(BenchmarkId::new("loop", size), |b| {
+            b.iter(|| {
+                for (key, hash) in black_box(&keys).iter().zip(&mut hashes) {
+                    *hash = cityhasher::hash(key);
+                }
+            })
+        });
+        group.bench_function(BenchmarkId::new("hash_batch", size), |b| {
+            b.iter(|| cityhasher::hash_batch(black_box(&keys), &mut hashes))
+        });
+        group.bench_function(BenchmarkId::new("hash_fixed_stride", size), |b| {
+            b.iter(|| cityhasher::hash_fixed_stride(black_box(&data), size, &mut hashes))
+        });
+    }
+}
+
+criterion_group!(benches, all_benches, hasher_benches, batch_benches);
 criterion_main!(benches);
@@ -0,0 +1,432 @@
+//! `cityhash`, a command-line tool that computes and verifies CityHash
+//! checksums in the style of `sha256sum`.
+
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use cityhasher::Fingerprint128;
+use clap::{Parser, ValueEnum};
+use serde::{Deserialize, Serialize};
+
+/// Computes and verifies CityHash checksums.
+///
+/// With no FILE, or when FILE is -, standard input is read.
+#[derive(Parser, Debug)]
+#[command(version)]
+struct Args {
+    /// The files to hash, or the checksum lists to verify with `--check`.
+    files: Vec<String>,
+    /// The CityHash function to compute.
+    #[arg(short, long, value_enum, default_value_t = Variant::Hash64)]
+    variant: Variant,
+    /// The seed used by the `64-seeded` variant, in decimal or as hexadecimal
+    /// prefixed with `0x`.
+    #[arg(short, long, value_parser = parse_seed)]
+    seed: Option<u64>,
+    /// The format to print checksums in, or to read them in with `--check`.
+    #[arg(short, long, value_enum, default_value_t = Format::Hex)]
+    format: Format,
+    /// Hashes each line of the input separately. Line terminators are not
+    /// included in the hashed bytes.
+    #[arg(short, long, conflicts_with = "check")]
+    lines: bool,
+    /// Reads checksums from the files and verifies them.
+    #[arg(short, long)]
+    check: bool,
+}
+
+/// A CityHash function.
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+enum Variant {
+    /// `CityHash32`.
+    #[value(name = "32")]
+    #[serde(rename = "32")]
+    Hash32,
+    /// `CityHash64`.
+    #[value(name = "64")]
+    #[serde(rename = "64")]
+    Hash64,
+    /// `CityHash64WithSeed`.
+    #[value(name = "64-seeded")]
+    #[serde(rename = "64-seeded")]
+    Hash64Seeded,
+    /// `CityHash128`.
+    #[value(name = "128")]
+    #[serde(rename = "128")]
+    Hash128,
+}
+
+/// A format for printing checksums.
+#[derive(ValueEnum, Clone, Copy, Debug, Eq, PartialEq)]
+enum Format {
+    /// Zero-padded lowercase hexadecimal. 128-bit checksums are printed as
+    /// their low 64 bits followed by their high 64 bits, like the reference
+    /// implementation's tools.
+    Hex,
+    /// Decimal.
+    Decimal,
+    /// One JSON object per line.
+    Json,
+}
+
+/// The `k2` constant of the CityHash algorithm.
+const K2: u64 = 0x9ae1_6a3b_2f90_404f;
+
+/// A hash function with its parameters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Hasher {
+    variant: Variant,
+    seed: Option<u64>,
+}
+
+impl Hasher {
+    fn new(variant: Variant, seed: Option<u64>) -> Result<Self, String> {
+        match (variant, seed) {
+            (Variant::Hash64Seeded, None) => {
+                Err(String::from("the 64-seeded variant requires --seed"))
+            }
+            (Variant::Hash64Seeded, Some(_)) | (_, None) => Ok(Self { variant, seed }),
+            (_, Some(_)) => Err(String::from(
+                "--seed can only be used with the 64-seeded variant",
+            )),
+        }
+    }
+
+    fn hash(&self, data: &[u8]) -> Digest {
+        let value = match self.variant {
+            Variant::Hash32 => u128::from(cityhasher::hash::<u32>(data)),
+            Variant::Hash64 => u128::from(cityhasher::hash::<u64>(data)),
+            Variant::Hash64Seeded => u128::from(cityhasher::hash_with_seed::<u64>(
+                data,
+                self.seed.unwrap_or_default(),
+            )),
+            Variant::Hash128 => cityhasher::hash::<u128>(data),
+        };
+        Digest {
+            value,
+            variant: self.variant,
+        }
+    }
+
+    /// Hashes the contents of `file`, or of standard input if `file` is `-`.
+    ///
+    /// Files are hashed by [`cityhasher::fs`], which hashes large files in
+    /// fixed-size blocks for the 64-bit variants rather than reading them into
+    /// memory. Standard input is read into memory.
+    fn hash_file(&self, file: &str) -> io::Result<Digest> {
+        if file == "-" {
+            let mut data = Vec::new();
+            io::stdin().lock().read_to_end(&mut data)?;
+            return Ok(self.hash(&data));
+        }
+
+        let value = match self.variant {
+            Variant::Hash32 => u128::from(cityhasher::fs::hash_file::<u32>(file)?),
+            Variant::Hash64 => u128::from(cityhasher::fs::hash_file::<u64>(file)?),
+            Variant::Hash64Seeded => {
+                // `CityHash64WithSeed` is `Hash128to64` of the unseeded hash,
+                // less k2, and the seed.
+                let hash = cityhasher::fs::hash_file::<u64>(file)?.wrapping_sub(K2);
+                let seed = self.seed.unwrap_or_default();
+                u128::from(cityhasher::hash128_to_64(
+                    u128::from(hash) | u128::from(seed) << 64,
+                ))
+            }
+            Variant::Hash128 => cityhasher::fs::hash_file::<u128>(file)?,
+        };
+        Ok(Digest {
+            value,
+            variant: self.variant,
+        })
+    }
+
+    /// Parses a checksum printed in `format` by this hasher.
+    fn parse_digest(&self, digest: &str, format: Format) -> Option<Digest> {
+        let digest = Digest {
+            value: match format {
+                Format::Hex | Format::Json => {
+                    if digest.len() != self.variant.hex_width() {
+                        return None;
+                    }
+                    match self.variant {
+                        Variant::Hash128 => digest.parse::<Fingerprint128>().ok()?.get(),
+                        _ => u128::from_str_radix(digest, 16).ok()?,
+                    }
+                }
+                Format::Decimal => digest.parse().ok()?,
+            },
+            variant: self.variant,
+        };
+        let fits = digest.value.checked_shr(self.variant.bits()).unwrap_or(0) == 0;
+        fits.then_some(digest)
+    }
+}
+
+impl Variant {
+    const fn bits(self) -> u32 {
+        match self {
+            Self::Hash32 => 32,
+            Self::Hash64 | Self::Hash64Seeded => 64,
+            Self::Hash128 => 128,
+        }
+    }
+
+    const fn hex_width(self) -> usize {
+        self.bits() as usize / 4
+    }
+}
+
+/// The result of a hash function.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Digest {
+    value: u128,
+    variant: Variant,
+}
+
+impl Digest {
+    fn display(self, format: Format) -> impl Display {
+        struct Formatted(Digest, Format);
+
+        impl Display for Formatted {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self.1 {
+                    Format::Hex | Format::Json => match self.0.variant {
+                        Variant::Hash128 => Fingerprint128::new(self.0.value).fmt(f),
+                        variant => {
+                            let width = variant.hex_width();
+                            write!(f, "{:0width$x}", self.0.value)
+                        }
+                    },
+                    Format::Decimal => write!(f, "{}", self.0.value),
+                }
+            }
+        }
+
+        Formatted(self, format)
+    }
+}
+
+/// A checksum printed in the JSON format.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct Record {
+    variant: Variant,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+    file: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    line: Option<u64>,
+    hash: String,
+}
+
+fn main() -> ExitCode {
+    let mut args = Args::parse();
+    if args.files.is_empty() {
+        args.files.push(String::from("-"));
+    }
+
+    let hasher = match Hasher::new(args.variant, args.seed) {
+        Ok(hasher) => hasher,
+        Err(err) => {
+            eprintln!("cityhash: {err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let result = if args.check {
+        check(&args, hasher, &mut out)
+    } else {
+        hash(&args, hasher, &mut out)
+    };
+    match result.and_then(|succeeded| out.flush().map(|()| succeeded)) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("cityhash: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints the checksums of each file in `args`. Returns false if any file
+/// could not be read.
+fn hash(args: &Args, hasher: Hasher, out: &mut impl Write) -> io::Result<bool> {
+    let mut succeeded = true;
+    for file in &args.files {
+        let result = if args.lines {
+            hash_lines(args, hasher, file, out)?
+        } else {
+            match hasher.hash_file(file) {
+                Ok(digest) => {
+                    print(args, hasher, file, None, digest, out)?;
+                    Ok(())
+                }
+                Err(err) => Err(err),
+            }
+        };
+        if let Err(err) = result {
+            eprintln!("cityhash: {file}: {err}");
+            succeeded = false;
+        }
+    }
+    Ok(succeeded)
+}
+
+/// Prints the checksum of each line of `file`. Errors writing to `out` are
+/// returned in the outer result, and errors reading `file` in the inner one.
+fn hash_lines(
+    args: &Args,
+    hasher: Hasher,
+    file: &str,
+    out: &mut impl Write,
+) -> io::Result<io::Result<()>> {
+    let mut input = match open(file) {
+        Ok(input) => input,
+        Err(err) => return Ok(Err(err)),
+    };
+    let mut line = Vec::new();
+    let mut number = 0;
+    loop {
+        match input.read_until(b'\n', &mut line) {
+            Ok(0) => return Ok(Ok(())),
+            Ok(_) => {}
+            Err(err) => return Ok(Err(err)),
+        }
+        number += 1;
+        if line.last() == Some(&b'\n') {
+            line.pop();
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+        }
+        print(args, hasher, file, Some(number), hasher.hash(&line), out)?;
+        line.clear();
+    }
+}
+
+fn print(
+    args: &Args,
+    hasher: Hasher,
+    file: &str,
+    line: Option<u64>,
+    digest: Digest,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    match (args.format, line) {
+        (Format::Json, _) => {
+            let record = Record {
+                variant: hasher.variant,
+                seed: hasher.seed,
+                file: file.to_string(),
+                line,
+                hash: digest.display(Format::Json).to_string(),
+            };
+            serde_json::to_writer(&mut *out, &record)?;
+            writeln!(out)
+        }
+        (format, Some(_)) => writeln!(out, "{}", digest.display(format)),
+        (format, None) => writeln!(out, "{}  {file}", digest.display(format)),
+    }
+}
+
+/// Verifies the checksums listed in each file in `args`. Returns false if any
+/// checksum did not match or could not be verified.
+fn check(args: &Args, hasher: Hasher, out: &mut impl Write) -> io::Result<bool> {
+    let mut mismatched = 0_usize;
+    let mut unreadable = 0_usize;
+    let mut malformed = 0_usize;
+    for list in &args.files {
+        let input = match open(list) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("cityhash: {list}: {err}");
+                unreadable += 1;
+                continue;
+            }
+        };
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (expected, file, file_hasher) = match parse_checksum(args, hasher, &line) {
+                Some(checksum) => checksum,
+                None => {
+                    malformed += 1;
+                    continue;
+                }
+            };
+
+            match file_hasher.hash_file(&file) {
+                Ok(digest) if digest == expected => writeln!(out, "{file}: OK")?,
+                Ok(_) => {
+                    writeln!(out, "{file}: FAILED")?;
+                    mismatched += 1;
+                }
+                Err(err) => {
+                    eprintln!("cityhash: {file}: {err}");
+                    writeln!(out, "{file}: FAILED open or read")?;
+                    unreadable += 1;
+                }
+            }
+        }
+    }
+
+    out.flush()?;
+    warn(malformed, "line is", "lines are", "improperly formatted");
+    warn(unreadable, "file", "files", "could not be read");
+    warn(
+        mismatched,
+        "computed checksum",
+        "computed checksums",
+        "did NOT match",
+    );
+    Ok(mismatched == 0 && unreadable == 0 && malformed == 0)
+}
+
+/// Parses a line of a checksum list, returning the expected digest, the file
+/// it belongs to, and the hasher that produced it.
+fn parse_checksum(args: &Args, hasher: Hasher, line: &str) -> Option<(Digest, String, Hasher)> {
+    if args.format == Format::Json {
+        let record: Record = serde_json::from_str(line).ok()?;
+        if record.line.is_some() {
+            return None;
+        }
+        let hasher = Hasher::new(record.variant, record.seed).ok()?;
+        let digest = hasher.parse_digest(&record.hash, Format::Json)?;
+        Some((digest, record.file, hasher))
+    } else {
+        let (digest, file) = line.split_once("  ")?;
+        let digest = hasher.parse_digest(digest, args.format)?;
+        Some((digest, file.to_string(), hasher))
+    }
+}
+
+fn warn(count: usize, singular: &str, plural: &str, problem: &str) {
+    match count {
+        0 => {}
+        1 => eprintln!("cityhash: WARNING: 1 {singular} {problem}"),
+        count => eprintln!("cityhash: WARNING: {count} {plural} {problem}"),
+    }
+}
+
+fn open(file: &str) -> io::Result<Box<dyn BufRead>> {
+    if file == "-" {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(Path::new(file))?)))
+    }
+}
+
+fn parse_seed(seed: &str) -> Result<u64, String> {
+    let parsed = match seed.strip_prefix("0x").or_else(|| seed.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => seed.parse(),
+    };
+    parsed.map_err(|err| format!("invalid seed: {err}"))
+}
@@ -0,0 +1,164 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+/// A directory that is removed when dropped.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("cityhash-cli-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    fn write(&self, name: &str, contents: impl AsRef<[u8]>) {
+        std::fs::write(self.0.join(name), contents).unwrap();
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn cityhash(dir: &Path, args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_cityhash"))
+        .args(args)
+        .current_dir(dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    assert!(output.status.success(), "{output:?}");
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn variants_and_formats() {
+    let dir = TempDir::new("variants");
+    dir.write("hello.txt", "hello");
+
+    let hash32: u32 = cityhasher::hash("hello");
+    let hash64: u64 = cityhasher::hash("hello");
+    let seeded: u64 = cityhasher::hash_with_seed("hello", 42);
+    let hash128: u128 = cityhasher::hash("hello");
+    // Like the reference implementation, the low 64 bits are printed first.
+    let hex128 = format!("{:016x}{:016x}", hash128 as u64, (hash128 >> 64) as u64);
+
+    let output = cityhash(&dir.0, &["hello.txt"], b"");
+    assert_eq!(stdout(&output), format!("{hash64:016x}  hello.txt\n"));
+    let output = cityhash(&dir.0, &["-v", "32", "hello.txt"], b"");
+    assert_eq!(stdout(&output), format!("{hash32:08x}  hello.txt\n"));
+    let output = cityhash(&dir.0, &["-v", "64-seeded", "-s", "0x2a"], b"hello");
+    assert_eq!(stdout(&output), format!("{seeded:016x}  -\n"));
+    let output = cityhash(&dir.0, &["-v", "128", "hello.txt"], b"");
+    assert_eq!(stdout(&output), format!("{hex128}  hello.txt\n"));
+    let output = cityhash(&dir.0, &["-v", "128", "-f", "decimal", "-"], b"hello");
+    assert_eq!(stdout(&output), format!("{hash128}  -\n"));
+    let output = cityhash(&dir.0, &["-v", "128", "-f", "json", "hello.txt"], b"");
+    assert_eq!(
+        stdout(&output),
+        format!("{{\"variant\":\"128\",\"file\":\"hello.txt\",\"hash\":\"{hex128}\"}}\n")
+    );
+}
+
+#[test]
+fn large_files() {
+    // Large enough to be hashed in blocks rather than read into memory.
+    let dir = TempDir::new("large");
+    let data: Vec<u8> = (0..200_000_u32).map(|i| (i % 251) as u8).collect();
+    dir.write("large.bin", &data);
+
+    let hash32: u32 = cityhasher::hash(&data);
+    let hash64: u64 = cityhasher::hash(&data);
+    let seeded: u64 = cityhasher::hash_with_seed(&data, 42);
+    let hash128 = cityhasher::Fingerprint128::new(cityhasher::hash(&data));
+    for (variant, expected) in [
+        (&["-v", "32"][..], format!("{hash32:08x}")),
+        (&["-v", "64"][..], format!("{hash64:016x}")),
+        (
+            &["-v", "64-seeded", "-s", "42"][..],
+            format!("{seeded:016x}"),
+        ),
+        (&["-v", "128"][..], hash128.to_string()),
+    ] {
+        let output = cityhash(&dir.0, &[variant, &["large.bin"]].concat(), b"");
+        assert_eq!(stdout(&output), format!("{expected}  large.bin\n"));
+        dir.write("sums", &output.stdout);
+        let output = cityhash(&dir.0, &[variant, &["-c", "sums"]].concat(), b"");
+        assert_eq!(stdout(&output), "large.bin: OK\n");
+    }
+}
+
+#[test]
+fn lines() {
+    let dir = TempDir::new("lines");
+    let output = cityhash(&dir.0, &["--lines"], b"alpha\nbeta\r\n\ngamma");
+    let expected = ["alpha", "beta", "", "gamma"]
+        .map(|line| format!("{:016x}\n", cityhasher::hash::<u64>(line)))
+        .concat();
+    assert_eq!(stdout(&output), expected);
+}
+
+#[test]
+fn check() {
+    let dir = TempDir::new("check");
+    dir.write("a.txt", "alpha");
+    dir.write("b.txt", "beta");
+
+    for variant in ["32", "128"] {
+        for format in ["hex", "decimal", "json"] {
+            let output = cityhash(
+                &dir.0,
+                &["-v", variant, "-f", format, "a.txt", "b.txt"],
+                b"",
+            );
+            dir.write("sums", &output.stdout);
+
+            let output = cityhash(
+                &dir.0,
+                &["-v", variant, "-f", format, "--check", "sums"],
+                b"",
+            );
+            assert_eq!(stdout(&output), "a.txt: OK\nb.txt: OK\n");
+
+            dir.write("b.txt", "modified");
+            let output = cityhash(&dir.0, &["-v", variant, "-f", format, "-c", "sums"], b"");
+            assert!(!output.status.success());
+            assert_eq!(
+                std::str::from_utf8(&output.stdout).unwrap(),
+                "a.txt: OK\nb.txt: FAILED\n"
+            );
+            dir.write("b.txt", "beta");
+        }
+    }
+
+    // 128-bit checksums listed by the reference implementation's tools, with
+    // the low 64 bits first, verify.
+    let hash128: u128 = cityhasher::hash("alpha");
+    let sums = format!(
+        "{:016x}{:016x}  a.txt\n",
+        hash128 as u64,
+        (hash128 >> 64) as u64
+    );
+    dir.write("sums", sums);
+    let output = cityhash(&dir.0, &["-v", "128", "-c", "sums"], b"");
+    assert_eq!(stdout(&output), "a.txt: OK\n");
+}
+
+#[test]
+fn invalid_seed() {
+    let dir = TempDir::new("seed");
+    let output = cityhash(&dir.0, &["-v", "64-seeded"], b"");
+    assert!(!output.status.success());
+    let output = cityhash(&dir.0, &["-s", "1"], b"");
+    assert!(!output.status.success());
+}
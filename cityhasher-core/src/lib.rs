@@ -0,0 +1,704 @@
+//! The CityHash algorithms operating on byte slices, shared by
+//! [`cityhasher`](https://docs.rs/cityhasher) and `cityhasher-macros`.
+//!
+//! Keeping a single implementation guarantees that the literals produced by
+//! the macros always match the runtime functions. This crate is an
+//! implementation detail of `cityhasher`, and its API is not covered by
+//! semantic versioning. Use `cityhasher` instead.
+
+#![no_std]
+#![warn(clippy::all)]
+#![cfg_attr(feature = "disable-bounds-checking", deny(unsafe_code))]
+#![cfg_attr(not(feature = "disable-bounds-checking"), forbid(unsafe_code))]
+
+use core::mem;
+
+pub struct Input<'a>(pub &'a [u8]);
+
+impl<'a> Input<'a> {
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[cfg_attr(feature = "disable-bounds-checking", allow(unsafe_code))]
+    #[inline]
+    pub fn fetch32(&self, offset: usize) -> u32 {
+        u32::from_le_bytes({
+            #[cfg(feature = "disable-bounds-checking")]
+            unsafe {
+                self.0
+                    .get_unchecked(offset..offset + 4)
+                    .try_into()
+                    .unwrap_unchecked()
+            }
+            #[cfg(not(feature = "disable-bounds-checking"))]
+            self.0[offset..offset + 4]
+                .try_into()
+                .expect("u32 is 4 bytes")
+        })
+    }
+
+    #[cfg_attr(feature = "disable-bounds-checking", allow(unsafe_code))]
+    #[inline]
+    pub fn fetch64(&self, offset: usize) -> u64 {
+        u64::from_le_bytes({
+            #[cfg(feature = "disable-bounds-checking")]
+            unsafe {
+                self.0
+                    .get_unchecked(offset..offset + 8)
+                    .try_into()
+                    .unwrap_unchecked()
+            }
+            #[cfg(not(feature = "disable-bounds-checking"))]
+            self.0[offset..offset + 8]
+                .try_into()
+                .expect("u64 is 8 bytes")
+        })
+    }
+
+    #[inline]
+    fn hash32_len_13_to_24(&self) -> u32 {
+        #[cfg(not(feature = "disable-bounds-checking"))]
+        assert!(self.len() <= 24); // This helps the optimizer with bounds checking
+        let a = self.fetch32((self.len() >> 1) - 4);
+        let b = self.fetch32(4);
+        let c = self.fetch32(self.len() - 8);
+        let d = self.fetch32(self.len() >> 1);
+        let e = self.fetch32(0);
+        let f = self.fetch32(self.len() - 4);
+        let h = self.len() as u32;
+
+        fmix(mur(f, mur(e, mur(d, mur(c, mur(b, mur(a, h)))))))
+    }
+
+    #[inline]
+    fn hash32_len_0_to_4(&self) -> u32 {
+        #[cfg(not(feature = "disable-bounds-checking"))]
+        assert!(self.len() <= 4); // This helps the optimizer with automatic loop unrolling.
+        let mut b = 0_u32;
+        let mut c = 9;
+        for v in self.0 {
+            let as_signed = *v as i8;
+
+            b = b.wrapping_mul(C1).wrapping_add(as_signed as u32);
+            c ^= b;
+        }
+        fmix(mur(b, mur(self.len() as u32, c)))
+    }
+
+    #[inline]
+    fn hash32_len_5_to_12(&self) -> u32 {
+        #[cfg(not(feature = "disable-bounds-checking"))]
+        assert!(self.len() <= 12);
+        let mut a = self.len() as u32;
+        let mut b = a.wrapping_mul(5);
+        let mut c = 9_u32;
+        let d = b;
+
+        a = a.wrapping_add(self.fetch32(0));
+        b = b.wrapping_add(self.fetch32(self.len() - 4));
+        c = c.wrapping_add(self.fetch32((self.len() >> 1) & 4));
+        fmix(mur(c, mur(b, mur(a, d))))
+    }
+
+    #[inline]
+    pub fn hash32(&self) -> u32 {
+        if self.len() <= 24 {
+            return if self.len() <= 12 {
+                if self.len() <= 4 {
+                    self.hash32_len_0_to_4()
+                } else {
+                    self.hash32_len_5_to_12()
+                }
+            } else {
+                self.hash32_len_13_to_24()
+            };
+        }
+
+        // len > 24
+        let mut state = Hash32Long::new(self, self.len());
+        for chunk in self.0[0..self.len() - 1].chunks_exact(20) {
+            state.update(&Self(chunk));
+        }
+        state.finish()
+    }
+
+    #[inline]
+    pub fn hash64(&self) -> u64 {
+        if self.len() <= 32 {
+            if self.len() <= 16 {
+                return self.hash64_len_0_to_16();
+            } else {
+                return self.hash64_len_17_to_32();
+            }
+        } else if self.len() <= 64 {
+            return self.hash64_len_33_to_64();
+        }
+
+        self.hash64_len_65_plus()
+    }
+
+    #[inline]
+    pub fn hash64_len_65_plus(&self) -> u64 {
+        let mut state = Hash64Long::new(self, self.len() as u64, self.fetch64(0));
+
+        // Decrease len to the nearest multiple of 64, and operate on 64-byte chunks.
+        for chunk in self.0[0..self.len() - 1].chunks_exact(64) {
+            state.update(&Self(chunk));
+        }
+        state.finish()
+    }
+
+    #[inline]
+    pub fn hash64_with_seeds(&self, seed0: u64, seed1: u64) -> u64 {
+        hash_len_16_u64(self.hash64().wrapping_sub(seed0), seed1)
+    }
+
+    #[inline]
+    pub fn hash64_len_0_to_16(&self) -> u64 {
+        if self.len() >= 8 {
+            hash64_len_8_to_16(
+                self.fetch64(0),
+                self.fetch64(self.len() - 8),
+                self.len() as u64,
+            )
+        } else if self.len() >= 4 {
+            hash64_len_4_to_7(
+                self.fetch32(0),
+                self.fetch32(self.len() - 4),
+                self.len() as u64,
+            )
+        } else if !self.is_empty() {
+            hash64_len_1_to_3(
+                self.0[0],
+                self.0[self.len() >> 1],
+                self.0[self.len() - 1],
+                self.len() as u32,
+            )
+        } else {
+            K2
+        }
+    }
+
+    #[inline]
+    pub fn hash64_len_17_to_32(&self) -> u64 {
+        let mul = K2.wrapping_add(self.len() as u64 * 2);
+        let a = self.fetch64(0).wrapping_mul(K1);
+        let b = self.fetch64(8);
+        let c = self.fetch64(self.len() - 8).wrapping_mul(mul);
+        let d = self.fetch64(self.len() - 16).wrapping_mul(K2);
+        hash_len_16_with_mul(
+            rotate64(a.wrapping_add(b), 43)
+                .wrapping_add(rotate64(c, 30))
+                .wrapping_add(d),
+            a.wrapping_add(rotate64(b.wrapping_add(K2), 18))
+                .wrapping_add(c),
+            mul,
+        )
+    }
+
+    #[inline]
+    pub fn hash64_len_33_to_64(&self) -> u64 {
+        let mul = K2.wrapping_add(self.len() as u64 * 2);
+        let a = self.fetch64(0).wrapping_mul(K2);
+        let b = self.fetch64(8);
+        let c = self.fetch64(self.len() - 24);
+        let d = self.fetch64(self.len() - 32);
+        let e = self.fetch64(16).wrapping_mul(K2);
+        let f = self.fetch64(24).wrapping_mul(9);
+        let g = self.fetch64(self.len() - 8);
+        let h = self.fetch64(self.len() - 16).wrapping_mul(mul);
+        let u = rotate64(a.wrapping_add(g), 43)
+            .wrapping_add(rotate64(b, 30).wrapping_add(c).wrapping_mul(9));
+        let v = (a.wrapping_add(g) ^ d).wrapping_add(f).wrapping_add(1);
+        let w = ((u.wrapping_add(v)).wrapping_mul(mul))
+            .swap_bytes()
+            .wrapping_add(h);
+        let x = rotate64(e.wrapping_add(f), 42).wrapping_add(c);
+        let y = (((v.wrapping_add(w)).wrapping_mul(mul))
+            .swap_bytes()
+            .wrapping_add(g))
+        .wrapping_mul(mul);
+        let z = e.wrapping_add(f).wrapping_add(c);
+        let a = ((x.wrapping_add(z)).wrapping_mul(mul).wrapping_add(y))
+            .swap_bytes()
+            .wrapping_add(b);
+        let b = shift_mix(
+            (z.wrapping_add(a))
+                .wrapping_mul(mul)
+                .wrapping_add(d)
+                .wrapping_add(h),
+        )
+        .wrapping_mul(mul);
+        b.wrapping_add(x)
+    }
+
+    #[inline]
+    fn city_murmur(&self, seed: (u64, u64)) -> (u64, u64) {
+        let (mut a, mut b) = seed;
+        let mut c;
+        let mut d;
+        if self.len() <= 16 {
+            a = shift_mix(a.wrapping_mul(K1)).wrapping_mul(K1);
+            c = b.wrapping_mul(K1).wrapping_add(self.hash64_len_0_to_16());
+            d = shift_mix(a.wrapping_add(if self.len() >= 8 { self.fetch64(0) } else { c }));
+        } else {
+            c = hash_len_16_u64(self.fetch64(self.len() - 8).wrapping_add(K1), a);
+            d = hash_len_16_u64(
+                b.wrapping_add(self.len() as u64),
+                c.wrapping_add(self.fetch64(self.len() - 16)),
+            );
+            a = a.wrapping_add(d);
+            let mut offset = 0;
+            while offset + 16 < self.len() {
+                a ^= shift_mix(self.fetch64(offset).wrapping_mul(K1)).wrapping_mul(K1);
+                a = a.wrapping_mul(K1);
+                b ^= a;
+                c ^= shift_mix(self.fetch64(offset + 8).wrapping_mul(K1)).wrapping_mul(K1);
+                c = c.wrapping_mul(K1);
+                d ^= c;
+                offset += 16;
+            }
+        }
+        let a = hash_len_16_u64(a, c);
+        let b = hash_len_16_u64(d, b);
+        (a ^ b, hash_len_16_u64(b, a))
+    }
+
+    #[inline]
+    pub fn hash128(&self) -> (u64, u64) {
+        if self.len() >= 16 {
+            Input(&self.0[16..])
+                .hash128_with_seed((self.fetch64(0), self.fetch64(8).wrapping_add(K0)))
+        } else {
+            self.hash128_with_seed((K0, K1))
+        }
+    }
+
+    #[inline]
+    pub fn hash128_with_seed(&self, seed: (u64, u64)) -> (u64, u64) {
+        if self.len() < 128 {
+            return self.city_murmur(seed);
+        }
+
+        // We expect len >= 128 to be the common case.
+        let mut state = Hash128Long::new(self, self.len() as u64, seed);
+        for chunk in self.0.chunks_exact(128) {
+            state.update(&Self(chunk));
+        }
+        state.finish(self)
+    }
+
+    #[inline]
+    pub fn weak_hash_len_32_with_seeds(&self, offset: usize, a: u64, b: u64) -> (u64, u64) {
+        weak_hash_len_32_with_seeds(
+            self.fetch64(offset),
+            self.fetch64(offset + 8),
+            self.fetch64(offset + 16),
+            self.fetch64(offset + 24),
+            a,
+            b,
+        )
+    }
+}
+
+/// The state of `CityHash32` for inputs longer than 24 bytes.
+///
+/// The state is initialized from the final 20 bytes and the length of the
+/// input, and then updated with each 20-byte chunk of the input, excluding
+/// the final byte.
+pub struct Hash32Long {
+    h: u32,
+    g: u32,
+    f: u32,
+}
+
+impl Hash32Long {
+    /// Returns the initial state for an input of `len` bytes. `tail` must end
+    /// with the final 20 bytes of the input.
+    #[inline(always)]
+    pub fn new(tail: &Input<'_>, len: usize) -> Self {
+        let end = tail.len();
+        let mut h = len as u32;
+        let mut g = h.wrapping_mul(C1);
+        let mut f = g;
+        let a0 = rotate32(tail.fetch32(end - 4).wrapping_mul(C1), 17).wrapping_mul(C2);
+        let a1 = rotate32(tail.fetch32(end - 8).wrapping_mul(C1), 17).wrapping_mul(C2);
+        let a2 = rotate32(tail.fetch32(end - 16).wrapping_mul(C1), 17).wrapping_mul(C2);
+        let a3 = rotate32(tail.fetch32(end - 12).wrapping_mul(C1), 17).wrapping_mul(C2);
+        let a4 = rotate32(tail.fetch32(end - 20).wrapping_mul(C1), 17).wrapping_mul(C2);
+        h ^= a0;
+        h = rotate32(h, 19);
+        h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+        h ^= a2;
+        h = rotate32(h, 19);
+        h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+        g ^= a1;
+        g = rotate32(g, 19);
+        g = g.wrapping_mul(5).wrapping_add(0xe6546b64);
+        g ^= a3;
+        g = rotate32(g, 19);
+        g = g.wrapping_mul(5).wrapping_add(0xe6546b64);
+        f = f.wrapping_add(a4);
+        f = rotate32(f, 19);
+        f = f.wrapping_mul(5).wrapping_add(0xe6546b64);
+        Self { h, g, f }
+    }
+
+    /// Updates the state with a 20-byte `chunk`.
+    #[inline(always)]
+    pub fn update(&mut self, chunk: &Input<'_>) {
+        let Self { h, g, f } = self;
+        let a0 = rotate32(chunk.fetch32(0).wrapping_mul(C1), 17).wrapping_mul(C2);
+        let a1 = chunk.fetch32(4);
+        let a2 = rotate32(chunk.fetch32(8).wrapping_mul(C1), 17).wrapping_mul(C2);
+        let a3 = rotate32(chunk.fetch32(12).wrapping_mul(C1), 17).wrapping_mul(C2);
+        let a4 = chunk.fetch32(16);
+        *h ^= a0;
+        *h = rotate32(*h, 18);
+        *h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+        *f = f.wrapping_add(a1);
+        *f = rotate32(*f, 19);
+        *f = f.wrapping_mul(C1);
+        *g = g.wrapping_add(a2);
+        *g = rotate32(*g, 18);
+        *g = g.wrapping_mul(5).wrapping_add(0xe6546b64);
+        *h ^= a3.wrapping_add(a1);
+        *h = rotate32(*h, 19);
+        *h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+        *g ^= a4;
+        *g = g.swap_bytes().wrapping_mul(5);
+        *h = h.wrapping_add(a4.wrapping_mul(5));
+        *h = h.swap_bytes();
+        *f = f.wrapping_add(a0);
+        permute3(f, h, g);
+    }
+
+    #[inline(always)]
+    pub fn finish(self) -> u32 {
+        let Self {
+            mut h,
+            mut g,
+            mut f,
+        } = self;
+        g = rotate32(g, 11).wrapping_mul(C1);
+        g = rotate32(g, 17).wrapping_mul(C1);
+        f = rotate32(f, 11).wrapping_mul(C1);
+        f = rotate32(f, 17).wrapping_mul(C1);
+        h = rotate32(h.wrapping_add(g), 19);
+        h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+        h = rotate32(h, 17).wrapping_mul(C1);
+        h = rotate32(h.wrapping_add(f), 19);
+        h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+        h = rotate32(h, 17).wrapping_mul(C1);
+        h
+    }
+}
+
+/// The state of `CityHash64` for inputs longer than 64 bytes.
+///
+/// The state is initialized from the final 64 bytes, the first 8 bytes, and
+/// the length of the input, and then updated with each 64-byte chunk of the
+/// input, excluding the final byte.
+pub struct Hash64Long {
+    x: u64,
+    y: u64,
+    z: u64,
+    v: (u64, u64),
+    w: (u64, u64),
+}
+
+impl Hash64Long {
+    /// Returns the initial state for an input of `len` bytes whose first 8
+    /// bytes are `first`. `tail` must end with the final 64 bytes of the input.
+    #[inline(always)]
+    pub fn new(tail: &Input<'_>, len: u64, first: u64) -> Self {
+        // For strings over 64 bytes we hash the end first, and then as we
+        // loop we keep 56 bytes of state: v, w, x, y, and z.
+        let end = tail.len();
+        let x = tail.fetch64(end - 40);
+        let y = tail.fetch64(end - 16).wrapping_add(tail.fetch64(end - 56));
+        let z = hash_len_16_u64(
+            tail.fetch64(end - 48).wrapping_add(len),
+            tail.fetch64(end - 24),
+        );
+        let v = tail.weak_hash_len_32_with_seeds(end - 64, len, z);
+        let w = tail.weak_hash_len_32_with_seeds(end - 32, y.wrapping_add(K1), x);
+        let x = x.wrapping_mul(K1).wrapping_add(first);
+        Self { x, y, z, v, w }
+    }
+
+    /// Updates the state with a 64-byte `chunk`.
+    #[inline(always)]
+    pub fn update(&mut self, chunk: &Input<'_>) {
+        let Self { x, y, z, v, w } = self;
+        *x = rotate64(
+            x.wrapping_add(*y)
+                .wrapping_add(v.0)
+                .wrapping_add(chunk.fetch64(8)),
+            37,
+        )
+        .wrapping_mul(K1);
+        *y = rotate64(y.wrapping_add(v.1).wrapping_add(chunk.fetch64(48)), 42).wrapping_mul(K1);
+        *x ^= w.1;
+        *y = y.wrapping_add(v.0.wrapping_add(chunk.fetch64(40)));
+        *z = rotate64(z.wrapping_add(w.0), 33).wrapping_mul(K1);
+        *v = chunk.weak_hash_len_32_with_seeds(0, v.1.wrapping_mul(K1), x.wrapping_add(w.0));
+        *w = chunk.weak_hash_len_32_with_seeds(
+            32,
+            z.wrapping_add(w.1),
+            y.wrapping_add(chunk.fetch64(16)),
+        );
+        mem::swap(z, x);
+    }
+
+    #[inline(always)]
+    pub fn finish(&self) -> u64 {
+        let Self { x, y, z, v, w } = *self;
+        hash_len_16_u64(
+            hash_len_16_u64(v.0, w.0).wrapping_add(shift_mix(y).wrapping_mul(K1).wrapping_add(z)),
+            hash_len_16_u64(v.1, w.1).wrapping_add(x),
+        )
+    }
+}
+
+/// The state of `CityHash128WithSeed` for inputs of at least 128 bytes.
+///
+/// The state is initialized from the seed, the first 96 bytes, and the length
+/// of the input, and then updated with each complete 128-byte chunk of the
+/// input. The final bytes that do not form a complete chunk are hashed when
+/// finishing, along with the final 128 bytes of the input.
+#[derive(Clone, Copy)]
+pub struct Hash128Long {
+    x: u64,
+    y: u64,
+    z: u64,
+    v: (u64, u64),
+    w: (u64, u64),
+    len: u64,
+}
+
+impl Hash128Long {
+    /// Returns the initial state for an input of `len` bytes. `head` must
+    /// start with the first 96 bytes of the input.
+    #[inline(always)]
+    pub fn new(head: &Input<'_>, len: u64, seed: (u64, u64)) -> Self {
+        // Keep 56 bytes of state: v, w, x, y, and z.
+        let (x, y) = seed;
+        let z = len.wrapping_mul(K1);
+        let v0 = rotate64(y ^ K1, 49)
+            .wrapping_mul(K1)
+            .wrapping_add(head.fetch64(0));
+        let v = (
+            v0,
+            rotate64(v0, 42)
+                .wrapping_mul(K1)
+                .wrapping_add(head.fetch64(8)),
+        );
+        let w = (
+            rotate64(y.wrapping_add(z), 35)
+                .wrapping_mul(K1)
+                .wrapping_add(x),
+            rotate64(x.wrapping_add(head.fetch64(88)), 53).wrapping_mul(K1),
+        );
+        Self { x, y, z, v, w, len }
+    }
+
+    /// Updates the state with a 128-byte `chunk`.
+    #[inline(always)]
+    pub fn update(&mut self, chunk: &Input<'_>) {
+        // This is the same inner loop as hash64(), manually unrolled.
+        let Self { x, y, z, v, w, .. } = self;
+        for offset in [0, 64] {
+            *x = rotate64(
+                x.wrapping_add(*y)
+                    .wrapping_add(v.0)
+                    .wrapping_add(chunk.fetch64(offset + 8)),
+                37,
+            )
+            .wrapping_mul(K1);
+            *y = rotate64(
+                y.wrapping_add(v.1).wrapping_add(chunk.fetch64(offset + 48)),
+                42,
+            )
+            .wrapping_mul(K1);
+            *x ^= w.1;
+            *y = y.wrapping_add(v.0.wrapping_add(chunk.fetch64(offset + 40)));
+            *z = rotate64(z.wrapping_add(w.0), 33).wrapping_mul(K1);
+            *v = chunk.weak_hash_len_32_with_seeds(
+                offset,
+                v.1.wrapping_mul(K1),
+                x.wrapping_add(w.0),
+            );
+            *w = chunk.weak_hash_len_32_with_seeds(
+                offset + 32,
+                z.wrapping_add(w.1),
+                y.wrapping_add(chunk.fetch64(offset + 16)),
+            );
+            mem::swap(z, x);
+        }
+    }
+
+    /// Returns the hash. `tail` must end with the final 128 bytes of the
+    /// input.
+    #[inline(always)]
+    pub fn finish(&self, tail: &Input<'_>) -> (u64, u64) {
+        let Self {
+            mut x,
+            mut y,
+            mut z,
+            mut v,
+            mut w,
+            len,
+        } = *self;
+        x = x.wrapping_add(rotate64(v.0.wrapping_add(z), 49).wrapping_mul(K0));
+        y = y.wrapping_mul(K0).wrapping_add(rotate64(w.1, 37));
+        z = z.wrapping_mul(K0).wrapping_add(rotate64(w.0, 27));
+        w.0 = w.0.wrapping_mul(9);
+        v.0 = v.0.wrapping_mul(K0);
+
+        // If 0 < len % 128, hash up to 4 chunks of 32 bytes each from the end.
+        let len = (len % 128) as usize;
+        let end = tail.len();
+        let mut tail_done = 0;
+        while tail_done < len {
+            tail_done += 32;
+            let offset = end - tail_done;
+            y = rotate64(x.wrapping_add(y), 42)
+                .wrapping_mul(K0)
+                .wrapping_add(v.1);
+            w.0 = w.0.wrapping_add(tail.fetch64(offset + 16));
+            x = x.wrapping_mul(K0).wrapping_add(w.0);
+            z = z.wrapping_add(w.1.wrapping_add(tail.fetch64(offset)));
+            w.1 = w.1.wrapping_add(v.0);
+            v = tail.weak_hash_len_32_with_seeds(offset, v.0.wrapping_add(z), v.1);
+            v.0 = v.0.wrapping_mul(K0);
+        }
+
+        // At this point our 56 bytes of state should contain more than enough
+        // information for a strong 128-bit hash.  We use two different
+        // 56-byte-to-8-byte hashes to get a 16-byte final result.
+        x = hash_len_16_u64(x, v.0);
+        y = hash_len_16_u64(y.wrapping_add(z), w.0);
+        (
+            hash_len_16_u64(x.wrapping_add(v.1), w.1).wrapping_add(y),
+            hash_len_16_u64(x.wrapping_add(w.1), y.wrapping_add(v.1)),
+        )
+    }
+}
+
+/// Hashes an input of 8 to 16 bytes, given its first and last 8 bytes.
+#[inline]
+pub const fn hash64_len_8_to_16(first: u64, last: u64, len: u64) -> u64 {
+    let mul = K2.wrapping_add(len.wrapping_mul(2));
+    let a = first.wrapping_add(K2);
+    let c = rotate64(last, 37).wrapping_mul(mul).wrapping_add(a);
+    let d = rotate64(a, 25).wrapping_add(last).wrapping_mul(mul);
+    hash_len_16_with_mul(c, d, mul)
+}
+
+/// Hashes an input of 4 to 7 bytes, given its first and last 4 bytes.
+#[inline]
+pub const fn hash64_len_4_to_7(first: u32, last: u32, len: u64) -> u64 {
+    let mul = K2.wrapping_add(len.wrapping_mul(2));
+    hash_len_16_with_mul(len.wrapping_add((first as u64) << 3), last as u64, mul)
+}
+
+/// Hashes an input of 1 to 3 bytes, given its first, middle and last bytes.
+#[inline]
+pub const fn hash64_len_1_to_3(first: u8, middle: u8, last: u8, len: u32) -> u64 {
+    let y = (first as u32).wrapping_add((middle as u32) << 8);
+    let z = len.wrapping_add((last as u32) << 2);
+    shift_mix((y as u64).wrapping_mul(K2) ^ (z as u64).wrapping_mul(K0)).wrapping_mul(K2)
+}
+
+#[inline]
+pub const fn hash_len_16_u64(u: u64, v: u64) -> u64 {
+    const MUL: u64 = 0x9ddfea08eb382d69;
+    hash_len_16_with_mul(u, v, MUL)
+}
+
+#[inline]
+pub const fn hash_len_16_with_mul(u: u64, v: u64, mul: u64) -> u64 {
+    // Murmur-inspired hashing.
+    let mut a = (u ^ v).wrapping_mul(mul);
+    a ^= a >> 47;
+    let mut b = (v ^ a).wrapping_mul(mul);
+    b ^= b >> 47;
+    b.wrapping_mul(mul)
+}
+
+// Some primes between 2^63 and 2^64 for various uses.
+pub const K0: u64 = 0xc3a5c85c97cb3127;
+pub const K1: u64 = 0xb492b66fbe98f273;
+pub const K2: u64 = 0x9ae16a3b2f90404f;
+
+// Magic numbers for 32-bit hashing.  Copied from Murmur3.
+pub const C1: u32 = 0xcc9e2d51;
+pub const C2: u32 = 0x1b873593;
+
+#[inline]
+pub const fn fmix(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^= h >> 16;
+    h
+}
+
+#[inline]
+pub const fn rotate32(val: u32, shift: u32) -> u32 {
+    val.rotate_right(shift)
+}
+
+#[inline]
+pub const fn rotate64(val: u64, shift: u32) -> u64 {
+    val.rotate_right(shift)
+}
+
+#[inline]
+pub const fn shift_mix(val: u64) -> u64 {
+    val ^ (val >> 47)
+}
+
+#[inline]
+pub fn permute3<T>(a: &mut T, b: &mut T, c: &mut T) {
+    mem::swap(a, b);
+    mem::swap(a, c);
+}
+
+#[inline]
+pub const fn mur(mut a: u32, mut h: u32) -> u32 {
+    // Helper from Murmur3 for combining two 32-bit values.
+    a = a.wrapping_mul(C1);
+    a = rotate32(a, 17);
+    a = a.wrapping_mul(C2);
+    h ^= a;
+    h = rotate32(h, 19);
+    h.wrapping_mul(5).wrapping_add(0xe6546b64)
+}
+
+#[inline]
+pub const fn weak_hash_len_32_with_seeds(
+    w: u64,
+    x: u64,
+    y: u64,
+    z: u64,
+    a: u64,
+    b: u64,
+) -> (u64, u64) {
+    let a = a.wrapping_add(w);
+    let b = rotate64(b.wrapping_add(a).wrapping_add(z), 21);
+    let c = a;
+    let a = a.wrapping_add(x);
+    let a = a.wrapping_add(y);
+    let b = b.wrapping_add(rotate64(a, 44));
+    (a.wrapping_add(z), b.wrapping_add(c))
+}
@@ -0,0 +1,86 @@
+#ifndef CITY_HASH_H_
+#define CITY_HASH_H_
+
+/* This file is generated by cbindgen from cityhasher-ffi. Do not edit it. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/* The integer types used by the reference implementation. */
+typedef uint8_t uint8;
+typedef uint32_t uint32;
+typedef uint64_t uint64;
+
+// A 128-bit hash, laid out like the reference `uint128`, which is a
+// `std::pair<uint64, uint64>`.
+typedef struct uint128 {
+  // The lower 64 bits, returned by the reference `Uint128Low64()`.
+  uint64_t first;
+  // The upper 64 bits, returned by the reference `Uint128High64()`.
+  uint64_t second;
+} uint128;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns the lower 64 bits of `x`.
+uint64_t Uint128Low64(struct uint128 x);
+
+// Returns the upper 64 bits of `x`.
+uint64_t Uint128High64(struct uint128 x);
+
+// Hashes 128 input bits down to 64 bits of output.
+uint64_t Hash128to64(struct uint128 x);
+
+// Hashes `len` bytes at `buf` using `CityHash32`.
+//
+// # Safety
+//
+// `buf` must be valid for reads of `len` bytes, unless `len` is 0.
+uint32_t CityHash32(const char *buf, size_t len);
+
+// Hashes `len` bytes at `buf` using `CityHash64`.
+//
+// # Safety
+//
+// `buf` must be valid for reads of `len` bytes, unless `len` is 0.
+uint64_t CityHash64(const char *buf, size_t len);
+
+// Hashes `len` bytes at `buf` with `seed` using `CityHash64WithSeed`.
+//
+// # Safety
+//
+// `buf` must be valid for reads of `len` bytes, unless `len` is 0.
+uint64_t CityHash64WithSeed(const char *buf, size_t len, uint64_t seed);
+
+// Hashes `len` bytes at `buf` with `seed0` and `seed1` using
+// `CityHash64WithSeeds`.
+//
+// # Safety
+//
+// `buf` must be valid for reads of `len` bytes, unless `len` is 0.
+uint64_t CityHash64WithSeeds(const char *buf, size_t len, uint64_t seed0, uint64_t seed1);
+
+// Hashes `len` bytes at `s` using `CityHash128`.
+//
+// # Safety
+//
+// `s` must be valid for reads of `len` bytes, unless `len` is 0.
+struct uint128 CityHash128(const char *s, size_t len);
+
+// Hashes `len` bytes at `s` with `seed` using `CityHash128WithSeed`.
+//
+// # Safety
+//
+// `s` must be valid for reads of `len` bytes, unless `len` is 0.
+struct uint128 CityHash128WithSeed(const char *s, size_t len, struct uint128 seed);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CITY_HASH_H_ */
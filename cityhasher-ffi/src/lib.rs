@@ -0,0 +1,144 @@
+//! A C ABI for [`cityhasher`] that is compatible with the reference CityHash
+//! library.
+//!
+//! This crate builds `libcityhash` as both a static and dynamic library. The
+//! exported functions have the same names, parameters, and results as the
+//! functions declared by the reference `city.h`, and are declared for C and
+//! C++ by `include/city.h`.
+//!
+//! The reference library is implemented in C++, and its functions have C++
+//! linkage. The functions in this crate have C linkage, so C++ programs must
+//! include this crate's header rather than the reference header.
+
+#![allow(non_camel_case_types, non_snake_case)]
+
+use core::ffi::c_char;
+
+/// A 128-bit hash, laid out like the reference `uint128`, which is a
+/// `std::pair<uint64, uint64>`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct uint128 {
+    /// The lower 64 bits, returned by the reference `Uint128Low64()`.
+    pub first: u64,
+    /// The upper 64 bits, returned by the reference `Uint128High64()`.
+    pub second: u64,
+}
+
+impl From<u128> for uint128 {
+    fn from(value: u128) -> Self {
+        Self {
+            first: value as u64,
+            second: (value >> 64) as u64,
+        }
+    }
+}
+
+impl From<uint128> for u128 {
+    fn from(value: uint128) -> Self {
+        (value.second as u128) << 64 | value.first as u128
+    }
+}
+
+/// Returns the lower 64 bits of `x`.
+#[no_mangle]
+pub extern "C" fn Uint128Low64(x: uint128) -> u64 {
+    x.first
+}
+
+/// Returns the upper 64 bits of `x`.
+#[no_mangle]
+pub extern "C" fn Uint128High64(x: uint128) -> u64 {
+    x.second
+}
+
+/// Hashes 128 input bits down to 64 bits of output.
+#[no_mangle]
+pub extern "C" fn Hash128to64(x: uint128) -> u64 {
+    cityhasher::hash128_to_64(x.into())
+}
+
+/// Returns the `len` bytes at `buf` as a slice.
+///
+/// # Safety
+///
+/// `buf` must be valid for reads of `len` bytes, unless `len` is 0.
+unsafe fn bytes<'a>(buf: *const c_char, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        // SAFETY: The caller guarantees that `buf` is valid for reads of `len`
+        // bytes.
+        unsafe { core::slice::from_raw_parts(buf.cast(), len) }
+    }
+}
+
+/// Hashes `len` bytes at `buf` using `CityHash32`.
+///
+/// # Safety
+///
+/// `buf` must be valid for reads of `len` bytes, unless `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn CityHash32(buf: *const c_char, len: usize) -> u32 {
+    cityhasher::hash(unsafe { bytes(buf, len) })
+}
+
+/// Hashes `len` bytes at `buf` using `CityHash64`.
+///
+/// # Safety
+///
+/// `buf` must be valid for reads of `len` bytes, unless `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn CityHash64(buf: *const c_char, len: usize) -> u64 {
+    cityhasher::hash(unsafe { bytes(buf, len) })
+}
+
+/// Hashes `len` bytes at `buf` with `seed` using `CityHash64WithSeed`.
+///
+/// # Safety
+///
+/// `buf` must be valid for reads of `len` bytes, unless `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn CityHash64WithSeed(buf: *const c_char, len: usize, seed: u64) -> u64 {
+    cityhasher::hash_with_seed(unsafe { bytes(buf, len) }, seed)
+}
+
+/// Hashes `len` bytes at `buf` with `seed0` and `seed1` using
+/// `CityHash64WithSeeds`.
+///
+/// # Safety
+///
+/// `buf` must be valid for reads of `len` bytes, unless `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn CityHash64WithSeeds(
+    buf: *const c_char,
+    len: usize,
+    seed0: u64,
+    seed1: u64,
+) -> u64 {
+    cityhasher::hash_with_seeds(unsafe { bytes(buf, len) }, seed0, seed1)
+}
+
+/// Hashes `len` bytes at `s` using `CityHash128`.
+///
+/// # Safety
+///
+/// `s` must be valid for reads of `len` bytes, unless `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn CityHash128(s: *const c_char, len: usize) -> uint128 {
+    cityhasher::hash::<u128>(unsafe { bytes(s, len) }).into()
+}
+
+/// Hashes `len` bytes at `s` with `seed` using `CityHash128WithSeed`.
+///
+/// # Safety
+///
+/// `s` must be valid for reads of `len` bytes, unless `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn CityHash128WithSeed(
+    s: *const c_char,
+    len: usize,
+    seed: uint128,
+) -> uint128 {
+    cityhasher::hash_with_seed::<u128>(unsafe { bytes(s, len) }, seed.into()).into()
+}
@@ -0,0 +1,71 @@
+#![cfg(unix)]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Converts the `TESTDATA` vectors in cityhasher's `src/tests.rs` into C
+/// initializers.
+fn testdata() -> String {
+    let tests = include_str!("../../src/tests.rs");
+    let start = tests
+        .find("static TESTDATA: [[u64; 16]; TEST_COUNT] = [")
+        .expect("TESTDATA not found");
+    let rows = &tests[start..];
+    let rows = &rows[rows.find('\n').unwrap()..rows.find("\n];").unwrap()];
+
+    let mut c = String::new();
+    for line in rows.lines() {
+        let line = line.trim();
+        match line {
+            "" => {}
+            "[" => c.push('{'),
+            "]," => c.push_str("},\n"),
+            value => {
+                let value = value.trim_end_matches(',');
+                assert!(value.starts_with("0x"), "unexpected line: {line}");
+                c.push_str(value);
+                c.push_str("ULL,");
+            }
+        }
+    }
+    c
+}
+
+/// Returns the directory containing the `libcityhash` built for this test.
+fn library_dir() -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    exe.parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn c_test_program() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("city_test");
+    std::fs::create_dir_all(&out_dir).unwrap();
+    std::fs::write(out_dir.join("testdata.inc"), testdata()).unwrap();
+
+    let library = library_dir().join("libcityhash.a");
+    assert!(library.exists(), "{} not found", library.display());
+
+    let program = out_dir.join("city_test");
+    let cc = std::env::var("CC").unwrap_or_else(|_| String::from("cc"));
+    let status = Command::new(cc)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-O2", "-I"])
+        .arg(crate_dir.join("include"))
+        .arg("-I")
+        .arg(&out_dir)
+        .arg(crate_dir.join("tests/city_test.c"))
+        .arg(library)
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to compile city_test.c");
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
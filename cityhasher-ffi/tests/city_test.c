@@ -0,0 +1,85 @@
+/*
+ * Verifies libcityhash against the test vectors used by cityhasher, which are
+ * the vectors from the reference implementation's city-test.cc.
+ *
+ * `testdata.inc` is generated from `src/tests.rs` by `tests/c.rs`, which also
+ * compiles and runs this program.
+ */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "city.h"
+
+static const uint64 k0 = 0xc3a5c85c97cb3127ULL;
+static const uint64 kSeed0 = 1234567;
+static const uint64 kSeed1 = 0xc3a5c85c97cb3127ULL;
+#define kDataSize (1 << 20)
+#define kTestSize 300
+
+static const uint64 testdata[kTestSize][16] = {
+#include "testdata.inc"
+};
+
+static char data[kDataSize];
+static int errors = 0;
+
+static void setup(void) {
+  uint64 a = 9;
+  uint64 b = 777;
+  for (int i = 0; i < kDataSize; i++) {
+    a += b;
+    b += a;
+    a = (a ^ (a >> 41)) * k0;
+    b = (b ^ (b >> 41)) * k0 + i;
+    uint8 u = b >> 37;
+    memcpy(data + i, &u, 1);
+  }
+}
+
+static void check(uint64 expected, uint64 actual, const char *function,
+                  size_t len) {
+  if (expected != actual) {
+    fprintf(stderr, "%s: length %zu: expected %016llx, got %016llx\n",
+            function, len, (unsigned long long)expected,
+            (unsigned long long)actual);
+    errors++;
+  }
+}
+
+static void test(const uint64 *expected, int offset, int len) {
+  const char *s = data + offset;
+  const uint128 seed = {kSeed0, kSeed1};
+  uint128 u = CityHash128(s, len);
+  uint128 v = CityHash128WithSeed(s, len, seed);
+
+  check(expected[0], CityHash64(s, len), "CityHash64", len);
+  check(expected[1], CityHash64WithSeed(s, len, kSeed0), "CityHash64WithSeed",
+        len);
+  check(expected[2], CityHash64WithSeeds(s, len, kSeed0, kSeed1),
+        "CityHash64WithSeeds", len);
+  /* CityHash64WithSeeds is defined in terms of Hash128to64. */
+  const uint128 seeded = {CityHash64(s, len) - kSeed0, kSeed1};
+  check(expected[2], Hash128to64(seeded), "Hash128to64", len);
+  check(expected[3], Uint128Low64(u), "CityHash128", len);
+  check(expected[4], Uint128High64(u), "CityHash128", len);
+  check(expected[5], Uint128Low64(v), "CityHash128WithSeed", len);
+  check(expected[6], Uint128High64(v), "CityHash128WithSeed", len);
+  check(expected[15], CityHash32(s, len), "CityHash32", len);
+}
+
+int main(void) {
+  setup();
+  int i = 0;
+  for (; i < kTestSize - 1; i++) {
+    test(testdata[i], i * i, i);
+  }
+  test(testdata[i], 0, kDataSize);
+
+  if (errors > 0) {
+    fprintf(stderr, "%d errors\n", errors);
+    return 1;
+  }
+  printf("all tests passed\n");
+  return 0;
+}
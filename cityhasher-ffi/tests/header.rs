@@ -0,0 +1,19 @@
+use std::path::Path;
+
+/// Verifies that `include/city.h` matches the exported API, regenerating it
+/// if it does not.
+#[test]
+fn header_is_up_to_date() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).unwrap();
+    let bindings = cbindgen::Builder::new()
+        .with_crate(crate_dir)
+        .with_config(config)
+        .generate()
+        .unwrap();
+    let changed = bindings.write_to_file(crate_dir.join("include/city.h"));
+    assert!(
+        !changed,
+        "include/city.h was out of date and has been regenerated"
+    );
+}
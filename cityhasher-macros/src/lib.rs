@@ -0,0 +1,124 @@
+//! Procedural macros that hash string literals at compile time and derive
+//! `StableHash` for [`cityhasher`](https://docs.rs/cityhasher).
+//!
+//! These macros are re-exported by `cityhasher` when its `macros` feature is
+//! enabled, and should be used through that crate.
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs, clippy::all)]
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+mod stable_hash;
+
+// The algorithm implementation is shared with `cityhasher`, guaranteeing that
+// the literals these macros produce match the runtime functions.
+use cityhasher_core::Input;
+
+/// Expands to the 32-bit CityHash of a string or byte string literal, as a
+/// `u32` literal.
+///
+/// The result is identical to `cityhasher::hash::<u32>()` of the literal's
+/// bytes. Because the expansion is a literal, it can be used as a `match`
+/// pattern.
+#[proc_macro]
+pub fn cityhash32(input: TokenStream) -> TokenStream {
+    match literal_bytes(input) {
+        Ok(bytes) => literal(Literal::u32_suffixed(Input(&bytes).hash32())),
+        Err(error) => error,
+    }
+}
+
+/// Expands to the 64-bit CityHash of a string or byte string literal, as a
+/// `u64` literal.
+///
+/// The result is identical to `cityhasher::hash::<u64>()` of the literal's
+/// bytes. Because the expansion is a literal, it can be used as a `match`
+/// pattern.
+#[proc_macro]
+pub fn cityhash64(input: TokenStream) -> TokenStream {
+    match literal_bytes(input) {
+        Ok(bytes) => literal(Literal::u64_suffixed(Input(&bytes).hash64())),
+        Err(error) => error,
+    }
+}
+
+/// Derives `cityhasher::StableHash` for a struct or enum.
+///
+/// Structs are encoded as each field in declaration order. Enums are encoded
+/// as the index of the variant in declaration order as a `u32`, followed by
+/// each of the variant's fields. Each type parameter is required to implement
+/// `StableHash`.
+#[proc_macro_derive(StableHash)]
+pub fn derive_stable_hash(input: TokenStream) -> TokenStream {
+    match stable_hash::derive(input) {
+        Ok(tokens) => tokens,
+        Err(mut error) => {
+            // `compile_error!` must be followed by a semicolon in item
+            // position.
+            error.extend([TokenTree::Punct(Punct::new(';', Spacing::Alone))]);
+            error
+        }
+    }
+}
+
+fn literal(literal: Literal) -> TokenStream {
+    TokenStream::from(TokenTree::Literal(literal))
+}
+
+/// Returns the bytes of the single string or byte string literal in `input`.
+fn literal_bytes(input: TokenStream) -> Result<Vec<u8>, TokenStream> {
+    let mut tokens = input.into_iter();
+    let token = match (tokens.next(), tokens.next()) {
+        (Some(token), None) => token,
+        (None, _) => {
+            return Err(compile_error(
+                Span::call_site(),
+                "expected a string or byte string literal",
+            ))
+        }
+        (Some(_), Some(extra)) => {
+            return Err(compile_error(extra.span(), "unexpected token"));
+        }
+    };
+
+    match token {
+        // Literals passed through `macro_rules!` fragments arrive wrapped in
+        // an invisible group.
+        TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+            literal_bytes(group.stream())
+        }
+        token => {
+            let span = token.span();
+            match litrs::Literal::try_from(token) {
+                Ok(litrs::Literal::String(lit)) => Ok(lit.value().as_bytes().to_vec()),
+                Ok(litrs::Literal::ByteString(lit)) => Ok(lit.value().to_vec()),
+                _ => Err(compile_error(
+                    span,
+                    "expected a string or byte string literal",
+                )),
+            }
+        }
+    }
+}
+
+/// Returns `compile_error!("{message}")`, reported at `span`.
+fn compile_error(span: Span, message: &str) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+    let mut group = Group::new(
+        Delimiter::Parenthesis,
+        TokenStream::from(TokenTree::Literal(message)),
+    );
+    group.set_span(span);
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+
+    [
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(bang),
+        TokenTree::Group(group),
+    ]
+    .into_iter()
+    .collect()
+}
@@ -0,0 +1,354 @@
+//! `#[derive(StableHash)]`, implemented without a parsing library by splitting
+//! the item's tokens into its name, generics, where clause and fields.
+
+use std::fmt::Write;
+
+use proc_macro::{Delimiter, Group, Ident, Spacing, Span, TokenStream, TokenTree};
+
+use crate::compile_error;
+
+/// The fields of a struct, or the variants of an enum.
+enum Body {
+    Struct(Fields),
+    Enum(Vec<(Ident, Fields)>),
+}
+
+/// The fields of a struct or enum variant.
+enum Fields {
+    Named(Vec<Ident>),
+    Unnamed(usize),
+    Unit,
+}
+
+/// A generic parameter of the item being derived.
+struct Param {
+    /// The parameter as declared, without its default.
+    declaration: TokenStream,
+    /// The parameter as passed to the type.
+    argument: TokenStream,
+    /// True if the parameter is a type parameter.
+    is_type: bool,
+}
+
+pub fn derive(input: TokenStream) -> Result<TokenStream, TokenStream> {
+    let mut tokens = input.into_iter().peekable();
+
+    // Attributes and visibility.
+    let kind = loop {
+        match tokens.next() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '#' => {
+                tokens.next();
+            }
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "pub" => {
+                if let Some(TokenTree::Group(group)) = tokens.peek() {
+                    if group.delimiter() == Delimiter::Parenthesis {
+                        tokens.next();
+                    }
+                }
+            }
+            Some(TokenTree::Ident(ident)) => break ident,
+            Some(other) => return Err(compile_error(other.span(), "unexpected token")),
+            None => {
+                return Err(compile_error(
+                    Span::call_site(),
+                    "expected a struct or enum",
+                ))
+            }
+        }
+    };
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(name)) => name,
+        _ => return Err(compile_error(kind.span(), "expected a type name")),
+    };
+
+    let mut params = Vec::new();
+    if matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == '<') {
+        tokens.next();
+        let mut generics = Vec::new();
+        let mut depth = AngleDepth::default();
+        for token in tokens.by_ref() {
+            if depth.update(&token) < 0 {
+                break;
+            }
+            generics.push(token);
+        }
+        params = split_top_level(generics, true)
+            .into_iter()
+            .map(parse_param)
+            .collect();
+    }
+
+    let mut rest: Vec<TokenTree> = tokens.collect();
+    let body = match kind.to_string().as_str() {
+        "struct" => {
+            if is_group(rest.first(), Delimiter::Parenthesis) {
+                let fields = rest.remove(0);
+                Body::Struct(Fields::Unnamed(field_count(&fields)))
+            } else if is_group(rest.last(), Delimiter::Brace) {
+                let fields = rest.pop().expect("checked");
+                Body::Struct(Fields::Named(field_names(&fields)))
+            } else {
+                Body::Struct(Fields::Unit)
+            }
+        }
+        "enum" => match rest.pop() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+                Body::Enum(parse_variants(group.stream())?)
+            }
+            _ => return Err(compile_error(name.span(), "expected enum variants")),
+        },
+        "union" => {
+            return Err(compile_error(
+                kind.span(),
+                "StableHash cannot be derived for unions",
+            ))
+        }
+        _ => return Err(compile_error(kind.span(), "expected a struct or enum")),
+    };
+
+    // What remains is the where clause, if any, and the trailing semicolon of
+    // tuple and unit structs.
+    if matches!(rest.last(), Some(TokenTree::Punct(punct)) if punct.as_char() == ';') {
+        rest.pop();
+    }
+    if matches!(rest.first(), Some(TokenTree::Ident(ident)) if ident.to_string() == "where") {
+        rest.remove(0);
+    }
+    let mut predicates = rest.into_iter().collect::<TokenStream>().to_string();
+    if !predicates.is_empty() && !predicates.trim_end().ends_with(',') {
+        predicates.push(',');
+    }
+    for param in params.iter().filter(|param| param.is_type) {
+        write!(predicates, " {}: ::cityhasher::StableHash,", param.argument).expect("infallible");
+    }
+
+    let declarations = join(params.iter().map(|param| &param.declaration));
+    let arguments = join(params.iter().map(|param| &param.argument));
+    let mut output = format!(
+        "impl<{declarations}> ::cityhasher::StableHash for {name}<{arguments}> where {predicates} {{
+            fn stable_hash<__H: ::cityhasher::StableHasher>(&self, state: &mut __H) {{"
+    );
+    match body {
+        Body::Struct(fields) => {
+            if let Some((pattern, count)) = pattern("Self", &fields) {
+                write!(output, "let {pattern} = self;").expect("infallible");
+                hash_fields(&mut output, count);
+            }
+            output.push_str("let _ = state;");
+        }
+        Body::Enum(variants) if variants.is_empty() => output.push_str("match *self {}"),
+        Body::Enum(variants) => {
+            output.push_str("match self {");
+            for (index, (variant, fields)) in variants.iter().enumerate() {
+                let (pattern, count) = pattern(&format!("Self::{variant}"), fields)
+                    .unwrap_or_else(|| (format!("Self::{variant}"), 0));
+                write!(
+                    output,
+                    "{pattern} => {{ ::cityhasher::StableHasher::write_variant(state, {index}_u32);"
+                )
+                .expect("infallible");
+                hash_fields(&mut output, count);
+                output.push('}');
+            }
+            output.push('}');
+        }
+    }
+    output.push_str("}}");
+
+    Ok(output.parse().expect("generated invalid tokens"))
+}
+
+/// Returns a pattern binding each field of `path` to `__self_{index}`, and the
+/// number of fields, or `None` if there are no fields to bind.
+fn pattern(path: &str, fields: &Fields) -> Option<(String, usize)> {
+    match fields {
+        Fields::Named(names) => {
+            let bindings = names
+                .iter()
+                .enumerate()
+                .map(|(index, name)| format!("{name}: __self_{index}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            Some((format!("{path} {{ {bindings} }}"), names.len()))
+        }
+        Fields::Unnamed(count) => {
+            let bindings = (0..*count)
+                .map(|index| format!("__self_{index}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            Some((format!("{path}({bindings})"), *count))
+        }
+        Fields::Unit => None,
+    }
+}
+
+fn hash_fields(output: &mut String, count: usize) {
+    for index in 0..count {
+        write!(
+            output,
+            "::cityhasher::StableHash::stable_hash(__self_{index}, state);"
+        )
+        .expect("infallible");
+    }
+}
+
+fn join<'a>(streams: impl Iterator<Item = &'a TokenStream>) -> String {
+    streams
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn is_group(token: Option<&TokenTree>, delimiter: Delimiter) -> bool {
+    matches!(token, Some(TokenTree::Group(group)) if group.delimiter() == delimiter)
+}
+
+/// Tracks the nesting of angle brackets, which unlike other delimiters are not
+/// grouped by the tokenizer.
+#[derive(Default)]
+struct AngleDepth {
+    depth: isize,
+    after_hyphen: bool,
+}
+
+impl AngleDepth {
+    /// Updates the depth for `token` and returns the new depth.
+    fn update(&mut self, token: &TokenTree) -> isize {
+        let after_hyphen = self.after_hyphen;
+        self.after_hyphen = false;
+        if let TokenTree::Punct(punct) = token {
+            match punct.as_char() {
+                '<' => self.depth += 1,
+                // `->` in the return type of a function type.
+                '>' if !after_hyphen => self.depth -= 1,
+                '-' => self.after_hyphen = punct.spacing() == Spacing::Joint,
+                _ => {}
+            }
+        }
+        self.depth
+    }
+}
+
+/// Splits `tokens` at each comma that is not nested inside a group or, if
+/// `angles` is true, inside angle brackets. Empty segments are omitted.
+fn split_top_level(
+    tokens: impl IntoIterator<Item = TokenTree>,
+    angles: bool,
+) -> Vec<Vec<TokenTree>> {
+    let mut segments = Vec::new();
+    let mut segment = Vec::new();
+    let mut depth = AngleDepth::default();
+    for token in tokens {
+        let nested = angles && depth.update(&token) > 0;
+        if !nested && matches!(&token, TokenTree::Punct(punct) if punct.as_char() == ',') {
+            segments.push(std::mem::take(&mut segment));
+        } else {
+            segment.push(token);
+        }
+    }
+    segments.push(segment);
+    segments.retain(|segment| !segment.is_empty());
+    segments
+}
+
+/// Removes any leading attributes from `tokens`.
+fn skip_attributes(tokens: &[TokenTree]) -> &[TokenTree] {
+    let mut tokens = tokens;
+    while matches!(tokens.first(), Some(TokenTree::Punct(punct)) if punct.as_char() == '#') {
+        tokens = tokens.get(2..).unwrap_or_default();
+    }
+    tokens
+}
+
+fn parse_param(tokens: Vec<TokenTree>) -> Param {
+    let tokens = skip_attributes(&tokens);
+    // Remove the default, which is only allowed in the type's declaration.
+    let mut depth = AngleDepth::default();
+    let declaration: Vec<TokenTree> = tokens
+        .iter()
+        .take_while(|token| {
+            depth.update(token) > 0
+                || !matches!(token, TokenTree::Punct(punct) if punct.as_char() == '=')
+        })
+        .cloned()
+        .collect();
+
+    let (argument, is_type) = match tokens {
+        [TokenTree::Punct(quote), name, ..] if quote.as_char() == '\'' => {
+            (vec![TokenTree::Punct(quote.clone()), name.clone()], false)
+        }
+        [TokenTree::Ident(keyword), name, ..] if keyword.to_string() == "const" => {
+            (vec![name.clone()], false)
+        }
+        [name, ..] => (vec![name.clone()], true),
+        [] => (Vec::new(), false),
+    };
+    Param {
+        declaration: declaration.into_iter().collect(),
+        argument: argument.into_iter().collect(),
+        is_type,
+    }
+}
+
+/// Returns the number of fields in a tuple struct or variant's fields.
+fn field_count(fields: &TokenTree) -> usize {
+    match fields {
+        TokenTree::Group(group) => split_top_level(group.stream(), true).len(),
+        _ => 0,
+    }
+}
+
+/// Returns the names of the fields in a struct or variant's named fields.
+fn field_names(fields: &TokenTree) -> Vec<Ident> {
+    let group = match fields {
+        TokenTree::Group(group) => group,
+        _ => return Vec::new(),
+    };
+    split_top_level(group.stream(), true)
+        .iter()
+        .filter_map(|field| {
+            let mut field = skip_attributes(field);
+            if matches!(field.first(), Some(TokenTree::Ident(ident)) if ident.to_string() == "pub")
+            {
+                field = &field[1..];
+                if is_group(field.first(), Delimiter::Parenthesis) {
+                    field = &field[1..];
+                }
+            }
+            match field.first() {
+                Some(TokenTree::Ident(name)) => Some(name.clone()),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn parse_variants(body: TokenStream) -> Result<Vec<(Ident, Fields)>, TokenStream> {
+    // Explicit discriminants can contain `<`, so angle brackets are not
+    // tracked. Commas in field types are nested inside the fields' group.
+    split_top_level(body, false)
+        .iter()
+        .map(|variant| {
+            let variant = skip_attributes(variant);
+            let name = match variant.first() {
+                Some(TokenTree::Ident(name)) => name.clone(),
+                Some(other) => return Err(compile_error(other.span(), "expected a variant")),
+                None => return Err(compile_error(Span::call_site(), "expected a variant")),
+            };
+            let fields = match variant.get(1) {
+                Some(TokenTree::Group(group)) => fields_of(group),
+                _ => Fields::Unit,
+            };
+            Ok((name, fields))
+        })
+        .collect()
+}
+
+fn fields_of(group: &Group) -> Fields {
+    let token = TokenTree::Group(group.clone());
+    match group.delimiter() {
+        Delimiter::Parenthesis => Fields::Unnamed(field_count(&token)),
+        Delimiter::Brace => Fields::Named(field_names(&token)),
+        _ => Fields::Unit,
+    }
+}
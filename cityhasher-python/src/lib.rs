@@ -0,0 +1,151 @@
+//! Python bindings for [`cityhasher`].
+//!
+//! The `cityhasher` Python module exports the same functions as the `cityhash`
+//! package on PyPI, with the same names, arguments, and results, allowing it
+//! to be used as a replacement:
+//!
+//! ```python
+//! import cityhasher as cityhash
+//!
+//! assert cityhash.CityHash64("abc") == 2640714258260161385
+//! ```
+//!
+//! Each function accepts `str`, which is hashed as UTF-8, `bytes`, or any
+//! object supporting the buffer protocol, such as `bytearray`, `memoryview`,
+//! or a contiguous NumPy array. The hashed bytes are borrowed from the object
+//! without being copied.
+//!
+//! The `cityhash` package represents 128-bit hashes and seeds as integers with
+//! the reference `Uint128Low64` in the upper 64 bits and `Uint128High64` in
+//! the lower 64 bits. This is the reverse of [`cityhasher::hash`], and these
+//! bindings follow the `cityhash` package.
+
+#![allow(non_snake_case)]
+
+use std::mem::MaybeUninit;
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::ffi;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyString};
+
+/// Invokes `f` with the bytes of `data` without copying them.
+fn with_bytes<R>(data: &Bound<'_, PyAny>, f: impl FnOnce(&[u8]) -> R) -> PyResult<R> {
+    if let Ok(bytes) = data.cast::<PyBytes>() {
+        Ok(f(bytes.as_bytes()))
+    } else if let Ok(string) = data.cast::<PyString>() {
+        Ok(f(string.to_str()?.as_bytes()))
+    } else {
+        let buffer = Buffer::get(data)?;
+        Ok(f(buffer.as_bytes()))
+    }
+}
+
+/// A buffer exported by an object supporting the buffer protocol.
+struct Buffer(ffi::Py_buffer);
+
+impl Buffer {
+    fn get(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let mut view = MaybeUninit::uninit();
+        // SAFETY: `view` is only read if the buffer is successfully exported.
+        // `PyBUF_SIMPLE` requests a contiguous buffer of bytes.
+        let exported =
+            unsafe { ffi::PyObject_GetBuffer(data.as_ptr(), view.as_mut_ptr(), ffi::PyBUF_SIMPLE) };
+        if exported == 0 {
+            // SAFETY: The buffer was exported, initializing `view`.
+            Ok(Self(unsafe { view.assume_init() }))
+        } else {
+            let err = PyErr::fetch(data.py());
+            let message = format!(
+                "argument must be str, bytes, or an object supporting the buffer protocol, not {}",
+                data.get_type().name()?
+            );
+            let type_err = PyTypeError::new_err(message);
+            type_err.set_cause(data.py(), Some(err));
+            Err(type_err)
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        let len = usize::try_from(self.0.len).unwrap_or_default();
+        if len == 0 {
+            &[]
+        } else {
+            // SAFETY: A `PyBUF_SIMPLE` buffer is `len` contiguous bytes starting
+            // at `buf`, which remain valid until the buffer is released.
+            unsafe { std::slice::from_raw_parts(self.0.buf.cast(), len) }
+        }
+    }
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        // SAFETY: The buffer was exported by `PyObject_GetBuffer`, and this
+        // type is only accessible while the GIL is held.
+        unsafe { ffi::PyBuffer_Release(&mut self.0) }
+    }
+}
+
+/// Converts between this crate's 128-bit layout and the `cityhash` package's.
+/// Swapping the halves is its own inverse.
+const fn swap_halves(value: u128) -> u128 {
+    value.rotate_left(64)
+}
+
+/// Obtain a 32-bit hash from input data.
+#[pyfunction]
+#[pyo3(signature = (data))]
+fn CityHash32(data: &Bound<'_, PyAny>) -> PyResult<u32> {
+    with_bytes(data, |data| cityhasher::hash(data))
+}
+
+/// Obtain a 64-bit hash from input data.
+#[pyfunction]
+#[pyo3(signature = (data))]
+fn CityHash64(data: &Bound<'_, PyAny>) -> PyResult<u64> {
+    with_bytes(data, |data| cityhasher::hash(data))
+}
+
+/// Obtain a 64-bit hash from input data given a seed.
+#[pyfunction]
+#[pyo3(signature = (data, seed = 0))]
+fn CityHash64WithSeed(data: &Bound<'_, PyAny>, seed: u64) -> PyResult<u64> {
+    with_bytes(data, |data| cityhasher::hash_with_seed(data, seed))
+}
+
+/// Obtain a 64-bit hash from input data given two seeds.
+#[pyfunction]
+#[pyo3(signature = (data, seed0 = 0, seed1 = 0))]
+fn CityHash64WithSeeds(data: &Bound<'_, PyAny>, seed0: u64, seed1: u64) -> PyResult<u64> {
+    with_bytes(data, |data| cityhasher::hash_with_seeds(data, seed0, seed1))
+}
+
+/// Obtain a 128-bit hash from input data.
+#[pyfunction]
+#[pyo3(signature = (data))]
+fn CityHash128(data: &Bound<'_, PyAny>) -> PyResult<u128> {
+    with_bytes(data, |data| swap_halves(cityhasher::hash(data)))
+}
+
+/// Obtain a 128-bit hash from input data given a seed.
+#[pyfunction]
+#[pyo3(signature = (data, seed = 0))]
+fn CityHash128WithSeed(data: &Bound<'_, PyAny>, seed: u128) -> PyResult<u128> {
+    with_bytes(data, |data| {
+        swap_halves(cityhasher::hash_with_seed(data, swap_halves(seed)))
+    })
+}
+
+/// Python wrapper for CityHash.
+#[pymodule]
+#[pyo3(name = "cityhasher")]
+pub fn cityhasher_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add_function(wrap_pyfunction!(CityHash32, m)?)?;
+    m.add_function(wrap_pyfunction!(CityHash64, m)?)?;
+    m.add_function(wrap_pyfunction!(CityHash64WithSeed, m)?)?;
+    m.add_function(wrap_pyfunction!(CityHash64WithSeeds, m)?)?;
+    m.add_function(wrap_pyfunction!(CityHash128, m)?)?;
+    m.add_function(wrap_pyfunction!(CityHash128WithSeed, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use std::ffi::CString;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::wrap_pymodule;
+
+/// Runs `code` with the bindings imported as `cityhash`.
+fn run(code: &str) {
+    Python::attach(|py| {
+        let module = wrap_pymodule!(cityhasher_python::cityhasher_module)(py);
+        let globals = PyDict::new(py);
+        globals.set_item("cityhash", module).unwrap();
+        let code = CString::new(code).unwrap();
+        if let Err(err) = py.run(&code, Some(&globals), None) {
+            err.print(py);
+            panic!("python code failed");
+        }
+    });
+}
+
+#[test]
+fn matches_cityhash_package() {
+    // The examples from the `cityhash` package's documentation.
+    run(r#"
+assert cityhash.CityHash32("abc") == 795041479
+assert cityhash.CityHash64("abc") == 2640714258260161385
+assert cityhash.CityHash128("abc") == 76434233956484675513733017140465933893
+"#);
+}
+
+#[test]
+fn matches_rust() {
+    let data = b"hello, world";
+    let hash32: u32 = cityhasher::hash(data);
+    let hash64: u64 = cityhasher::hash(data);
+    let seeded: u64 = cityhasher::hash_with_seed(data, 42);
+    let seeds = cityhasher::hash_with_seeds(data, 1, 2);
+    let hash128: u128 = cityhasher::hash(data);
+    let seed128 = (3_u128 << 64) | 4;
+    let seeded128: u128 = cityhasher::hash_with_seed(data, seed128);
+    run(&format!(
+        r#"
+low, high = {}, {}
+assert cityhash.CityHash32(b"hello, world") == {hash32}
+assert cityhash.CityHash64(b"hello, world") == {hash64}
+assert cityhash.CityHash64WithSeed(b"hello, world", 42) == {seeded}
+assert cityhash.CityHash64WithSeed(b"hello, world", seed=42) == {seeded}
+assert cityhash.CityHash64WithSeeds(b"hello, world", 1, seed1=2) == {seeds}
+assert cityhash.CityHash128(b"hello, world") == (low << 64) | high
+seeded = cityhash.CityHash128WithSeed(b"hello, world", (4 << 64) | 3)
+assert seeded == ({} << 64) | {}
+"#,
+        hash128 as u64,
+        hash128 >> 64,
+        seeded128 as u64,
+        seeded128 >> 64,
+    ));
+}
+
+#[test]
+fn default_seeds() {
+    run(r#"
+assert cityhash.CityHash64WithSeed(b"abc") == cityhash.CityHash64WithSeed(b"abc", 0)
+assert cityhash.CityHash64WithSeeds(b"abc") == cityhash.CityHash64WithSeeds(b"abc", 0, 0)
+assert cityhash.CityHash128WithSeed(b"abc") == cityhash.CityHash128WithSeed(b"abc", 0)
+"#);
+}
+
+#[test]
+fn input_types() {
+    run(r#"
+import array
+
+data = "héllo".encode()
+expected = cityhash.CityHash64(data)
+assert cityhash.CityHash64("héllo") == expected
+assert cityhash.CityHash64(bytearray(data)) == expected
+assert cityhash.CityHash64(memoryview(data)) == expected
+assert cityhash.CityHash64(memoryview(b"xx" + data)[2:]) == expected
+assert cityhash.CityHash64(array.array("B", data)) == expected
+assert cityhash.CityHash64(b"") == cityhash.CityHash64("")
+
+for invalid in [1, None, ["a"], memoryview(b"abcd")[::2]]:
+    try:
+        cityhash.CityHash64(invalid)
+    except TypeError:
+        pass
+    else:
+        raise AssertionError(f"{invalid!r} was accepted")
+
+try:
+    cityhash.CityHash64WithSeed(b"abc", -1)
+except OverflowError:
+    pass
+else:
+    raise AssertionError("negative seed was accepted")
+"#);
+}
@@ -0,0 +1,244 @@
+use crate::city::Input;
+use crate::lanes::{self, hash64_lanes, with_lanes, LanesClass, U64x4, WithLanes, LANES};
+use crate::FromCityHash;
+
+/// Hashes each of `inputs` using the [CityHash][cityhash] algorithm, storing
+/// the results in `out`.
+///
+/// Each result is identical to calling [`hash`](crate::hash) with the
+/// corresponding input. For `u64` outputs, inputs are hashed in groups. When
+/// the inputs in a group have lengths within the same range, their length is
+/// only examined once and their hashes are computed together. Groups of
+/// inputs whose lengths all fall within one of the ranges 0, 1 to 3, 4 to 7,
+/// 8 to 16, or 17 to 32 bytes are hashed in parallel using SIMD instructions
+/// when the `intrinsics` feature is enabled and the CPU supports them.
+///
+/// ```rust
+/// let inputs: [&[u8]; 3] = [b"hello", b"world", b""];
+/// let mut hashes = [0_u64; 3];
+/// cityhasher::hash_batch(&inputs, &mut hashes);
+///
+/// let hello: u64 = cityhasher::hash("hello");
+/// assert_eq!(hashes[0], hello);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `inputs` and `out` have different lengths.
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_batch<T>(inputs: &[&[u8]], out: &mut [T])
+where
+    T: FromCityHash,
+{
+    assert_eq!(
+        inputs.len(),
+        out.len(),
+        "inputs and out must have the same length"
+    );
+    T::from_city_hash_batch(inputs, out);
+}
+
+/// Hashes each `stride`-byte record of `data` using the [CityHash][cityhash]
+/// algorithm, storing the results in `out`.
+///
+/// Each result is identical to calling [`hash`](crate::hash) with the
+/// corresponding record. For `u64` outputs, the implementation for records of
+/// `stride` bytes is selected once, and multiple records are hashed together.
+/// When `stride` is 32 or less, records are hashed in parallel using SIMD
+/// instructions when the `intrinsics` feature is enabled and the CPU supports
+/// them.
+///
+/// ```rust
+/// let ids = [1_u64, 2, 3].map(u64::to_le_bytes).concat();
+/// let mut hashes = [0_u64; 3];
+/// cityhasher::hash_fixed_stride(&ids, 8, &mut hashes);
+///
+/// let first: u64 = cityhasher::hash(1_u64.to_le_bytes());
+/// assert_eq!(hashes[0], first);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `stride` is 0 or if `data.len()` is not equal to
+/// `stride * out.len()`.
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_fixed_stride<T>(data: &[u8], stride: usize, out: &mut [T])
+where
+    T: FromCityHash,
+{
+    assert!(stride > 0, "stride must be greater than 0");
+    assert_eq!(
+        Some(data.len()),
+        stride.checked_mul(out.len()),
+        "data must contain exactly out.len() records of stride bytes"
+    );
+    T::from_city_hash_fixed_stride(data, stride, out);
+}
+
+/// The ranges of input lengths handled by separate implementations of
+/// `CityHash64`.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum LengthClass {
+    UpTo16,
+    UpTo32,
+    UpTo64,
+    Longer,
+}
+
+impl LengthClass {
+    const fn of(len: usize) -> Self {
+        if len <= 16 {
+            Self::UpTo16
+        } else if len <= 32 {
+            Self::UpTo32
+        } else if len <= 64 {
+            Self::UpTo64
+        } else {
+            Self::Longer
+        }
+    }
+}
+
+pub(crate) fn hash64_batch(inputs: &[&[u8]], out: &mut [u64]) {
+    struct Batch<'a, 'b> {
+        inputs: &'a [&'b [u8]],
+        out: &'a mut [u64],
+    }
+
+    impl WithLanes for Batch<'_, '_> {
+        type Output = ();
+
+        #[inline(always)]
+        fn run<V: U64x4>(self) {
+            hash64_batch_with::<V>(self.inputs, self.out);
+        }
+    }
+
+    with_lanes(Batch { inputs, out });
+}
+
+#[inline(always)]
+fn hash64_batch_with<V: U64x4>(inputs: &[&[u8]], out: &mut [u64]) {
+    let mut input_groups = inputs.chunks_exact(LANES);
+    let mut out_groups = out.chunks_exact_mut(LANES);
+    for (inputs, out) in (&mut input_groups).zip(&mut out_groups) {
+        let lanes_class = LanesClass::of(inputs[0].len()).filter(|&class| {
+            inputs[1..]
+                .iter()
+                .all(|input| LanesClass::of(input.len()) == Some(class))
+        });
+        let class = LengthClass::of(inputs[0].len());
+        if let Some(lanes_class) = lanes_class {
+            let inputs = inputs.try_into().expect("group has LANES inputs");
+            out.copy_from_slice(&hash64_lanes::<V>(inputs, lanes_class));
+        } else if inputs[1..]
+            .iter()
+            .all(|input| LengthClass::of(input.len()) == class)
+        {
+            match class {
+                LengthClass::UpTo16 => hash_group(inputs, out, |input| input.hash64_len_0_to_16()),
+                LengthClass::UpTo32 => hash_group(inputs, out, |input| input.hash64_len_17_to_32()),
+                LengthClass::UpTo64 => hash_group(inputs, out, |input| input.hash64_len_33_to_64()),
+                LengthClass::Longer => hash_group(inputs, out, |input| input.hash64_len_65_plus()),
+            }
+        } else {
+            hash_group(inputs, out, |input| input.hash64());
+        }
+    }
+
+    hash_group(
+        input_groups.remainder(),
+        out_groups.into_remainder(),
+        |input| input.hash64(),
+    );
+}
+
+#[inline(always)]
+fn hash_group(inputs: &[&[u8]], out: &mut [u64], hash: impl Fn(&Input<'_>) -> u64) {
+    for (input, out) in inputs.iter().zip(out) {
+        *out = hash(&Input(input));
+    }
+}
+
+pub(crate) fn hash64_fixed_stride(data: &[u8], stride: usize, out: &mut [u64]) {
+    struct FixedStride<'a> {
+        data: &'a [u8],
+        stride: usize,
+        out: &'a mut [u64],
+    }
+
+    impl WithLanes for FixedStride<'_> {
+        type Output = ();
+
+        #[inline(always)]
+        fn run<V: U64x4>(self) {
+            hash_records_in_lanes::<V>(self.data, self.stride, self.out);
+        }
+    }
+
+    match LengthClass::of(stride) {
+        LengthClass::UpTo16 | LengthClass::UpTo32 => with_lanes(FixedStride { data, stride, out }),
+        LengthClass::UpTo64 => hash_records(data, stride, out, |input| input.hash64_len_33_to_64()),
+        LengthClass::Longer => hash_records(data, stride, out, |input| input.hash64_len_65_plus()),
+    }
+}
+
+/// Hashes records of up to [`MAX_LEN`](lanes::MAX_LEN) bytes, [`LANES`] records at a time.
+#[inline(always)]
+fn hash_records_in_lanes<V: U64x4>(data: &[u8], stride: usize, out: &mut [u64]) {
+    let mut record_groups = data.chunks_exact(stride * LANES);
+    let mut out_groups = out.chunks_exact_mut(LANES);
+    if stride == 8 {
+        // Each record is a single word, so a group can be loaded directly.
+        for (records, out) in (&mut record_groups).zip(&mut out_groups) {
+            let words = V::load(records);
+            out.copy_from_slice(&lanes::hash64_len_8_to_16(words, words, V::splat(8)).to_array());
+        }
+    } else if stride == 16 {
+        for (records, out) in (&mut record_groups).zip(&mut out_groups) {
+            let (first, last) = V::load(records).deinterleave(V::load(&records[32..]));
+            out.copy_from_slice(&lanes::hash64_len_8_to_16(first, last, V::splat(16)).to_array());
+        }
+    }
+    let class = LanesClass::of(stride).expect("stride is at most MAX_LEN");
+    for (records, out) in (&mut record_groups).zip(&mut out_groups) {
+        let records = [
+            &records[..stride],
+            &records[stride..stride * 2],
+            &records[stride * 2..stride * 3],
+            &records[stride * 3..],
+        ];
+        out.copy_from_slice(&hash64_lanes::<V>(&records, class));
+    }
+
+    for (record, out) in record_groups
+        .remainder()
+        .chunks_exact(stride)
+        .zip(out_groups.into_remainder())
+    {
+        *out = Input(record).hash64();
+    }
+}
+
+#[inline(always)]
+fn hash_records(data: &[u8], stride: usize, out: &mut [u64], hash: impl Fn(&Input<'_>) -> u64) {
+    let mut record_groups = data.chunks_exact(stride.saturating_mul(LANES));
+    let mut out_groups = out.chunks_exact_mut(LANES);
+    for (records, out) in (&mut record_groups).zip(&mut out_groups) {
+        for (record, out) in records.chunks_exact(stride).zip(out) {
+            *out = hash(&Input(record));
+        }
+    }
+
+    for (record, out) in record_groups
+        .remainder()
+        .chunks_exact(stride)
+        .zip(out_groups.into_remainder())
+    {
+        *out = hash(&Input(record));
+    }
+}
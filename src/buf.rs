@@ -0,0 +1,81 @@
+#[cfg(feature = "std")]
+use std::io::IoSlice;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use bytes::Buf;
+
+use crate::{hash_vectored_iter, FromCityHashVectored};
+
+/// Hashes the remaining bytes of `buf` using the [CityHash][cityhash]
+/// algorithm, copying them into a contiguous buffer first unless `buf` is
+/// contiguous or the `std` feature is enabled and [`Buf::chunks_vectored`]
+/// returns every chunk of `buf`.
+///
+/// The result is identical to [`hash`](crate::hash) of the remaining bytes
+/// flattened into a single slice. The supported outputs are the same as
+/// [`hash_vectored`](crate::hash_vectored).
+///
+/// CityHash reads the end of its input before the beginning, so the chunks of
+/// `buf` must be accessible at the same time. Contiguous buffers, such as
+/// [`Bytes`](bytes::Bytes) and [`BytesMut`](bytes::BytesMut), are hashed in
+/// place. With the `std` feature, buffers that return all of their chunks from
+/// [`Buf::chunks_vectored`], such as [`Chain`](bytes::buf::Chain) and
+/// `VecDeque<u8>`, are also hashed in place, regardless of the number of
+/// chunks. Only the remaining buffers are copied.
+///
+/// ```rust
+/// use bytes::{Buf, Bytes};
+///
+/// let message = Bytes::from_static(b"hello, ").chain(Bytes::from_static(b"world"));
+/// let hash: u64 = cityhasher::hash_buf(message);
+/// let expected: u64 = cityhasher::hash("hello, world");
+///
+/// assert_eq!(hash, expected);
+/// ```
+///
+/// [cityhash]: https://github.com/google/cityhash
+pub fn hash_buf<T>(mut buf: impl Buf) -> T
+where
+    T: FromCityHashVectored,
+{
+    let remaining = buf.remaining();
+    if buf.chunk().len() == remaining {
+        return hash_vectored_iter(Some(buf.chunk()));
+    }
+
+    #[cfg(feature = "std")]
+    if let Some(hash) = hash_chunks_vectored(&buf) {
+        return hash;
+    }
+
+    let bytes = buf.copy_to_bytes(remaining);
+    hash_vectored_iter(Some(&bytes[..]))
+}
+
+/// Hashes the chunks of `buf` returned by [`Buf::chunks_vectored`], or returns
+/// `None` if they do not contain all of its remaining bytes.
+#[cfg(feature = "std")]
+fn hash_chunks_vectored<T>(buf: &impl Buf) -> Option<T>
+where
+    T: FromCityHashVectored,
+{
+    // `chunks_vectored` fills as much of its argument as it can, so the
+    // slices are grown until they are not filled.
+    let mut stack = [IoSlice::new(&[]); 64];
+    let mut heap = Vec::new();
+    let mut chunks = &mut stack[..];
+    loop {
+        let count = buf.chunks_vectored(chunks);
+        if count < chunks.len() {
+            let chunks = &chunks[..count];
+            let len = chunks.iter().map(|chunk| chunk.len()).sum::<usize>();
+            return (len == buf.remaining())
+                .then(|| hash_vectored_iter(chunks.iter().map(|chunk| &**chunk)));
+        }
+
+        let len = chunks.len() * 2;
+        heap.resize(len, IoSlice::new(&[]));
+        chunks = &mut heap;
+    }
+}
@@ -0,0 +1,85 @@
+use alloc::vec::Vec;
+use core::hash::Hasher;
+
+use crate::{hash, hash_with_seed};
+
+/// A [`Hasher`] that collects every byte written to it in memory, and hashes
+/// the concatenation when [finished](Hasher::finish).
+///
+/// [`CityHasher`](crate::CityHasher) rehashes its state on every call to
+/// [`Hasher::write`], which makes its output depend on how the input was split
+/// across writes. This type guarantees that any sequence of writes produces the
+/// same value as [`hash::<u64>`](crate::hash) (or
+/// [`hash_with_seed::<u64>`](crate::hash_with_seed) when seeded) over the
+/// concatenated bytes. It provides no memory savings over collecting the bytes
+/// into a `Vec` and calling [`hash`](crate::hash).
+///
+/// # Memory Usage
+///
+/// CityHash cannot be computed incrementally in bounded memory: for inputs
+/// longer than 64 bytes, the algorithm initializes its state from the final 64
+/// bytes and the total length before processing the input from the beginning.
+/// Because neither is known until all input has been written, this type retains
+/// every byte written to it until it is [reset](Self::reset) or dropped.
+/// Compressing the input in 64-byte blocks as it is written would produce a
+/// different hash.
+///
+/// ```rust
+/// use core::hash::Hasher;
+///
+/// use cityhasher::BufferingCityHasher;
+///
+/// let mut hasher = BufferingCityHasher::new();
+/// hasher.write(b"hello, ");
+/// hasher.write(b"world");
+/// let expected: u64 = cityhasher::hash("hello, world");
+/// assert_eq!(hasher.finish(), expected);
+/// ```
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct BufferingCityHasher {
+    buffer: Vec<u8>,
+    seed: Option<u64>,
+}
+
+impl BufferingCityHasher {
+    /// Returns a new hasher with no seed.
+    pub const fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            seed: None,
+        }
+    }
+
+    /// Returns a hasher that incorporates `seed` into the hashes produced.
+    pub const fn with_seed(seed: u64) -> Self {
+        Self {
+            buffer: Vec::new(),
+            seed: Some(seed),
+        }
+    }
+
+    /// Returns the bytes that have been written to this hasher.
+    #[must_use]
+    pub fn bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Discards all bytes written to this hasher, retaining its seed and
+    /// allocated capacity.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+impl Hasher for BufferingCityHasher {
+    fn finish(&self) -> u64 {
+        match self.seed {
+            Some(seed) => hash_with_seed(&self.buffer, seed),
+            None => hash(&self.buffer),
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+}
@@ -0,0 +1,187 @@
+//! Combinators for fingerprinting collections from the hashes of their
+//! elements.
+//!
+//! [`Ordered`] combines hashes in sequence, so its result depends on the order
+//! of the elements, making it suitable for lists and the fields of a struct.
+//! [`Unordered`] combines hashes commutatively, so its result only depends on
+//! which elements have been inserted, making it suitable for sets and
+//! multisets. Elements can also be removed from an [`Unordered`] without
+//! rehashing the remaining elements.
+//!
+//! Both combinators are built on [`hash128_to_64`], which combines a
+//! `Uint128Low64` and `Uint128High64` pair into a single value, and are defined
+//! as follows:
+//!
+//! - [`Ordered`] starts with a state of 0. Each element's hash replaces the
+//!   state with `Hash128to64` of the state and the hash, as `Uint128Low64` and
+//!   `Uint128High64` respectively. The result is `Hash128to64` of the state
+//!   and the number of elements.
+//! - [`Unordered`] starts with a sum of 0. Each element's hash is mixed by
+//!   computing `Hash128to64` of the hash and `0x9ae16a3b2f90404f`, and the
+//!   mixed value is added to the sum, wrapping on overflow. Removing an
+//!   element subtracts its mixed value. The result is `Hash128to64` of the sum
+//!   and the number of elements. Mixing each hash prevents related hashes
+//!   from cancelling each other out in the sum.
+//!
+//! ```rust
+//! use cityhasher::combine::{Ordered, Unordered};
+//!
+//! let hashes: [u64; 3] = ["a", "b", "c"].map(cityhasher::hash);
+//!
+//! let forward: Ordered = hashes.iter().copied().collect();
+//! let reverse: Ordered = hashes.iter().rev().copied().collect();
+//! assert_ne!(forward.finish(), reverse.finish());
+//!
+//! let mut forward: Unordered = hashes.iter().copied().collect();
+//! let reverse: Unordered = hashes.iter().rev().copied().collect();
+//! assert_eq!(forward.finish(), reverse.finish());
+//!
+//! forward.remove(hashes[1]);
+//! let without_b: Unordered = [hashes[0], hashes[2]].into_iter().collect();
+//! assert_eq!(forward.finish(), without_b.finish());
+//! ```
+
+use crate::city::K2;
+use crate::{hash128_to_64, u128_from_halves};
+
+/// Combines `low` and `high` using `Hash128to64`.
+const fn combine(low: u64, high: u64) -> u64 {
+    hash128_to_64(u128_from_halves((low, high)))
+}
+
+/// An order-dependent combination of the hashes of a sequence of elements.
+///
+/// See the [module documentation](self) for how hashes are combined.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Ordered {
+    state: u64,
+    len: u64,
+}
+
+impl Ordered {
+    /// Returns a combination of an empty sequence.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { state: 0, len: 0 }
+    }
+
+    /// Appends the element whose hash is `hash` to the sequence.
+    pub fn push(&mut self, hash: u64) {
+        self.state = combine(self.state, hash);
+        self.len = self.len.wrapping_add(1);
+    }
+
+    /// Returns the number of elements in the sequence.
+    #[must_use]
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns true if the sequence contains no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the fingerprint of the sequence.
+    #[must_use]
+    pub const fn finish(&self) -> u64 {
+        combine(self.state, self.len)
+    }
+}
+
+impl Extend<u64> for Ordered {
+    fn extend<T: IntoIterator<Item = u64>>(&mut self, hashes: T) {
+        for hash in hashes {
+            self.push(hash);
+        }
+    }
+}
+
+impl FromIterator<u64> for Ordered {
+    fn from_iter<T: IntoIterator<Item = u64>>(hashes: T) -> Self {
+        let mut ordered = Self::new();
+        ordered.extend(hashes);
+        ordered
+    }
+}
+
+/// An order-independent combination of the hashes of a set or multiset of
+/// elements.
+///
+/// Inserting the same hash more than once counts it once per insertion, and
+/// [`remove()`](Self::remove) removes a single occurrence. Removing a hash
+/// that was never inserted produces a meaningless fingerprint.
+///
+/// See the [module documentation](self) for how hashes are combined.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Unordered {
+    sum: u64,
+    len: u64,
+}
+
+impl Unordered {
+    /// Returns a combination of an empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { sum: 0, len: 0 }
+    }
+
+    /// Inserts the element whose hash is `hash`.
+    pub fn insert(&mut self, hash: u64) {
+        self.sum = self.sum.wrapping_add(mix(hash));
+        self.len = self.len.wrapping_add(1);
+    }
+
+    /// Removes an element whose hash is `hash`, which must have been
+    /// previously inserted.
+    pub fn remove(&mut self, hash: u64) {
+        self.sum = self.sum.wrapping_sub(mix(hash));
+        self.len = self.len.wrapping_sub(1);
+    }
+
+    /// Inserts all elements that have been inserted into `other`.
+    pub fn merge(&mut self, other: &Self) {
+        self.sum = self.sum.wrapping_add(other.sum);
+        self.len = self.len.wrapping_add(other.len);
+    }
+
+    /// Returns the number of elements in the set.
+    #[must_use]
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns true if the set contains no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the fingerprint of the set.
+    #[must_use]
+    pub const fn finish(&self) -> u64 {
+        combine(self.sum, self.len)
+    }
+}
+
+/// Mixes an element's hash before it is added to an [`Unordered`] sum.
+const fn mix(hash: u64) -> u64 {
+    combine(hash, K2)
+}
+
+impl Extend<u64> for Unordered {
+    fn extend<T: IntoIterator<Item = u64>>(&mut self, hashes: T) {
+        for hash in hashes {
+            self.insert(hash);
+        }
+    }
+}
+
+impl FromIterator<u64> for Unordered {
+    fn from_iter<T: IntoIterator<Item = u64>>(hashes: T) -> Self {
+        let mut unordered = Self::new();
+        unordered.extend(hashes);
+        unordered
+    }
+}
@@ -0,0 +1,351 @@
+//! Implementations of the CityHash algorithms that can be evaluated at compile
+//! time.
+//!
+//! The runtime implementations in `Input` rely on `for` loops, `mem::swap` and
+//! unchecked slice access, none of which are available in `const fn` on this
+//! crate's minimum supported Rust version. These implementations mirror the
+//! runtime implementations step for step and share all of the mixing
+//! primitives with them. The test suite verifies both against the reference
+//! test vectors.
+
+use crate::city::{
+    fmix, hash64_len_1_to_3, hash64_len_4_to_7, hash64_len_8_to_16, hash_len_16_u64,
+    hash_len_16_with_mul, mur, rotate32, rotate64, shift_mix, weak_hash_len_32_with_seeds, C1, C2,
+    K1, K2,
+};
+
+const fn fetch32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ])
+}
+
+const fn fetch64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+        data[offset + 4],
+        data[offset + 5],
+        data[offset + 6],
+        data[offset + 7],
+    ])
+}
+
+/// Hashes `data` using `CityHash32`, in a `const` context.
+///
+/// This function produces the same result as [`hash::<u32>`](crate::hash), but
+/// can be used to initialize constants. When hashing at runtime,
+/// [`hash`](crate::hash) is faster.
+///
+/// ```rust
+/// const HELLO: u32 = cityhasher::hash32(b"hello");
+///
+/// let runtime: u32 = cityhasher::hash("hello");
+/// assert_eq!(HELLO, runtime);
+/// ```
+#[must_use]
+pub const fn hash32(data: &[u8]) -> u32 {
+    let len = data.len();
+    if len <= 4 {
+        hash32_len_0_to_4(data)
+    } else if len <= 12 {
+        hash32_len_5_to_12(data)
+    } else if len <= 24 {
+        hash32_len_13_to_24(data)
+    } else {
+        hash32_len_over_24(data)
+    }
+}
+
+const fn hash32_len_0_to_4(data: &[u8]) -> u32 {
+    let mut b = 0_u32;
+    let mut c = 9;
+    let mut i = 0;
+    while i < data.len() {
+        let as_signed = data[i] as i8;
+        b = b.wrapping_mul(C1).wrapping_add(as_signed as u32);
+        c ^= b;
+        i += 1;
+    }
+    fmix(mur(b, mur(data.len() as u32, c)))
+}
+
+const fn hash32_len_5_to_12(data: &[u8]) -> u32 {
+    let len = data.len();
+    let mut a = len as u32;
+    let mut b = a.wrapping_mul(5);
+    let mut c = 9_u32;
+    let d = b;
+
+    a = a.wrapping_add(fetch32(data, 0));
+    b = b.wrapping_add(fetch32(data, len - 4));
+    c = c.wrapping_add(fetch32(data, (len >> 1) & 4));
+    fmix(mur(c, mur(b, mur(a, d))))
+}
+
+const fn hash32_len_13_to_24(data: &[u8]) -> u32 {
+    let len = data.len();
+    let a = fetch32(data, (len >> 1) - 4);
+    let b = fetch32(data, 4);
+    let c = fetch32(data, len - 8);
+    let d = fetch32(data, len >> 1);
+    let e = fetch32(data, 0);
+    let f = fetch32(data, len - 4);
+    let h = len as u32;
+
+    fmix(mur(f, mur(e, mur(d, mur(c, mur(b, mur(a, h)))))))
+}
+
+const fn scramble32(data: &[u8], offset: usize) -> u32 {
+    rotate32(fetch32(data, offset).wrapping_mul(C1), 17).wrapping_mul(C2)
+}
+
+const fn hash32_len_over_24(data: &[u8]) -> u32 {
+    let len = data.len();
+    let mut h = len as u32;
+    let mut g = h.wrapping_mul(C1);
+    let mut f = g;
+    let a0 = scramble32(data, len - 4);
+    let a1 = scramble32(data, len - 8);
+    let a2 = scramble32(data, len - 16);
+    let a3 = scramble32(data, len - 12);
+    let a4 = scramble32(data, len - 20);
+    h ^= a0;
+    h = rotate32(h, 19);
+    h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+    h ^= a2;
+    h = rotate32(h, 19);
+    h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+    g ^= a1;
+    g = rotate32(g, 19);
+    g = g.wrapping_mul(5).wrapping_add(0xe6546b64);
+    g ^= a3;
+    g = rotate32(g, 19);
+    g = g.wrapping_mul(5).wrapping_add(0xe6546b64);
+    f = f.wrapping_add(a4);
+    f = rotate32(f, 19);
+    f = f.wrapping_mul(5).wrapping_add(0xe6546b64);
+    let mut iters = (len - 1) / 20;
+    let mut offset = 0;
+
+    while iters > 0 {
+        let a0 = scramble32(data, offset);
+        let a1 = fetch32(data, offset + 4);
+        let a2 = scramble32(data, offset + 8);
+        let a3 = scramble32(data, offset + 12);
+        let a4 = fetch32(data, offset + 16);
+        h ^= a0;
+        h = rotate32(h, 18);
+        h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+        f = f.wrapping_add(a1);
+        f = rotate32(f, 19);
+        f = f.wrapping_mul(C1);
+        g = g.wrapping_add(a2);
+        g = rotate32(g, 18);
+        g = g.wrapping_mul(5).wrapping_add(0xe6546b64);
+        h ^= a3.wrapping_add(a1);
+        h = rotate32(h, 19);
+        h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+        g ^= a4;
+        g = g.swap_bytes().wrapping_mul(5);
+        h = h.wrapping_add(a4.wrapping_mul(5));
+        h = h.swap_bytes();
+        f = f.wrapping_add(a0);
+        // permute3(f, h, g)
+        let old_f = f;
+        f = g;
+        g = h;
+        h = old_f;
+        offset += 20;
+        iters -= 1;
+    }
+    g = rotate32(g, 11).wrapping_mul(C1);
+    g = rotate32(g, 17).wrapping_mul(C1);
+    f = rotate32(f, 11).wrapping_mul(C1);
+    f = rotate32(f, 17).wrapping_mul(C1);
+    h = rotate32(h.wrapping_add(g), 19);
+    h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+    h = rotate32(h, 17).wrapping_mul(C1);
+    h = rotate32(h.wrapping_add(f), 19);
+    h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
+    rotate32(h, 17).wrapping_mul(C1)
+}
+
+/// Hashes `data` using `CityHash64`, in a `const` context.
+///
+/// This function produces the same result as [`hash::<u64>`](crate::hash), but
+/// can be used to initialize constants. When hashing at runtime,
+/// [`hash`](crate::hash) is faster.
+///
+/// ```rust
+/// const HELLO: u64 = cityhasher::hash64(b"hello");
+///
+/// let runtime: u64 = cityhasher::hash("hello");
+/// assert_eq!(HELLO, runtime);
+/// ```
+#[must_use]
+pub const fn hash64(data: &[u8]) -> u64 {
+    let len = data.len();
+    if len <= 16 {
+        hash64_len_0_to_16(data)
+    } else if len <= 32 {
+        hash64_len_17_to_32(data)
+    } else if len <= 64 {
+        hash64_len_33_to_64(data)
+    } else {
+        hash64_len_over_64(data)
+    }
+}
+
+/// Hashes `data` with a seed value using `CityHash64WithSeed`, in a `const`
+/// context.
+///
+/// This function produces the same result as
+/// [`hash_with_seed::<u64>`](crate::hash_with_seed), but can be used to
+/// initialize constants. When hashing at runtime,
+/// [`hash_with_seed`](crate::hash_with_seed) is faster.
+///
+/// ```rust
+/// const HELLO: u64 = cityhasher::hash64_with_seed(b"hello", 1);
+///
+/// let runtime: u64 = cityhasher::hash_with_seed("hello", 1);
+/// assert_eq!(HELLO, runtime);
+/// ```
+#[must_use]
+pub const fn hash64_with_seed(data: &[u8], seed: u64) -> u64 {
+    hash_len_16_u64(hash64(data).wrapping_sub(K2), seed)
+}
+
+const fn hash64_len_0_to_16(data: &[u8]) -> u64 {
+    let len = data.len();
+    if len >= 8 {
+        hash64_len_8_to_16(fetch64(data, 0), fetch64(data, len - 8), len as u64)
+    } else if len >= 4 {
+        hash64_len_4_to_7(fetch32(data, 0), fetch32(data, len - 4), len as u64)
+    } else if len > 0 {
+        hash64_len_1_to_3(data[0], data[len >> 1], data[len - 1], len as u32)
+    } else {
+        K2
+    }
+}
+
+const fn hash64_len_17_to_32(data: &[u8]) -> u64 {
+    let len = data.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let a = fetch64(data, 0).wrapping_mul(K1);
+    let b = fetch64(data, 8);
+    let c = fetch64(data, len - 8).wrapping_mul(mul);
+    let d = fetch64(data, len - 16).wrapping_mul(K2);
+    hash_len_16_with_mul(
+        rotate64(a.wrapping_add(b), 43)
+            .wrapping_add(rotate64(c, 30))
+            .wrapping_add(d),
+        a.wrapping_add(rotate64(b.wrapping_add(K2), 18))
+            .wrapping_add(c),
+        mul,
+    )
+}
+
+const fn hash64_len_33_to_64(data: &[u8]) -> u64 {
+    let len = data.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let a = fetch64(data, 0).wrapping_mul(K2);
+    let b = fetch64(data, 8);
+    let c = fetch64(data, len - 24);
+    let d = fetch64(data, len - 32);
+    let e = fetch64(data, 16).wrapping_mul(K2);
+    let f = fetch64(data, 24).wrapping_mul(9);
+    let g = fetch64(data, len - 8);
+    let h = fetch64(data, len - 16).wrapping_mul(mul);
+    let u = rotate64(a.wrapping_add(g), 43)
+        .wrapping_add(rotate64(b, 30).wrapping_add(c).wrapping_mul(9));
+    let v = (a.wrapping_add(g) ^ d).wrapping_add(f).wrapping_add(1);
+    let w = ((u.wrapping_add(v)).wrapping_mul(mul))
+        .swap_bytes()
+        .wrapping_add(h);
+    let x = rotate64(e.wrapping_add(f), 42).wrapping_add(c);
+    let y = (((v.wrapping_add(w)).wrapping_mul(mul))
+        .swap_bytes()
+        .wrapping_add(g))
+    .wrapping_mul(mul);
+    let z = e.wrapping_add(f).wrapping_add(c);
+    let a = ((x.wrapping_add(z)).wrapping_mul(mul).wrapping_add(y))
+        .swap_bytes()
+        .wrapping_add(b);
+    let b = shift_mix(
+        (z.wrapping_add(a))
+            .wrapping_mul(mul)
+            .wrapping_add(d)
+            .wrapping_add(h),
+    )
+    .wrapping_mul(mul);
+    b.wrapping_add(x)
+}
+
+const fn weak_hash_len_32_at(data: &[u8], offset: usize, a: u64, b: u64) -> (u64, u64) {
+    weak_hash_len_32_with_seeds(
+        fetch64(data, offset),
+        fetch64(data, offset + 8),
+        fetch64(data, offset + 16),
+        fetch64(data, offset + 24),
+        a,
+        b,
+    )
+}
+
+const fn hash64_len_over_64(data: &[u8]) -> u64 {
+    let len = data.len();
+    // For strings over 64 bytes we hash the end first, and then as we
+    // loop we keep 56 bytes of state: v, w, x, y, and z.
+    let mut x = fetch64(data, len - 40);
+    let mut y = fetch64(data, len - 16).wrapping_add(fetch64(data, len - 56));
+    let mut z = hash_len_16_u64(
+        fetch64(data, len - 48).wrapping_add(len as u64),
+        fetch64(data, len - 24),
+    );
+    let mut v = weak_hash_len_32_at(data, len - 64, len as u64, z);
+    let mut w = weak_hash_len_32_at(data, len - 32, y.wrapping_add(K1), x);
+    x = x.wrapping_mul(K1).wrapping_add(fetch64(data, 0));
+
+    // Decrease len to the nearest multiple of 64, and operate on 64-byte chunks.
+    let mut chunks = (len - 1) / 64;
+    let mut offset = 0;
+    while chunks > 0 {
+        x = rotate64(
+            x.wrapping_add(y)
+                .wrapping_add(v.0)
+                .wrapping_add(fetch64(data, offset + 8)),
+            37,
+        )
+        .wrapping_mul(K1);
+        y = rotate64(
+            y.wrapping_add(v.1).wrapping_add(fetch64(data, offset + 48)),
+            42,
+        )
+        .wrapping_mul(K1);
+        x ^= w.1;
+        y = y.wrapping_add(v.0.wrapping_add(fetch64(data, offset + 40)));
+        z = rotate64(z.wrapping_add(w.0), 33).wrapping_mul(K1);
+        v = weak_hash_len_32_at(data, offset, v.1.wrapping_mul(K1), x.wrapping_add(w.0));
+        w = weak_hash_len_32_at(
+            data,
+            offset + 32,
+            z.wrapping_add(w.1),
+            y.wrapping_add(fetch64(data, offset + 16)),
+        );
+        let old_z = z;
+        z = x;
+        x = old_z;
+        offset += 64;
+        chunks -= 1;
+    }
+    hash_len_16_u64(
+        hash_len_16_u64(v.0, w.0).wrapping_add(shift_mix(y).wrapping_mul(K1).wrapping_add(z)),
+        hash_len_16_u64(v.1, w.1).wrapping_add(x),
+    )
+}
@@ -0,0 +1,415 @@
+use crate::city::{hash_len_16_u64, permute3, rotate64, shift_mix, Input, K0};
+use crate::{u128_from_halves, u128_to_halves};
+
+/// An implementation of the CRC-32C update performed by the SSE4.2
+/// `_mm_crc32_u64` intrinsic.
+pub(crate) trait Crc32 {
+    /// Updates the CRC stored in the low 32 bits of `crc` with the 8 bytes of
+    /// `value`, returning the new CRC zero-extended to 64 bits.
+    fn crc32_u64(crc: u64, value: u64) -> u64;
+}
+
+/// A portable, table-driven implementation of [`Crc32`].
+pub(crate) struct Software;
+
+impl Crc32 for Software {
+    #[inline(always)]
+    fn crc32_u64(crc: u64, value: u64) -> u64 {
+        let low = (crc as u32) ^ (value as u32);
+        let high = (value >> 32) as u32;
+        let crc = TABLES[7][(low & 0xff) as usize]
+            ^ TABLES[6][((low >> 8) & 0xff) as usize]
+            ^ TABLES[5][((low >> 16) & 0xff) as usize]
+            ^ TABLES[4][(low >> 24) as usize]
+            ^ TABLES[3][(high & 0xff) as usize]
+            ^ TABLES[2][((high >> 8) & 0xff) as usize]
+            ^ TABLES[1][((high >> 16) & 0xff) as usize]
+            ^ TABLES[0][(high >> 24) as usize];
+        u64::from(crc)
+    }
+}
+
+/// The reflected CRC-32C (Castagnoli) polynomial.
+const POLYNOMIAL: u32 = 0x82f6_3b78;
+
+/// Lookup tables for processing 8 bytes per step ("slicing-by-8").
+static TABLES: [[u32; 256]; 8] = crc32c_tables();
+
+const fn crc32c_tables() -> [[u32; 256]; 8] {
+    let mut tables = [[0_u32; 256]; 8];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        tables[0][i] = crc;
+        i += 1;
+    }
+
+    let mut table = 1;
+    while table < 8 {
+        let mut i = 0;
+        while i < 256 {
+            let previous = tables[table - 1][i];
+            tables[table][i] = (previous >> 8) ^ tables[0][(previous & 0xff) as usize];
+            i += 1;
+        }
+        table += 1;
+    }
+    tables
+}
+
+/// An implementation of [`Crc32`] using the SSE4.2 `crc32` instruction.
+///
+/// This type must only be used after ensuring SSE4.2 is supported.
+#[cfg(all(
+    feature = "intrinsics",
+    target_arch = "x86_64",
+    any(feature = "std", target_feature = "sse4.2")
+))]
+pub(crate) struct Sse42;
+
+#[cfg(all(
+    feature = "intrinsics",
+    target_arch = "x86_64",
+    any(feature = "std", target_feature = "sse4.2")
+))]
+impl Crc32 for Sse42 {
+    #[inline(always)]
+    #[allow(unsafe_code)]
+    fn crc32_u64(crc: u64, value: u64) -> u64 {
+        // SAFETY: This type is only used when SSE4.2 support has been detected
+        // at compile time or at runtime.
+        unsafe { core::arch::x86_64::_mm_crc32_u64(crc, value) }
+    }
+}
+
+#[cfg(all(
+    feature = "intrinsics",
+    feature = "std",
+    target_arch = "x86_64",
+    not(target_feature = "sse4.2")
+))]
+#[target_feature(enable = "sse4.2")]
+#[allow(unsafe_code)]
+unsafe fn hash_crc256_long_sse42(input: &Input<'_>, seed: u32) -> [u64; 4] {
+    input.hash_crc256_long::<Sse42>(seed)
+}
+
+/// Returns true if the running CPU supports SSE4.2.
+#[cfg(all(
+    feature = "intrinsics",
+    feature = "std",
+    target_arch = "x86_64",
+    any(test, not(target_feature = "sse4.2"))
+))]
+pub(crate) fn sse42_available() -> bool {
+    std::is_x86_feature_detected!("sse4.2")
+}
+
+/// The `CityHashCrc` functions of [`Input`], which live in this crate rather
+/// than `cityhasher-core` because they detect CPU features at runtime.
+pub(crate) trait CrcInput {
+    fn hash_crc256_long_dispatch(&self, seed: u32) -> [u64; 4];
+
+    /// Computes `CityHashCrc256`, using `long` to hash inputs that are at least
+    /// 240 bytes long.
+    fn hash_crc256_with(&self, long: impl FnOnce(&Input<'_>, u32) -> [u64; 4]) -> [u64; 4];
+
+    fn hash_crc256(&self) -> [u64; 4];
+
+    /// Requires `self.len() >= 240`.
+    fn hash_crc256_long<C: Crc32>(&self, seed: u32) -> [u64; 4];
+
+    fn hash_crc128(&self) -> (u64, u64);
+
+    fn hash_crc128_with_seed(&self, seed: (u64, u64)) -> (u64, u64);
+}
+
+impl CrcInput for Input<'_> {
+    #[allow(unreachable_code)]
+    fn hash_crc256_long_dispatch(&self, seed: u32) -> [u64; 4] {
+        #[cfg(all(
+            feature = "intrinsics",
+            target_arch = "x86_64",
+            target_feature = "sse4.2"
+        ))]
+        return self.hash_crc256_long::<Sse42>(seed);
+
+        #[cfg(all(
+            feature = "intrinsics",
+            feature = "std",
+            target_arch = "x86_64",
+            not(target_feature = "sse4.2")
+        ))]
+        if sse42_available() {
+            #[allow(unsafe_code)]
+            // SAFETY: SSE4.2 support was just verified.
+            return unsafe { hash_crc256_long_sse42(self, seed) };
+        }
+
+        self.hash_crc256_long::<Software>(seed)
+    }
+
+    fn hash_crc256_with(&self, long: impl FnOnce(&Input<'_>, u32) -> [u64; 4]) -> [u64; 4] {
+        if self.len() >= 240 {
+            long(self, 0)
+        } else {
+            let mut buf = [0; 240];
+            buf[..self.len()].copy_from_slice(self.0);
+            long(&Input(&buf), !(self.len() as u32))
+        }
+    }
+
+    fn hash_crc256(&self) -> [u64; 4] {
+        self.hash_crc256_with(|input, seed| input.hash_crc256_long_dispatch(seed))
+    }
+
+    #[inline(always)]
+    fn hash_crc256_long<C: Crc32>(&self, seed: u32) -> [u64; 4] {
+        let mut result = [0; 4];
+        let mut a = self.fetch64(56).wrapping_add(K0);
+        let mut b = self.fetch64(96).wrapping_add(K0);
+        result[0] = hash_len_16_u64(b, self.len() as u64);
+        let mut c = result[0];
+        result[1] = self
+            .fetch64(120)
+            .wrapping_mul(K0)
+            .wrapping_add(self.len() as u64);
+        let mut d = result[1];
+        let mut e = self.fetch64(184).wrapping_add(u64::from(seed));
+        let mut f = 0_u64;
+        let mut g = 0_u64;
+        let mut h = c.wrapping_add(d);
+        let mut x = u64::from(seed);
+        let mut y = 0;
+        let mut z = 0;
+        let mut offset = 0;
+
+        macro_rules! chunk {
+            ($offset:expr, $r:expr) => {
+                let offset = $offset;
+                permute3(&mut x, &mut z, &mut y);
+                b = b.wrapping_add(self.fetch64(offset));
+                c = c.wrapping_add(self.fetch64(offset + 8));
+                d = d.wrapping_add(self.fetch64(offset + 16));
+                e = e.wrapping_add(self.fetch64(offset + 24));
+                f = f.wrapping_add(self.fetch64(offset + 32));
+                a = a.wrapping_add(b);
+                h = h.wrapping_add(f);
+                b = b.wrapping_add(c);
+                f = f.wrapping_add(d);
+                g = g.wrapping_add(e);
+                e = e.wrapping_add(z);
+                g = g.wrapping_add(x);
+                z = C::crc32_u64(z, b.wrapping_add(g));
+                y = C::crc32_u64(y, e.wrapping_add(h));
+                x = C::crc32_u64(x, f.wrapping_add(a));
+                e = rotate64(e, $r);
+                c = c.wrapping_add(e);
+            };
+        }
+
+        // 240 bytes of input per iteration.
+        let mut len = self.len();
+        while len >= 240 {
+            chunk!(offset, 0);
+            offset += 40;
+            permute3(&mut a, &mut h, &mut c);
+            chunk!(offset, 33);
+            offset += 40;
+            permute3(&mut a, &mut h, &mut f);
+            chunk!(offset, 0);
+            offset += 40;
+            permute3(&mut b, &mut h, &mut f);
+            chunk!(offset, 42);
+            offset += 40;
+            permute3(&mut b, &mut h, &mut d);
+            chunk!(offset, 0);
+            offset += 40;
+            permute3(&mut b, &mut h, &mut e);
+            chunk!(offset, 33);
+            offset += 40;
+            permute3(&mut a, &mut h, &mut e);
+            len -= 240;
+        }
+
+        while len >= 40 {
+            chunk!(offset, 29);
+            offset += 40;
+            e ^= rotate64(a, 20);
+            h = h.wrapping_add(rotate64(b, 30));
+            g ^= rotate64(c, 40);
+            f = f.wrapping_add(rotate64(d, 34));
+            permute3(&mut c, &mut h, &mut g);
+            len -= 40;
+        }
+        if len > 0 {
+            chunk!(offset + len - 40, 33);
+            e ^= rotate64(a, 43);
+            h = h.wrapping_add(rotate64(b, 42));
+            g ^= rotate64(c, 41);
+            f = f.wrapping_add(rotate64(d, 40));
+        }
+
+        result[0] ^= h;
+        result[1] ^= g;
+        g = g.wrapping_add(h);
+        a = hash_len_16_u64(a, g.wrapping_add(z));
+        x = x.wrapping_add(y << 32);
+        b = b.wrapping_add(x);
+        c = hash_len_16_u64(c, z).wrapping_add(h);
+        d = hash_len_16_u64(d, e.wrapping_add(result[0]));
+        g = g.wrapping_add(e);
+        h = h.wrapping_add(hash_len_16_u64(x, f));
+        e = hash_len_16_u64(a, d).wrapping_add(g);
+        z = hash_len_16_u64(b, c).wrapping_add(a);
+        y = hash_len_16_u64(g, h).wrapping_add(c);
+        result[0] = e.wrapping_add(z).wrapping_add(y).wrapping_add(x);
+        a = shift_mix(a.wrapping_add(y).wrapping_mul(K0))
+            .wrapping_mul(K0)
+            .wrapping_add(b);
+        result[1] = result[1].wrapping_add(a.wrapping_add(result[0]));
+        a = shift_mix(a.wrapping_mul(K0))
+            .wrapping_mul(K0)
+            .wrapping_add(c);
+        result[2] = a.wrapping_add(result[1]);
+        a = shift_mix(a.wrapping_add(e).wrapping_mul(K0)).wrapping_mul(K0);
+        result[3] = a.wrapping_add(result[2]);
+        result
+    }
+
+    fn hash_crc128(&self) -> (u64, u64) {
+        if self.len() <= 900 {
+            self.hash128()
+        } else {
+            let result = self.hash_crc256();
+            (result[2], result[3])
+        }
+    }
+
+    fn hash_crc128_with_seed(&self, seed: (u64, u64)) -> (u64, u64) {
+        if self.len() <= 900 {
+            self.hash128_with_seed(seed)
+        } else {
+            let result = self.hash_crc256();
+            let u = seed.1.wrapping_add(result[0]);
+            let v = seed.0.wrapping_add(result[1]);
+            (
+                hash_len_16_u64(u, v.wrapping_add(result[2])),
+                hash_len_16_u64(rotate64(v, 32), u.wrapping_mul(K0).wrapping_add(result[3])),
+            )
+        }
+    }
+}
+
+/// Hashes `data` using the CRC32-accelerated variants of the
+/// [CityHash][cityhash] algorithm.
+///
+/// The exact implementation is decided upon by `T`:
+///
+/// |     `T`     | C++ Function Equivalent |
+/// |-------------|-------------------------|
+/// | `u128`      | `CityHashCrc128`        |
+/// | `[u64; 4]`  | `CityHashCrc256`        |
+///
+/// When the `intrinsics` feature is enabled and the target supports SSE4.2,
+/// the `crc32` instruction is used. Otherwise, a portable implementation that
+/// produces identical output is used.
+///
+/// For inputs of 900 bytes or fewer, `CityHashCrc128` produces the same output
+/// as `CityHash128`.
+///
+/// ```rust
+/// let crc128: u128 = cityhasher::hash_crc("hello");
+/// let crc256: [u64; 4] = cityhasher::hash_crc("hello");
+///
+/// let hash128: u128 = cityhasher::hash("hello");
+/// assert_eq!(crc128, hash128);
+/// assert_ne!(crc256, [0; 4]);
+/// ```
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_crc<T>(data: impl AsRef<[u8]>) -> T
+where
+    T: FromCityHashCrc,
+{
+    T::from_city_hash_crc(data.as_ref())
+}
+
+/// Hashes `data` with a seed value, using the CRC32-accelerated variants of
+/// the [CityHash][cityhash] algorithm.
+///
+/// The exact implementation is decided upon by `T`:
+///
+/// |  `T`  | C++ Function Equivalent  |
+/// |-------|--------------------------|
+/// | `u128`| `CityHashCrc128WithSeed` |
+///
+/// ```rust
+/// let hello: u128 = cityhasher::hash_crc("hello");
+/// let hello_with_seed: u128 = cityhasher::hash_crc_with_seed("hello", 1);
+///
+/// assert_ne!(hello, hello_with_seed);
+/// ```
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_crc_with_seed<T>(data: impl AsRef<[u8]>, seed: T) -> T
+where
+    T: FromSeededCityHashCrc,
+{
+    T::from_city_hash_crc_with_seed(data.as_ref(), seed)
+}
+
+/// A type that can be produced by the CRC32-accelerated CityHash algorithm.
+pub trait FromCityHashCrc: sealed::Sealed {}
+
+/// A type that can be produced by the CRC32-accelerated CityHash algorithm
+/// using a seeded input.
+pub trait FromSeededCityHashCrc: sealed::SealedSeeded {}
+
+mod sealed {
+    pub trait Sealed {
+        fn from_city_hash_crc(data: &[u8]) -> Self;
+    }
+
+    pub trait SealedSeeded {
+        fn from_city_hash_crc_with_seed(data: &[u8], seed: Self) -> Self;
+    }
+}
+
+impl FromCityHashCrc for u128 {}
+
+impl sealed::Sealed for u128 {
+    #[inline]
+    fn from_city_hash_crc(data: &[u8]) -> Self {
+        u128_from_halves(Input(data).hash_crc128())
+    }
+}
+
+impl FromSeededCityHashCrc for u128 {}
+
+impl sealed::SealedSeeded for u128 {
+    #[inline]
+    fn from_city_hash_crc_with_seed(data: &[u8], seed: Self) -> Self {
+        u128_from_halves(Input(data).hash_crc128_with_seed(u128_to_halves(seed)))
+    }
+}
+
+impl FromCityHashCrc for [u64; 4] {}
+
+impl sealed::Sealed for [u64; 4] {
+    #[inline]
+    fn from_city_hash_crc(data: &[u8]) -> Self {
+        Input(data).hash_crc256()
+    }
+}
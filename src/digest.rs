@@ -0,0 +1,174 @@
+use core::hash::Hasher;
+
+use digest::consts::{U16, U8};
+use digest::{FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+use crate::{hash, hash_with_seed, BufferingCityHasher};
+
+/// A streaming `CityHash64` implementation of the [RustCrypto `digest`
+/// traits][digest].
+///
+/// The output is the same value as [`hash::<u64>`](crate::hash) (or
+/// [`hash_with_seed::<u64>`](crate::hash_with_seed) when seeded) over all bytes
+/// passed to [`Update::update`], encoded as 8 big-endian bytes. Every byte is
+/// retained until the hasher is finalized or reset, as described by
+/// [`BufferingCityHasher`'s memory usage](BufferingCityHasher#memory-usage),
+/// which this type wraps.
+///
+/// **CityHash is not a cryptographic hash function.** Its collisions can be
+/// found deliberately. This type implements [`HashMarker`] so that it can be
+/// used with generic `digest` code, but wrapping it in `Hmac` or using it for
+/// signatures or content addressing of untrusted data provides no security.
+///
+/// ```rust
+/// use cityhasher::CityHash64Digest;
+/// use digest::Digest;
+///
+/// let mut hasher = CityHash64Digest::new();
+/// hasher.update(b"hello, ");
+/// hasher.update(b"world");
+///
+/// let expected: u64 = cityhasher::hash("hello, world");
+/// assert_eq!(hasher.finalize()[..], expected.to_be_bytes());
+/// ```
+///
+/// [digest]: https://docs.rs/digest
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct CityHash64Digest(BufferingCityHasher);
+
+impl CityHash64Digest {
+    /// Returns a new hasher with no seed.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(BufferingCityHasher::new())
+    }
+
+    /// Returns a hasher that incorporates `seed` into the hashes produced.
+    #[must_use]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self(BufferingCityHasher::with_seed(seed))
+    }
+}
+
+impl HashMarker for CityHash64Digest {}
+
+impl OutputSizeUser for CityHash64Digest {
+    type OutputSize = U8;
+}
+
+impl Update for CityHash64Digest {
+    fn update(&mut self, data: &[u8]) {
+        self.0.write(data);
+    }
+}
+
+impl FixedOutput for CityHash64Digest {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.0.finish().to_be_bytes());
+    }
+}
+
+impl Reset for CityHash64Digest {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl FixedOutputReset for CityHash64Digest {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.0.finish().to_be_bytes());
+        self.0.reset();
+    }
+}
+
+/// A streaming `CityHash128` implementation of the [RustCrypto `digest`
+/// traits][digest].
+///
+/// The output is the same value as [`hash::<u128>`](crate::hash) (or
+/// [`hash_with_seed::<u128>`](crate::hash_with_seed) when seeded) over all
+/// bytes passed to [`Update::update`], encoded as 16 big-endian bytes:
+/// `Uint128High64` followed by `Uint128Low64`. Like [`CityHash64Digest`], it
+/// retains every byte until it is finalized or reset (see
+/// [`BufferingCityHasher`'s memory usage](BufferingCityHasher#memory-usage)).
+///
+/// **This is not a cryptographic hash function**, despite implementing
+/// [`HashMarker`]. A 128-bit output does not make CityHash collision
+/// resistant, so it must not be used with `Hmac` or anywhere an attacker
+/// controls the input.
+///
+/// ```rust
+/// use cityhasher::CityHash128Digest;
+/// use digest::Digest;
+///
+/// let expected: u128 = cityhasher::hash("hello, world");
+/// assert_eq!(
+///     CityHash128Digest::digest(b"hello, world")[..],
+///     expected.to_be_bytes()
+/// );
+/// ```
+///
+/// [digest]: https://docs.rs/digest
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct CityHash128Digest {
+    // The buffer's own seed is unused, because it is only 64 bits.
+    buffer: BufferingCityHasher,
+    seed: Option<u128>,
+}
+
+impl CityHash128Digest {
+    /// Returns a new hasher with no seed.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buffer: BufferingCityHasher::new(),
+            seed: None,
+        }
+    }
+
+    /// Returns a hasher that incorporates `seed` into the hashes produced.
+    #[must_use]
+    pub const fn with_seed(seed: u128) -> Self {
+        Self {
+            buffer: BufferingCityHasher::new(),
+            seed: Some(seed),
+        }
+    }
+
+    fn finish(&self) -> u128 {
+        match self.seed {
+            Some(seed) => hash_with_seed(self.buffer.bytes(), seed),
+            None => hash(self.buffer.bytes()),
+        }
+    }
+}
+
+impl HashMarker for CityHash128Digest {}
+
+impl OutputSizeUser for CityHash128Digest {
+    type OutputSize = U16;
+}
+
+impl Update for CityHash128Digest {
+    fn update(&mut self, data: &[u8]) {
+        self.buffer.write(data);
+    }
+}
+
+impl FixedOutput for CityHash128Digest {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.finish().to_be_bytes());
+    }
+}
+
+impl Reset for CityHash128Digest {
+    fn reset(&mut self) {
+        self.buffer.reset();
+    }
+}
+
+impl FixedOutputReset for CityHash128Digest {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.finish().to_be_bytes());
+        self.reset();
+    }
+}
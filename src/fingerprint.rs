@@ -0,0 +1,445 @@
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, LowerHex};
+use core::str::FromStr;
+
+use crate::{sealed, u128_from_halves, u128_to_halves, FromCityHash, FromSeededCityHash};
+
+/// A 64-bit hash produced by `CityHash64`.
+///
+/// Fingerprints are formatted and parsed as 16 lowercase hexadecimal digits,
+/// and are encoded as 8 big-endian bytes by
+/// [`to_be_bytes()`](Self::to_be_bytes).
+///
+/// ```rust
+/// use cityhasher::Fingerprint64;
+///
+/// let fingerprint: Fingerprint64 = cityhasher::hash("world");
+/// assert_eq!(fingerprint.to_string(), "e41a54435eb8b46e");
+/// assert_eq!(fingerprint, "e41a54435eb8b46e".parse().unwrap());
+/// let value: u64 = cityhasher::hash("world");
+/// assert_eq!(u64::from(fingerprint), value);
+/// ```
+#[derive(Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Fingerprint64(u64);
+
+impl Fingerprint64 {
+    /// Returns a fingerprint containing `value`.
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the value of this fingerprint.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Returns this fingerprint as 8 big-endian bytes.
+    #[must_use]
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the fingerprint encoded as big-endian `bytes`.
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Self(u64::from_be_bytes(bytes))
+    }
+
+    /// Returns the fingerprint encoded as big-endian `bytes`, as produced by
+    /// [`to_be_bytes()`](Self::to_be_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not exactly 8 bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FingerprintError> {
+        match <[u8; 8]>::try_from(bytes) {
+            Ok(bytes) => Ok(Self::from_be_bytes(bytes)),
+            Err(_) => Err(FingerprintError { bytes: 8 }),
+        }
+    }
+
+    fn encode_hex(self, out: &mut [u8; 16]) -> &str {
+        encode_hex(self.0, out)
+    }
+}
+
+impl From<u64> for Fingerprint64 {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Fingerprint64> for u64 {
+    fn from(fingerprint: Fingerprint64) -> Self {
+        fingerprint.0
+    }
+}
+
+impl Display for Fingerprint64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.encode_hex(&mut [0; 16]))
+    }
+}
+
+impl LowerHex for Fingerprint64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        Display::fmt(self, f)
+    }
+}
+
+impl Debug for Fingerprint64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fingerprint64({self})")
+    }
+}
+
+impl FromStr for Fingerprint64 {
+    type Err = FingerprintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        match decode_hex(s.as_bytes()) {
+            Some(value) => Ok(Self(value)),
+            None => Err(FingerprintError { bytes: 8 }),
+        }
+    }
+}
+
+impl FromCityHash for Fingerprint64 {}
+
+impl sealed::Sealed for Fingerprint64 {
+    #[inline]
+    fn from_city_hash(data: &[u8]) -> Self {
+        Self(u64::from_city_hash(data))
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn from_city_hash64() -> Option<fn(u64) -> Self> {
+        Some(Self)
+    }
+}
+
+impl FromSeededCityHash for Fingerprint64 {}
+
+impl sealed::SealedSeeded for Fingerprint64 {
+    #[inline]
+    fn from_city_hash_with_seed(data: &[u8], seed: Self) -> Self {
+        Self(u64::from_city_hash_with_seed(data, seed.0))
+    }
+}
+
+/// A 128-bit hash produced by `CityHash128`.
+///
+/// Fingerprints are formatted and parsed as 32 lowercase hexadecimal digits,
+/// following the reference implementation's convention of printing
+/// `Uint128Low64` followed by `Uint128High64`. They are encoded as bytes by
+/// [`to_be_bytes()`](Self::to_be_bytes) and ordered in the same way, so the
+/// bytes are the hexadecimal digits decoded, and fingerprints sort like their
+/// strings. This differs from the `u128` value returned by
+/// [`get()`](Self::get), which has `Uint128High64` in its upper 64 bits.
+///
+/// ```rust
+/// use cityhasher::Fingerprint128;
+///
+/// let fingerprint: Fingerprint128 = cityhasher::hash("world");
+/// let value: u128 = cityhasher::hash("world");
+/// assert_eq!(fingerprint.get(), value);
+/// assert_eq!(
+///     fingerprint.to_string(),
+///     format!("{:016x}{:016x}", fingerprint.low(), fingerprint.high())
+/// );
+/// assert_eq!(fingerprint, fingerprint.to_string().parse().unwrap());
+///
+/// let bytes = fingerprint.to_be_bytes();
+/// assert_eq!(bytes[..8], fingerprint.low().to_be_bytes());
+/// assert_eq!(bytes[8..], fingerprint.high().to_be_bytes());
+/// assert_ne!(bytes, value.to_be_bytes());
+/// ```
+#[derive(Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Fingerprint128(u128);
+
+impl Fingerprint128 {
+    /// Returns a fingerprint containing `value`, with `Uint128High64` in the
+    /// upper 64 bits and `Uint128Low64` in the lower 64 bits.
+    #[must_use]
+    pub const fn new(value: u128) -> Self {
+        Self(value)
+    }
+
+    /// Returns a fingerprint from the reference `Uint128Low64` and
+    /// `Uint128High64` values.
+    #[must_use]
+    pub const fn from_halves(low: u64, high: u64) -> Self {
+        Self(u128_from_halves((low, high)))
+    }
+
+    /// Returns the value of this fingerprint, with `Uint128High64` in the upper
+    /// 64 bits and `Uint128Low64` in the lower 64 bits.
+    #[must_use]
+    pub const fn get(self) -> u128 {
+        self.0
+    }
+
+    /// Returns the reference `Uint128Low64` of this fingerprint.
+    #[must_use]
+    pub const fn low(self) -> u64 {
+        u128_to_halves(self.0).0
+    }
+
+    /// Returns the reference `Uint128High64` of this fingerprint.
+    #[must_use]
+    pub const fn high(self) -> u64 {
+        u128_to_halves(self.0).1
+    }
+
+    /// Returns this fingerprint as the 8 big-endian bytes of `Uint128Low64`
+    /// followed by the 8 big-endian bytes of `Uint128High64`, in the same order
+    /// as its hexadecimal digits.
+    #[must_use]
+    pub const fn to_be_bytes(self) -> [u8; 16] {
+        let (low, high) = u128_to_halves(self.0);
+        ((low as u128) << 64 | high as u128).to_be_bytes()
+    }
+
+    /// Returns the fingerprint encoded as `bytes` by
+    /// [`to_be_bytes()`](Self::to_be_bytes).
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let swapped = u128::from_be_bytes(bytes);
+        Self(u128_from_halves(((swapped >> 64) as u64, swapped as u64)))
+    }
+
+    /// Returns the fingerprint encoded as `bytes` by
+    /// [`to_be_bytes()`](Self::to_be_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not exactly 16 bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FingerprintError> {
+        match <[u8; 16]>::try_from(bytes) {
+            Ok(bytes) => Ok(Self::from_be_bytes(bytes)),
+            Err(_) => Err(FingerprintError { bytes: 16 }),
+        }
+    }
+
+    /// Writes `Uint128Low64` followed by `Uint128High64` as hexadecimal digits.
+    fn encode_hex(self, out: &mut [u8; 32]) -> &str {
+        let (low, high) = out.split_at_mut(16);
+        encode_hex(self.low(), low);
+        encode_hex(self.high(), high);
+        core::str::from_utf8(out).unwrap_or_default()
+    }
+}
+
+/// Orders fingerprints by `Uint128Low64`, then `Uint128High64`, like their
+/// strings and bytes.
+impl Ord for Fingerprint128 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.low(), self.high()).cmp(&(other.low(), other.high()))
+    }
+}
+
+impl PartialOrd for Fingerprint128 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<u128> for Fingerprint128 {
+    fn from(value: u128) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Fingerprint128> for u128 {
+    fn from(fingerprint: Fingerprint128) -> Self {
+        fingerprint.0
+    }
+}
+
+impl Display for Fingerprint128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.encode_hex(&mut [0; 32]))
+    }
+}
+
+impl LowerHex for Fingerprint128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        Display::fmt(self, f)
+    }
+}
+
+impl Debug for Fingerprint128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fingerprint128({self})")
+    }
+}
+
+impl FromStr for Fingerprint128 {
+    type Err = FingerprintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+        if s.len() == 32 {
+            let (low, high) = s.split_at(16);
+            if let (Some(low), Some(high)) = (decode_hex(low), decode_hex(high)) {
+                return Ok(Self::from_halves(low, high));
+            }
+        }
+        Err(FingerprintError { bytes: 16 })
+    }
+}
+
+impl FromCityHash for Fingerprint128 {}
+
+impl sealed::Sealed for Fingerprint128 {
+    #[inline]
+    fn from_city_hash(data: &[u8]) -> Self {
+        Self(u128::from_city_hash(data))
+    }
+}
+
+impl FromSeededCityHash for Fingerprint128 {}
+
+impl sealed::SealedSeeded for Fingerprint128 {
+    #[inline]
+    fn from_city_hash_with_seed(data: &[u8], seed: Self) -> Self {
+        Self(u128::from_city_hash_with_seed(data, seed.0))
+    }
+}
+
+/// An error parsing or decoding a fingerprint.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct FingerprintError {
+    bytes: usize,
+}
+
+impl Display for FingerprintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid fingerprint: expected {} hexadecimal digits or {} bytes",
+            self.bytes * 2,
+            self.bytes
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FingerprintError {}
+
+/// Writes `value` as 16 lowercase hexadecimal digits to `out`.
+fn encode_hex(value: u64, out: &mut [u8]) -> &str {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    for (i, out) in out.iter_mut().enumerate() {
+        *out = DIGITS[(value >> (60 - i * 4)) as usize & 0xf];
+    }
+    core::str::from_utf8(out).unwrap_or_default()
+}
+
+/// Parses exactly 16 hexadecimal digits.
+fn decode_hex(digits: &[u8]) -> Option<u64> {
+    if digits.len() != 16 {
+        return None;
+    }
+    digits.iter().try_fold(0, |value, &digit| {
+        let digit = char::from(digit).to_digit(16)?;
+        Some(value << 4 | u64::from(digit))
+    })
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use core::fmt;
+
+    use serde::de::{Error, SeqAccess, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{Fingerprint128, Fingerprint64};
+
+    macro_rules! impl_serde {
+        ($type:ident, $bytes:literal) => {
+            /// Serializes as a hexadecimal string in human-readable formats, and as
+            /// the bytes returned by `to_be_bytes()` otherwise.
+            impl Serialize for $type {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    if serializer.is_human_readable() {
+                        serializer.serialize_str(self.encode_hex(&mut [0; $bytes * 2]))
+                    } else {
+                        serializer.serialize_bytes(&self.to_be_bytes())
+                    }
+                }
+            }
+
+            /// Deserializes from a hexadecimal string in human-readable formats,
+            /// and from the bytes returned by `to_be_bytes()` otherwise.
+            impl<'de> Deserialize<'de> for $type {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    struct FingerprintVisitor;
+
+                    impl<'de> Visitor<'de> for FingerprintVisitor {
+                        type Value = $type;
+
+                        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                            write!(f, "{} hexadecimal digits or {} bytes", $bytes * 2, $bytes)
+                        }
+
+                        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                        where
+                            E: Error,
+                        {
+                            v.parse().map_err(E::custom)
+                        }
+
+                        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+                        where
+                            E: Error,
+                        {
+                            $type::from_bytes(v).map_err(E::custom)
+                        }
+
+                        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                        where
+                            A: SeqAccess<'de>,
+                        {
+                            let mut bytes = [0_u8; $bytes];
+                            for (i, byte) in bytes.iter_mut().enumerate() {
+                                *byte = seq
+                                    .next_element()?
+                                    .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+                            }
+                            if seq.next_element::<u8>()?.is_some() {
+                                return Err(A::Error::invalid_length($bytes + 1, &self));
+                            }
+                            Ok($type::from_be_bytes(bytes))
+                        }
+                    }
+
+                    if deserializer.is_human_readable() {
+                        deserializer.deserialize_str(FingerprintVisitor)
+                    } else {
+                        deserializer.deserialize_bytes(FingerprintVisitor)
+                    }
+                }
+            }
+        };
+    }
+
+    impl_serde!(Fingerprint64, 8);
+    impl_serde!(Fingerprint128, 16);
+}
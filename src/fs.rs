@@ -0,0 +1,219 @@
+//! Functions for hashing the contents of files.
+//!
+//! Regular files of at least 64 KiB are hashed without reading them into
+//! memory when the output is produced by `CityHash64`, such as `u64`. The final
+//! 64 bytes of the file are read first, as `CityHash64` requires, and then the
+//! file is read from the beginning in 1 MiB blocks. With the `mmap` feature,
+//! these files are memory mapped and hashed in place instead, regardless of the
+//! output.
+//!
+//! `CityHash32` and `CityHash128` read their input out of order, so without
+//! the `mmap` feature, files hashed with those outputs are read into memory
+//! before being hashed. So are smaller files and anything that is not a
+//! regular file, such as a pipe. In every case, the results are identical to
+//! calling [`hash`](crate::hash) with the file's contents.
+//!
+//! # Concurrent modification
+//!
+//! If a file is modified while it is being hashed, the resulting hash is
+//! unspecified. A file that is truncated while being read in blocks produces an
+//! error, but if a memory-mapped file is truncated, some platforms terminate
+//! the process. Only enable the `mmap` feature if files being hashed are not
+//! modified concurrently.
+
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::vec;
+use std::vec::Vec;
+
+use crate::city::{Hash64Long, Input};
+use crate::FromCityHash;
+
+/// The smallest file that is hashed in blocks or memory mapped, rather than
+/// read into memory.
+const LARGE_FILE: u64 = 64 * 1024;
+
+/// The number of bytes read at a time from large files, which is also the
+/// interval at which progress is reported. This must be a multiple of 64.
+const BLOCK_SIZE: usize = 1 << 20;
+
+/// Hashes the contents of the file at `path` using the [CityHash][cityhash]
+/// algorithm.
+///
+/// The exact implementation is decided upon by `T`, as described by
+/// [`hash`](crate::hash).
+///
+/// ```rust
+/// # let path = std::env::temp_dir().join("cityhasher-fs-hash-file");
+/// std::fs::write(&path, "hello")?;
+///
+/// let file: u64 = cityhasher::fs::hash_file(&path)?;
+/// let expected: u64 = cityhasher::hash("hello");
+/// assert_eq!(file, expected);
+/// # std::fs::remove_file(&path)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns any error that occurs while opening or reading the file.
+///
+/// [cityhash]: https://github.com/google/cityhash
+pub fn hash_file<T>(path: impl AsRef<Path>) -> io::Result<T>
+where
+    T: FromCityHash,
+{
+    hash_file_with_progress(path, |_| {})
+}
+
+/// Hashes the contents of the file at `path` using the [CityHash][cityhash]
+/// algorithm, invoking `progress` as the file is hashed.
+///
+/// `progress` is invoked with the total number of bytes hashed so far. For
+/// outputs produced by `CityHash64`, such as `u64`, it is invoked after every
+/// 1 MiB of large files. It is always invoked once the entire file has been
+/// hashed.
+///
+/// ```rust
+/// # let path = std::env::temp_dir().join("cityhasher-fs-hash-file-with-progress");
+/// std::fs::write(&path, vec![0; 3 << 20])?;
+///
+/// let mut hashed = Vec::new();
+/// let file: u64 =
+///     cityhasher::fs::hash_file_with_progress(&path, |bytes| hashed.push(bytes))?;
+/// assert_eq!(hashed, [1 << 20, 2 << 20, (3 << 20) - 64, 3 << 20]);
+/// # std::fs::remove_file(&path)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns any error that occurs while opening or reading the file.
+///
+/// [cityhash]: https://github.com/google/cityhash
+pub fn hash_file_with_progress<T>(
+    path: impl AsRef<Path>,
+    mut progress: impl FnMut(u64),
+) -> io::Result<T>
+where
+    T: FromCityHash,
+{
+    let mut file = File::open(path)?;
+    let metadata = file.metadata()?;
+    if metadata.is_file() && metadata.len() >= LARGE_FILE {
+        #[cfg(feature = "mmap")]
+        if let Some(map) = map(&file) {
+            return Ok(hash_with_progress(&map, &mut progress));
+        }
+
+        if let Some(from_city_hash64) = T::from_city_hash64() {
+            let hash = hash64_file(&mut file, metadata.len(), &mut progress)?;
+            progress(metadata.len());
+            return Ok(from_city_hash64(hash));
+        }
+    }
+
+    let mut data = Vec::new();
+    file.read_to_end(&mut data)?;
+    Ok(hash_with_progress(&data, &mut progress))
+}
+
+fn hash_with_progress<T>(data: &[u8], mut progress: impl FnMut(u64)) -> T
+where
+    T: FromCityHash,
+{
+    let hash = match T::from_city_hash64() {
+        Some(from_city_hash64) if data.len() > 64 => {
+            let (_, tail) = data.split_at(data.len() - 64);
+            let mut blocks = Hash64Blocks::new(tail, data.len() as u64);
+            for block in data[..blocks.remaining() as usize].chunks(BLOCK_SIZE) {
+                blocks.update(block);
+                progress(blocks.hashed);
+            }
+            from_city_hash64(blocks.finish())
+        }
+        _ => T::from_city_hash(data),
+    };
+    progress(data.len() as u64);
+    hash
+}
+
+/// Returns the `CityHash64` of `file`, which is `len` bytes long and longer
+/// than 64 bytes, by reading it in blocks.
+fn hash64_file(file: &mut File, len: u64, mut progress: impl FnMut(u64)) -> io::Result<u64> {
+    let mut tail = [0; 64];
+    file.seek(SeekFrom::Start(len - 64))?;
+    file.read_exact(&mut tail)?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let mut blocks = Hash64Blocks::new(&tail, len);
+    let mut buffer = vec![0; BLOCK_SIZE];
+    while blocks.remaining() > 0 {
+        let block_len = blocks.remaining().min(BLOCK_SIZE as u64) as usize;
+        let block = &mut buffer[..block_len];
+        file.read_exact(block)?;
+        blocks.update(block);
+        progress(blocks.hashed);
+    }
+    Ok(blocks.finish())
+}
+
+/// `CityHash64` of an input longer than 64 bytes, computed from its final 64
+/// bytes and then its contents in blocks.
+struct Hash64Blocks {
+    tail: [u8; 64],
+    len: u64,
+    state: Option<Hash64Long>,
+    /// The number of bytes passed to [`update()`](Self::update) so far.
+    hashed: u64,
+}
+
+impl Hash64Blocks {
+    fn new(tail: &[u8], len: u64) -> Self {
+        Self {
+            tail: tail.try_into().expect("tail is 64 bytes"),
+            len,
+            state: None,
+            hashed: 0,
+        }
+    }
+
+    /// Returns the number of bytes that remain to be passed to
+    /// [`update()`](Self::update). The final byte of the input is only read
+    /// through the tail.
+    fn remaining(&self) -> u64 {
+        (self.len - 1) / 64 * 64 - self.hashed
+    }
+
+    /// Updates the state with the next `block` of the input, whose length is a
+    /// multiple of 64 bytes.
+    fn update(&mut self, block: &[u8]) {
+        for chunk in block.chunks_exact(64) {
+            let chunk = Input(chunk);
+            self.state
+                .get_or_insert_with(|| {
+                    Hash64Long::new(&Input(&self.tail), self.len, chunk.fetch64(0))
+                })
+                .update(&chunk);
+        }
+        self.hashed += block.len() as u64;
+    }
+
+    fn finish(&self) -> u64 {
+        self.state
+            .as_ref()
+            .expect("inputs over 64 bytes have a chunk")
+            .finish()
+    }
+}
+
+/// Memory maps `file`, returning `None` if it should be read instead.
+#[cfg(feature = "mmap")]
+fn map(file: &File) -> Option<memmap2::Mmap> {
+    #[allow(unsafe_code)]
+    // SAFETY: The mapping is only read. Concurrent modification of the file
+    // is documented in the module documentation.
+    let map = unsafe { memmap2::Mmap::map(file) };
+    map.ok()
+}
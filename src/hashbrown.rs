@@ -0,0 +1,29 @@
+//! Type aliases for the [hashbrown](https://docs.rs/hashbrown) collections
+//! that hash using [`CityHasher`].
+//!
+//! Unlike the aliases at the crate root, these do not require the `std`
+//! feature, allowing them to be used in `no_std` environments with an
+//! allocator.
+//!
+//! ```rust
+//! use cityhasher::hashbrown::HashMap;
+//!
+//! let mut map: HashMap<_, _> = HashMap::default();
+//! map.insert(1, "hello");
+//! assert_eq!(map.get(&1), Some(&"hello"));
+//! ```
+
+use crate::{CityHasher, RandomCityState};
+
+/// A type alias for [`hashbrown::HashMap`] that hashes its keys using
+/// [`CityHasher`].
+pub type HashMap<K, V, S = CityHasher> = ::hashbrown::HashMap<K, V, S>;
+/// A type alias for [`hashbrown::HashSet`] that hashes its members using
+/// [`CityHasher`].
+pub type HashSet<K, S = CityHasher> = ::hashbrown::HashSet<K, S>;
+/// A type alias for [`hashbrown::HashMap`] that hashes its keys using
+/// [`CityHasher`] with a random seed for each map.
+pub type RandomHashMap<K, V> = ::hashbrown::HashMap<K, V, RandomCityState>;
+/// A type alias for [`hashbrown::HashSet`] that hashes its members using
+/// [`CityHasher`] with a random seed for each set.
+pub type RandomHashSet<K> = ::hashbrown::HashSet<K, RandomCityState>;
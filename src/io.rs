@@ -0,0 +1,418 @@
+use std::boxed::Box;
+use std::io::{self, Read, Write};
+
+use crate::city::{Hash128Long, Input, K0, K1};
+use crate::{u128_from_halves, BufferingCityHasher};
+
+/// A [`Write`] implementation that hashes the bytes written through it to an
+/// inner writer.
+///
+/// [`finish64()`](Self::finish64) and [`finish128()`](Self::finish128) return
+/// the same values as [`hash`](crate::hash) over all bytes that the inner
+/// writer has accepted. Bytes rejected by the inner writer, either by an error
+/// or a short write, are not hashed.
+///
+/// A writer created with [`new()`](Self::new) retains every byte written, like
+/// [`BufferingCityHasher`], because CityHash reads the length and the end of
+/// its input first. If the number of bytes that will be written is known in
+/// advance, [`with_len()`](Self::with_len) computes `CityHash128` as bytes are
+/// written instead, using a fixed amount of memory.
+///
+/// ```rust
+/// use std::io::Write;
+///
+/// use cityhasher::CityHashWriter;
+///
+/// let mut writer = CityHashWriter::new(Vec::new());
+/// writer.write_all(b"hello, ").unwrap();
+/// writer.write_all(b"world").unwrap();
+///
+/// let expected: u64 = cityhasher::hash("hello, world");
+/// assert_eq!(writer.finish64(), Some(expected));
+/// assert_eq!(writer.into_inner(), b"hello, world");
+///
+/// let mut writer = CityHashWriter::with_len(Vec::new(), 12);
+/// writer.write_all(b"hello, world").unwrap();
+///
+/// let expected: u128 = cityhasher::hash("hello, world");
+/// assert_eq!(writer.finish128(), Some(expected));
+/// ```
+#[derive(Debug)]
+pub struct CityHashWriter<W> {
+    inner: W,
+    hasher: Tee,
+}
+
+impl<W> CityHashWriter<W>
+where
+    W: Write,
+{
+    /// Returns a writer that hashes all bytes written to `inner`, retaining
+    /// them in memory.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: Tee::Buffered(BufferingCityHasher::new()),
+        }
+    }
+
+    /// Returns a writer that computes `CityHash128` of the `len` bytes that will
+    /// be written to `inner`, without retaining them.
+    ///
+    /// Attempting to write more than `len` bytes returns an error.
+    pub fn with_len(inner: W, len: u64) -> Self {
+        Self {
+            inner,
+            hasher: Tee::Streamed(Box::new(Hash128Stream::new(len))),
+        }
+    }
+
+    /// Returns the 64-bit hash of the bytes written, or `None` if this writer
+    /// was created with [`with_len()`](Self::with_len).
+    #[must_use]
+    pub fn finish64(&self) -> Option<u64> {
+        self.hasher.finish64()
+    }
+
+    /// Returns the 128-bit hash of the bytes written, or `None` if this writer
+    /// was created with [`with_len()`](Self::with_len) and fewer than the
+    /// expected number of bytes have been written.
+    #[must_use]
+    pub fn finish128(&self) -> Option<u128> {
+        self.hasher.finish128()
+    }
+
+    /// Returns the number of bytes that remain to be written, or `None` if this
+    /// writer was created with [`new()`](Self::new).
+    #[must_use]
+    pub fn remaining(&self) -> Option<u64> {
+        self.hasher.remaining()
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner writer.
+    ///
+    /// Bytes written directly to the inner writer are not hashed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W> Write for CityHashWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let buf = match self.hasher.remaining() {
+            Some(0) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "more bytes were written than the length given to CityHashWriter",
+                ))
+            }
+            Some(remaining) => {
+                &buf[..buf
+                    .len()
+                    .min(usize::try_from(remaining).unwrap_or(usize::MAX))]
+            }
+            None => buf,
+        };
+        let written = self.inner.write(buf)?;
+        self.hasher.write(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A [`Read`] implementation that hashes the bytes read through it from an
+/// inner reader.
+///
+/// Like [`CityHashWriter`], a reader created with [`new()`](Self::new) retains
+/// every byte read, and one created with [`with_len()`](Self::with_len)
+/// computes `CityHash128` of a known number of bytes as they are read, using a
+/// fixed amount of memory. In the latter case, reading returns an
+/// [`InvalidData`](io::ErrorKind::InvalidData) error if the inner reader
+/// produces more bytes than expected, rather than silently ending the input
+/// early.
+///
+/// [`finish64()`](Self::finish64) and [`finish128()`](Self::finish128) return
+/// the same values as [`hash`](crate::hash) over all bytes that have been read.
+///
+/// ```rust
+/// use std::io;
+///
+/// use cityhasher::CityHashReader;
+///
+/// let mut reader = CityHashReader::new(&b"hello, world"[..]);
+/// let mut copy = Vec::new();
+/// io::copy(&mut reader, &mut copy).unwrap();
+///
+/// let expected: u64 = cityhasher::hash("hello, world");
+/// assert_eq!(reader.finish64(), Some(expected));
+/// ```
+#[derive(Debug)]
+pub struct CityHashReader<R> {
+    inner: R,
+    hasher: Tee,
+}
+
+impl<R> CityHashReader<R>
+where
+    R: Read,
+{
+    /// Returns a reader that hashes all bytes read from `inner`, retaining them
+    /// in memory.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hasher: Tee::Buffered(BufferingCityHasher::new()),
+        }
+    }
+
+    /// Returns a reader that computes `CityHash128` of the `len` bytes that
+    /// `inner` will produce, without retaining them.
+    pub fn with_len(inner: R, len: u64) -> Self {
+        Self {
+            inner,
+            hasher: Tee::Streamed(Box::new(Hash128Stream::new(len))),
+        }
+    }
+
+    /// Returns the 64-bit hash of the bytes read, or `None` if this reader was
+    /// created with [`with_len()`](Self::with_len).
+    #[must_use]
+    pub fn finish64(&self) -> Option<u64> {
+        self.hasher.finish64()
+    }
+
+    /// Returns the 128-bit hash of the bytes read, or `None` if this reader was
+    /// created with [`with_len()`](Self::with_len) and fewer than the expected
+    /// number of bytes have been read.
+    #[must_use]
+    pub fn finish128(&self) -> Option<u128> {
+        self.hasher.finish128()
+    }
+
+    /// Returns the number of bytes that remain to be read, or `None` if this
+    /// reader was created with [`new()`](Self::new).
+    #[must_use]
+    pub fn remaining(&self) -> Option<u64> {
+        self.hasher.remaining()
+    }
+
+    /// Returns a reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner reader.
+    ///
+    /// Bytes read directly from the inner reader are not hashed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> Read for CityHashReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = match self.hasher.remaining() {
+            // Any further bytes would be silently dropped from the input.
+            Some(0) if !buf.is_empty() => {
+                return if self.inner.read(buf)? == 0 {
+                    Ok(0)
+                } else {
+                    Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "more bytes were read than the length given to CityHashReader",
+                    ))
+                };
+            }
+            Some(remaining) => buf
+                .len()
+                .min(usize::try_from(remaining).unwrap_or(usize::MAX)),
+            None => buf.len(),
+        };
+        let read = self.inner.read(&mut buf[..len])?;
+        self.hasher.write(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// How an adapter hashes the bytes passing through it.
+#[derive(Debug)]
+enum Tee {
+    Buffered(BufferingCityHasher),
+    Streamed(Box<Hash128Stream>),
+}
+
+impl Tee {
+    fn remaining(&self) -> Option<u64> {
+        match self {
+            Self::Buffered(_) => None,
+            Self::Streamed(stream) => Some(stream.remaining()),
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Buffered(buffer) => core::hash::Hasher::write(buffer, bytes),
+            Self::Streamed(stream) => stream.write(bytes),
+        }
+    }
+
+    fn finish64(&self) -> Option<u64> {
+        match self {
+            Self::Buffered(buffer) => Some(crate::hash(buffer.bytes())),
+            Self::Streamed(_) => None,
+        }
+    }
+
+    fn finish128(&self) -> Option<u128> {
+        match self {
+            Self::Buffered(buffer) => Some(crate::hash(buffer.bytes())),
+            Self::Streamed(stream) => stream.finish(),
+        }
+    }
+}
+
+/// `CityHash128` of an input whose length is known in advance, computed as the
+/// input is written.
+struct Hash128Stream {
+    len: u64,
+    written: u64,
+    /// The first 16 bytes of the input, which seed the rest of it.
+    prefix: [u8; 16],
+    /// The most recently hashed chunk, followed by `pending` bytes that have
+    /// not been hashed.
+    buffer: [u8; 256],
+    pending: usize,
+    /// The number of complete chunks that have not been hashed.
+    chunks: u64,
+    state: Option<Hash128Long>,
+}
+
+impl Hash128Stream {
+    fn new(len: u64) -> Self {
+        Self {
+            len,
+            written: 0,
+            prefix: [0; 16],
+            buffer: [0; 256],
+            pending: 0,
+            chunks: len.saturating_sub(16) / 128,
+            state: None,
+        }
+    }
+
+    fn remaining(&self) -> u64 {
+        self.len - self.written
+    }
+
+    /// Hashes `bytes`, which must not be longer than
+    /// [`remaining()`](Self::remaining).
+    fn write(&mut self, mut bytes: &[u8]) {
+        debug_assert!(bytes.len() as u64 <= self.remaining());
+        if self.written < 16 {
+            let offset = self.written as usize;
+            let count = bytes.len().min(16 - offset);
+            self.prefix[offset..offset + count].copy_from_slice(&bytes[..count]);
+            self.written += count as u64;
+            bytes = &bytes[count..];
+        }
+
+        // Hash complete chunks in place while no bytes are pending.
+        if self.pending == 0 {
+            let count = (bytes.len() as u64 / 128).min(self.chunks) as usize;
+            if count > 0 {
+                let (chunks, rest) = bytes.split_at(count * 128);
+                let seed = self.seed();
+                for chunk in chunks.chunks_exact(128) {
+                    Self::update(&mut self.state, &Input(chunk), self.len - 16, seed);
+                }
+                self.chunks -= count as u64;
+                self.buffer[..128].copy_from_slice(&chunks[chunks.len() - 128..]);
+                self.written += chunks.len() as u64;
+                bytes = rest;
+            }
+        }
+
+        while !bytes.is_empty() {
+            let count = bytes.len().min(128 - self.pending);
+            self.buffer[128 + self.pending..128 + self.pending + count]
+                .copy_from_slice(&bytes[..count]);
+            self.pending += count;
+            self.written += count as u64;
+            bytes = &bytes[count..];
+
+            if self.pending == 128 && self.chunks > 0 {
+                let seed = self.seed();
+                let (hashed, chunk) = self.buffer.split_at_mut(128);
+                Self::update(&mut self.state, &Input(chunk), self.len - 16, seed);
+                self.chunks -= 1;
+                hashed.copy_from_slice(chunk);
+                self.pending = 0;
+            }
+        }
+    }
+
+    /// Updates `state` with the next `chunk` of the input following the first
+    /// 16 bytes, which is `len` bytes long and seeded by `seed`.
+    fn update(state: &mut Option<Hash128Long>, chunk: &Input<'_>, len: u64, seed: (u64, u64)) {
+        state
+            .get_or_insert_with(|| Hash128Long::new(chunk, len, seed))
+            .update(chunk);
+    }
+
+    /// Returns the seed of the input following the first 16 bytes.
+    fn seed(&self) -> (u64, u64) {
+        let prefix = Input(&self.prefix);
+        (prefix.fetch64(0), prefix.fetch64(8).wrapping_add(K0))
+    }
+
+    fn finish(&self) -> Option<u128> {
+        if self.written < self.len {
+            return None;
+        }
+
+        let hash = if self.len < 16 {
+            Input(&self.prefix[..self.len as usize]).hash128_with_seed((K0, K1))
+        } else if let Some(state) = &self.state {
+            state.finish(&Input(&self.buffer[self.pending..128 + self.pending]))
+        } else {
+            Input(&self.buffer[128..128 + self.pending]).hash128_with_seed(self.seed())
+        };
+        Some(u128_from_halves(hash))
+    }
+}
+
+impl core::fmt::Debug for Hash128Stream {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Hash128Stream")
+            .field("len", &self.len)
+            .field("written", &self.written)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,503 @@
+//! Hashing multiple short inputs at once.
+//!
+//! `CityHash64` selects its implementation based on the length of the input.
+//! When the lengths of several inputs fall within the same range, they follow
+//! identical paths, so their hashes can be computed in separate lanes of SIMD
+//! registers, with each lane using its own length. The algorithm is
+//! implemented once, generically over [`U64x4`], and each implementation of
+//! [`U64x4`] provides the lane-wise arithmetic.
+
+use crate::city::{Input, K0, K1, K2};
+
+/// The number of inputs hashed at once.
+pub(crate) const LANES: usize = 4;
+
+/// The longest input length supported by [`hash64_lanes`].
+pub(crate) const MAX_LEN: usize = 32;
+
+/// Four `u64` lanes supporting the wrapping arithmetic used by CityHash.
+pub(crate) trait U64x4: Copy {
+    fn splat(value: u64) -> Self;
+    fn from_array(values: [u64; LANES]) -> Self;
+    fn to_array(self) -> [u64; LANES];
+
+    /// Loads four consecutive little-endian `u64`s from the start of `bytes`.
+    #[inline(always)]
+    fn load(bytes: &[u8]) -> Self {
+        let input = Input(&bytes[..32]);
+        Self::from_array([0, 8, 16, 24].map(|offset| input.fetch64(offset)))
+    }
+
+    /// Returns the even-indexed and odd-indexed values of `self` followed by
+    /// `other`.
+    #[inline(always)]
+    fn deinterleave(self, other: Self) -> (Self, Self) {
+        let [a, b, c, d] = self.to_array();
+        let [e, f, g, h] = other.to_array();
+        (
+            Self::from_array([a, c, e, g]),
+            Self::from_array([b, d, f, h]),
+        )
+    }
+    fn add(self, other: Self) -> Self;
+    fn mul(self, other: Self) -> Self;
+    fn xor(self, other: Self) -> Self;
+    fn shl(self, shift: u32) -> Self;
+    fn shr(self, shift: u32) -> Self;
+
+    /// Requires `0 < shift < 64`.
+    #[inline(always)]
+    fn rotate_right(self, shift: u32) -> Self {
+        // The shifted values have no bits in common, so `xor` is equivalent
+        // to `or`.
+        self.shr(shift).xor(self.shl(64 - shift))
+    }
+}
+
+/// An operation that is generic over the implementation of [`U64x4`].
+pub(crate) trait WithLanes {
+    type Output;
+
+    fn run<V: U64x4>(self) -> Self::Output;
+}
+
+/// Runs `operation` using the fastest implementation of [`U64x4`] supported
+/// by the running CPU.
+#[inline]
+pub(crate) fn with_lanes<W: WithLanes>(operation: W) -> W::Output {
+    #[cfg(all(
+        feature = "intrinsics",
+        target_arch = "x86_64",
+        target_feature = "avx2"
+    ))]
+    return operation.run::<Avx2>();
+
+    #[cfg(all(
+        feature = "intrinsics",
+        feature = "std",
+        target_arch = "x86_64",
+        not(target_feature = "avx2")
+    ))]
+    if avx2_available() {
+        #[allow(unsafe_code)]
+        // SAFETY: AVX2 support was just verified.
+        return unsafe { run_avx2(operation) };
+    }
+
+    #[cfg(all(
+        feature = "intrinsics",
+        target_arch = "aarch64",
+        target_feature = "neon"
+    ))]
+    return operation.run::<Neon>();
+
+    #[allow(unreachable_code)]
+    operation.run::<Portable>()
+}
+
+/// The ranges of input lengths for which [`hash64_lanes`] performs the same
+/// operations, allowing inputs of different lengths within a range to be
+/// hashed together.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum LanesClass {
+    Empty,
+    UpTo3,
+    UpTo7,
+    UpTo16,
+    UpTo32,
+}
+
+impl LanesClass {
+    /// Returns the class of inputs of `len` bytes, or `None` if `len` is
+    /// greater than [`MAX_LEN`].
+    #[inline(always)]
+    pub(crate) const fn of(len: usize) -> Option<Self> {
+        match len {
+            0 => Some(Self::Empty),
+            1..=3 => Some(Self::UpTo3),
+            4..=7 => Some(Self::UpTo7),
+            8..=16 => Some(Self::UpTo16),
+            17..=MAX_LEN => Some(Self::UpTo32),
+            _ => None,
+        }
+    }
+}
+
+/// Returns `CityHash64` of each of `inputs`, whose lengths must all be of
+/// `class`.
+///
+/// This mirrors `Input::hash64_len_0_to_16` and `Input::hash64_len_17_to_32`.
+/// Offsets relative to the end of the input are computed for each lane.
+#[inline(always)]
+pub(crate) fn hash64_lanes<V: U64x4>(inputs: &[&[u8]; LANES], class: LanesClass) -> [u64; LANES] {
+    debug_assert!(inputs
+        .iter()
+        .all(|input| LanesClass::of(input.len()) == Some(class)));
+
+    let len = V::from_array(inputs.map(|input| input.len() as u64));
+    let fetch64 = |offset: fn(usize) -> usize| {
+        V::from_array(inputs.map(|input| Input(input).fetch64(offset(input.len()))))
+    };
+    let fetch32 = |offset: fn(usize) -> usize| {
+        V::from_array(inputs.map(|input| u64::from(Input(input).fetch32(offset(input.len())))))
+    };
+    let byte = |offset: fn(usize) -> usize| {
+        V::from_array(inputs.map(|input| u64::from(input[offset(input.len())])))
+    };
+
+    let hash = match class {
+        LanesClass::UpTo32 => {
+            let mul = V::splat(K2).add(len.shl(1));
+            let a = fetch64(|_| 0).mul(V::splat(K1));
+            let b = fetch64(|_| 8);
+            let c = fetch64(|len| len - 8).mul(mul);
+            let d = fetch64(|len| len - 16).mul(V::splat(K2));
+            hash_len_16_with_mul(
+                a.add(b).rotate_right(43).add(c.rotate_right(30)).add(d),
+                a.add(b.add(V::splat(K2)).rotate_right(18)).add(c),
+                mul,
+            )
+        }
+        LanesClass::UpTo16 => hash64_len_8_to_16(fetch64(|_| 0), fetch64(|len| len - 8), len),
+        LanesClass::UpTo7 => {
+            let mul = V::splat(K2).add(len.shl(1));
+            hash_len_16_with_mul(len.add(fetch32(|_| 0).shl(3)), fetch32(|len| len - 4), mul)
+        }
+        LanesClass::UpTo3 => {
+            let y = byte(|_| 0).add(byte(|len| len >> 1).shl(8));
+            let z = len.add(byte(|len| len - 1).shl(2));
+            shift_mix(y.mul(V::splat(K2)).xor(z.mul(V::splat(K0)))).mul(V::splat(K2))
+        }
+        LanesClass::Empty => return [K2; LANES],
+    };
+    hash.to_array()
+}
+
+/// Hashes inputs of 8 to 16 bytes, given their first and last 8 bytes and
+/// their lengths.
+#[inline(always)]
+pub(crate) fn hash64_len_8_to_16<V: U64x4>(first: V, last: V, len: V) -> V {
+    let mul = V::splat(K2).add(len.shl(1));
+    let a = first.add(V::splat(K2));
+    let c = last.rotate_right(37).mul(mul).add(a);
+    let d = a.rotate_right(25).add(last).mul(mul);
+    hash_len_16_with_mul(c, d, mul)
+}
+
+#[inline(always)]
+fn hash_len_16_with_mul<V: U64x4>(u: V, v: V, mul: V) -> V {
+    let a = shift_mix(u.xor(v).mul(mul));
+    let b = shift_mix(v.xor(a).mul(mul));
+    b.mul(mul)
+}
+
+#[inline(always)]
+fn shift_mix<V: U64x4>(value: V) -> V {
+    value.xor(value.shr(47))
+}
+
+/// A portable implementation of [`U64x4`], which compilers are often able to
+/// vectorize.
+#[derive(Clone, Copy)]
+pub(crate) struct Portable([u64; LANES]);
+
+impl Portable {
+    #[inline(always)]
+    fn zip(self, other: Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        let mut result = self.0;
+        for (result, other) in result.iter_mut().zip(other.0) {
+            *result = op(*result, other);
+        }
+        Self(result)
+    }
+}
+
+impl U64x4 for Portable {
+    #[inline(always)]
+    fn splat(value: u64) -> Self {
+        Self([value; LANES])
+    }
+
+    #[inline(always)]
+    fn from_array(values: [u64; LANES]) -> Self {
+        Self(values)
+    }
+
+    #[inline(always)]
+    fn to_array(self) -> [u64; LANES] {
+        self.0
+    }
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        self.zip(other, u64::wrapping_add)
+    }
+
+    #[inline(always)]
+    fn mul(self, other: Self) -> Self {
+        self.zip(other, u64::wrapping_mul)
+    }
+
+    #[inline(always)]
+    fn xor(self, other: Self) -> Self {
+        self.zip(other, |a, b| a ^ b)
+    }
+
+    #[inline(always)]
+    fn shl(self, shift: u32) -> Self {
+        Self(self.0.map(|value| value << shift))
+    }
+
+    #[inline(always)]
+    fn shr(self, shift: u32) -> Self {
+        Self(self.0.map(|value| value >> shift))
+    }
+}
+
+/// An implementation of [`U64x4`] using AVX2.
+///
+/// This type must only be used after ensuring AVX2 is supported.
+#[cfg(all(
+    feature = "intrinsics",
+    target_arch = "x86_64",
+    any(feature = "std", target_feature = "avx2")
+))]
+#[derive(Clone, Copy)]
+pub(crate) struct Avx2(core::arch::x86_64::__m256i);
+
+// SAFETY: All of the intrinsics used require AVX2, which is verified before
+// this type is used.
+#[cfg(all(
+    feature = "intrinsics",
+    target_arch = "x86_64",
+    any(feature = "std", target_feature = "avx2")
+))]
+#[allow(unsafe_code)]
+impl U64x4 for Avx2 {
+    #[inline(always)]
+    fn splat(value: u64) -> Self {
+        Self(unsafe { core::arch::x86_64::_mm256_set1_epi64x(value as i64) })
+    }
+
+    #[inline(always)]
+    fn from_array([a, b, c, d]: [u64; LANES]) -> Self {
+        Self(unsafe {
+            core::arch::x86_64::_mm256_set_epi64x(d as i64, c as i64, b as i64, a as i64)
+        })
+    }
+
+    #[inline(always)]
+    fn to_array(self) -> [u64; LANES] {
+        let mut values = [0; LANES];
+        unsafe { core::arch::x86_64::_mm256_storeu_si256(values.as_mut_ptr().cast(), self.0) };
+        values
+    }
+
+    #[inline(always)]
+    fn load(bytes: &[u8]) -> Self {
+        let bytes = &bytes[..32];
+        Self(unsafe { core::arch::x86_64::_mm256_loadu_si256(bytes.as_ptr().cast()) })
+    }
+
+    #[inline(always)]
+    fn deinterleave(self, other: Self) -> (Self, Self) {
+        use core::arch::x86_64::{
+            _mm256_permute4x64_epi64, _mm256_unpackhi_epi64, _mm256_unpacklo_epi64,
+        };
+
+        // The unpack instructions operate within each 128-bit half, producing
+        // [a0, b0, a2, b2] and [a1, b1, a3, b3], which are then reordered.
+        unsafe {
+            (
+                Self(_mm256_permute4x64_epi64(
+                    _mm256_unpacklo_epi64(self.0, other.0),
+                    0b11_01_10_00,
+                )),
+                Self(_mm256_permute4x64_epi64(
+                    _mm256_unpackhi_epi64(self.0, other.0),
+                    0b11_01_10_00,
+                )),
+            )
+        }
+    }
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        Self(unsafe { core::arch::x86_64::_mm256_add_epi64(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn mul(self, other: Self) -> Self {
+        use core::arch::x86_64::{_mm256_add_epi64, _mm256_mul_epu32, _mm256_slli_epi64};
+
+        // AVX2 only multiplies the low 32 bits of each lane. The low 64 bits
+        // of the full product are `lo * lo + ((lo * hi + hi * lo) << 32)`.
+        unsafe {
+            let low = _mm256_mul_epu32(self.0, other.0);
+            let cross = _mm256_add_epi64(
+                _mm256_mul_epu32(self.shr(32).0, other.0),
+                _mm256_mul_epu32(self.0, other.shr(32).0),
+            );
+            Self(_mm256_add_epi64(low, _mm256_slli_epi64(cross, 32)))
+        }
+    }
+
+    #[inline(always)]
+    fn xor(self, other: Self) -> Self {
+        Self(unsafe { core::arch::x86_64::_mm256_xor_si256(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn shl(self, shift: u32) -> Self {
+        use core::arch::x86_64::{_mm256_set1_epi64x, _mm256_sllv_epi64};
+
+        Self(unsafe { _mm256_sllv_epi64(self.0, _mm256_set1_epi64x(i64::from(shift))) })
+    }
+
+    #[inline(always)]
+    fn shr(self, shift: u32) -> Self {
+        use core::arch::x86_64::{_mm256_set1_epi64x, _mm256_srlv_epi64};
+
+        Self(unsafe { _mm256_srlv_epi64(self.0, _mm256_set1_epi64x(i64::from(shift))) })
+    }
+}
+
+#[cfg(all(
+    feature = "intrinsics",
+    feature = "std",
+    target_arch = "x86_64",
+    not(target_feature = "avx2")
+))]
+#[target_feature(enable = "avx2")]
+#[allow(unsafe_code)]
+unsafe fn run_avx2<W: WithLanes>(operation: W) -> W::Output {
+    operation.run::<Avx2>()
+}
+
+/// Returns true if the running CPU supports AVX2.
+#[cfg(all(
+    feature = "intrinsics",
+    feature = "std",
+    target_arch = "x86_64",
+    any(test, not(target_feature = "avx2"))
+))]
+pub(crate) fn avx2_available() -> bool {
+    std::is_x86_feature_detected!("avx2")
+}
+
+/// An implementation of [`U64x4`] using NEON, which is always available on
+/// aarch64 targets that enable it.
+#[cfg(all(
+    feature = "intrinsics",
+    target_arch = "aarch64",
+    target_feature = "neon"
+))]
+#[derive(Clone, Copy)]
+pub(crate) struct Neon(
+    core::arch::aarch64::uint64x2_t,
+    core::arch::aarch64::uint64x2_t,
+);
+
+#[cfg(all(
+    feature = "intrinsics",
+    target_arch = "aarch64",
+    target_feature = "neon"
+))]
+impl Neon {
+    #[inline(always)]
+    fn map(
+        self,
+        op: impl Fn(core::arch::aarch64::uint64x2_t) -> core::arch::aarch64::uint64x2_t,
+    ) -> Self {
+        Self(op(self.0), op(self.1))
+    }
+
+    #[inline(always)]
+    fn zip(
+        self,
+        other: Self,
+        op: impl Fn(
+            core::arch::aarch64::uint64x2_t,
+            core::arch::aarch64::uint64x2_t,
+        ) -> core::arch::aarch64::uint64x2_t,
+    ) -> Self {
+        Self(op(self.0, other.0), op(self.1, other.1))
+    }
+}
+
+// SAFETY: This type is only compiled when NEON is enabled at compile time.
+#[cfg(all(
+    feature = "intrinsics",
+    target_arch = "aarch64",
+    target_feature = "neon"
+))]
+#[allow(unsafe_code)]
+impl U64x4 for Neon {
+    #[inline(always)]
+    fn splat(value: u64) -> Self {
+        let lanes = unsafe { core::arch::aarch64::vdupq_n_u64(value) };
+        Self(lanes, lanes)
+    }
+
+    #[inline(always)]
+    fn from_array(values: [u64; LANES]) -> Self {
+        use core::arch::aarch64::vld1q_u64;
+
+        unsafe { Self(vld1q_u64(values.as_ptr()), vld1q_u64(values[2..].as_ptr())) }
+    }
+
+    #[inline(always)]
+    fn to_array(self) -> [u64; LANES] {
+        use core::arch::aarch64::vst1q_u64;
+
+        let mut values = [0; LANES];
+        unsafe {
+            vst1q_u64(values.as_mut_ptr(), self.0);
+            vst1q_u64(values[2..].as_mut_ptr(), self.1);
+        }
+        values
+    }
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        self.zip(other, |a, b| unsafe {
+            core::arch::aarch64::vaddq_u64(a, b)
+        })
+    }
+
+    #[inline(always)]
+    fn mul(self, other: Self) -> Self {
+        use core::arch::aarch64::{vmlal_u32, vmovn_u64, vmull_u32, vshlq_n_u64, vshrn_n_u64};
+
+        // NEON has no 64-bit multiply. The low 64 bits of the full product
+        // are `lo * lo + ((lo * hi + hi * lo) << 32)`.
+        self.zip(other, |a, b| unsafe {
+            let (a_low, a_high) = (vmovn_u64(a), vshrn_n_u64::<32>(a));
+            let (b_low, b_high) = (vmovn_u64(b), vshrn_n_u64::<32>(b));
+            let cross = vmlal_u32(vmull_u32(a_low, b_high), a_high, b_low);
+            vmlal_u32(vshlq_n_u64::<32>(cross), a_low, b_low)
+        })
+    }
+
+    #[inline(always)]
+    fn xor(self, other: Self) -> Self {
+        self.zip(other, |a, b| unsafe {
+            core::arch::aarch64::veorq_u64(a, b)
+        })
+    }
+
+    #[inline(always)]
+    fn shl(self, shift: u32) -> Self {
+        use core::arch::aarch64::{vdupq_n_s64, vshlq_u64};
+
+        self.map(|a| unsafe { vshlq_u64(a, vdupq_n_s64(i64::from(shift))) })
+    }
+
+    #[inline(always)]
+    fn shr(self, shift: u32) -> Self {
+        use core::arch::aarch64::{vdupq_n_s64, vshlq_u64};
+
+        // NEON shifts right when given a negative shift.
+        self.map(|a| unsafe { vshlq_u64(a, vdupq_n_s64(-i64::from(shift))) })
+    }
+}
@@ -1,401 +1,86 @@
-#![doc = include_str!("../README.md")]
+// The README's examples use the standard library's collections.
+#![cfg_attr(feature = "std", doc = include_str!("../README.md"))]
+#![cfg_attr(
+    not(feature = "std"),
+    doc = "A pure Rust implementation of the CityHash algorithm. See the README for usage."
+)]
 #![no_std]
 #![warn(missing_docs, clippy::all)]
-#![cfg_attr(feature = "disable-bounds-checking", deny(unsafe_code))]
-#![cfg_attr(not(feature = "disable-bounds-checking"), forbid(unsafe_code))]
+#![cfg_attr(
+    any(
+        feature = "disable-bounds-checking",
+        feature = "intrinsics",
+        feature = "mmap"
+    ),
+    deny(unsafe_code)
+)]
+#![cfg_attr(
+    not(any(
+        feature = "disable-bounds-checking",
+        feature = "intrinsics",
+        feature = "mmap"
+    )),
+    forbid(unsafe_code)
+)]
 
 use core::hash::{BuildHasher, Hasher};
-use core::mem;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
+// Allows the tests to use `#[derive(StableHash)]`, which refers to this crate
+// by name.
+#[cfg(all(test, feature = "macros"))]
+extern crate self as cityhasher;
+
+mod batch;
+#[cfg(feature = "bytes")]
+mod buf;
+#[cfg(feature = "alloc")]
+mod buffering;
+pub mod combine;
+mod const_hash;
+mod crc;
+#[cfg(feature = "digest")]
+mod digest;
+mod fingerprint;
+#[cfg(feature = "std")]
+pub mod fs;
+#[cfg(feature = "hashbrown")]
+pub mod hashbrown;
+#[cfg(feature = "std")]
+mod io;
+mod lanes;
+mod random;
+mod stable;
+pub mod tree;
+pub mod v1_0_2;
+mod value;
+mod vectored;
+
+#[cfg(feature = "digest")]
+pub use self::digest::{CityHash128Digest, CityHash64Digest};
+pub use batch::{hash_batch, hash_fixed_stride};
+#[cfg(feature = "bytes")]
+pub use buf::hash_buf;
+#[cfg(feature = "alloc")]
+pub use buffering::BufferingCityHasher;
+#[cfg(feature = "macros")]
+pub use cityhasher_macros::{cityhash32, cityhash64, StableHash};
+pub use const_hash::{hash32, hash64, hash64_with_seed};
+pub use crc::{hash_crc, hash_crc_with_seed, FromCityHashCrc, FromSeededCityHashCrc};
+pub use fingerprint::{Fingerprint128, Fingerprint64, FingerprintError};
+#[cfg(feature = "std")]
+pub use io::{CityHashReader, CityHashWriter};
+pub use random::RandomCityState;
+pub use stable::{hash_stable, hash_stable_with_seed, StableHash, StableHasher};
+pub use value::{hash_value, hash_value_with_seed};
+pub use vectored::{hash_vectored, hash_vectored_iter, FromCityHashVectored};
 
-struct Input<'a>(&'a [u8]);
-
-impl<'a> Input<'a> {
-    const fn len(&self) -> usize {
-        self.0.len()
-    }
-
-    #[cfg_attr(feature = "disable-bounds-checking", allow(unsafe_code))]
-    fn fetch32(&self, offset: usize) -> u32 {
-        u32::from_le_bytes({
-            #[cfg(feature = "disable-bounds-checking")]
-            unsafe {
-                self.0
-                    .get_unchecked(offset..offset + 4)
-                    .try_into()
-                    .unwrap_unchecked()
-            }
-            #[cfg(not(feature = "disable-bounds-checking"))]
-            self.0[offset..offset + 4]
-                .try_into()
-                .expect("u32 is 4 bytes")
-        })
-    }
-
-    #[cfg_attr(feature = "disable-bounds-checking", allow(unsafe_code))]
-    fn fetch64(&self, offset: usize) -> u64 {
-        u64::from_le_bytes({
-            #[cfg(feature = "disable-bounds-checking")]
-            unsafe {
-                self.0
-                    .get_unchecked(offset..offset + 8)
-                    .try_into()
-                    .unwrap_unchecked()
-            }
-            #[cfg(not(feature = "disable-bounds-checking"))]
-            self.0[offset..offset + 8]
-                .try_into()
-                .expect("u64 is 8 bytes")
-        })
-    }
-
-    fn hash32_len_13_to_24(&self) -> u32 {
-        #[cfg(not(feature = "disable-bounds-checking"))]
-        assert!(self.len() <= 24); // This helps the optimizer with bounds checking
-        let a = self.fetch32((self.len() >> 1) - 4);
-        let b = self.fetch32(4);
-        let c = self.fetch32(self.len() - 8);
-        let d = self.fetch32(self.len() >> 1);
-        let e = self.fetch32(0);
-        let f = self.fetch32(self.len() - 4);
-        let h = self.len() as u32;
-
-        fmix(mur(f, mur(e, mur(d, mur(c, mur(b, mur(a, h)))))))
-    }
-
-    fn hash32_len_0_to_4(&self) -> u32 {
-        #[cfg(not(feature = "disable-bounds-checking"))]
-        assert!(self.len() <= 4); // This helps the optimizer with automatic loop unrolling.
-        let mut b = 0_u32;
-        let mut c = 9;
-        for v in self.0 {
-            let as_signed = *v as i8;
-
-            b = b.wrapping_mul(C1).wrapping_add(as_signed as u32);
-            c ^= b;
-        }
-        fmix(mur(b, mur(self.len() as u32, c)))
-    }
-
-    fn hash32_len_5_to_12(&self) -> u32 {
-        #[cfg(not(feature = "disable-bounds-checking"))]
-        assert!(self.len() <= 12);
-        let mut a = self.len() as u32;
-        let mut b = a.wrapping_mul(5);
-        let mut c = 9_u32;
-        let d = b;
-
-        a = a.wrapping_add(self.fetch32(0));
-        b = b.wrapping_add(self.fetch32(self.len() - 4));
-        c = c.wrapping_add(self.fetch32((self.len() >> 1) & 4));
-        fmix(mur(c, mur(b, mur(a, d))))
-    }
-
-    fn hash32(&self) -> u32 {
-        if self.len() <= 24 {
-            return if self.len() <= 12 {
-                if self.len() <= 4 {
-                    self.hash32_len_0_to_4()
-                } else {
-                    self.hash32_len_5_to_12()
-                }
-            } else {
-                self.hash32_len_13_to_24()
-            };
-        }
-
-        // len > 24
-        let mut h = self.len() as u32;
-        let mut g = h.wrapping_mul(C1);
-        let mut f = g;
-        let a0 = rotate32(self.fetch32(self.len() - 4).wrapping_mul(C1), 17).wrapping_mul(C2);
-        let a1 = rotate32(self.fetch32(self.len() - 8).wrapping_mul(C1), 17).wrapping_mul(C2);
-        let a2 = rotate32(self.fetch32(self.len() - 16).wrapping_mul(C1), 17).wrapping_mul(C2);
-        let a3 = rotate32(self.fetch32(self.len() - 12).wrapping_mul(C1), 17).wrapping_mul(C2);
-        let a4 = rotate32(self.fetch32(self.len() - 20).wrapping_mul(C1), 17).wrapping_mul(C2);
-        h ^= a0;
-        h = rotate32(h, 19);
-        h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
-        h ^= a2;
-        h = rotate32(h, 19);
-        h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
-        g ^= a1;
-        g = rotate32(g, 19);
-        g = g.wrapping_mul(5).wrapping_add(0xe6546b64);
-        g ^= a3;
-        g = rotate32(g, 19);
-        g = g.wrapping_mul(5).wrapping_add(0xe6546b64);
-        f = f.wrapping_add(a4);
-        f = rotate32(f, 19);
-        f = f.wrapping_mul(5).wrapping_add(0xe6546b64);
-        let mut iters = (self.0.len() - 1) / 20;
-        let mut offset = 0;
-
-        loop {
-            let a0 = rotate32(self.fetch32(offset).wrapping_mul(C1), 17).wrapping_mul(C2);
-            let a1 = self.fetch32(offset + 4);
-            let a2 = rotate32(self.fetch32(offset + 8).wrapping_mul(C1), 17).wrapping_mul(C2);
-            let a3 = rotate32(self.fetch32(offset + 12).wrapping_mul(C1), 17).wrapping_mul(C2);
-            let a4 = self.fetch32(offset + 16);
-            h ^= a0;
-            h = rotate32(h, 18);
-            h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
-            f = f.wrapping_add(a1);
-            f = rotate32(f, 19);
-            f = f.wrapping_mul(C1);
-            g = g.wrapping_add(a2);
-            g = rotate32(g, 18);
-            g = g.wrapping_mul(5).wrapping_add(0xe6546b64);
-            h ^= a3.wrapping_add(a1);
-            h = rotate32(h, 19);
-            h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
-            g ^= a4;
-            g = g.swap_bytes().wrapping_mul(5);
-            h = h.wrapping_add(a4.wrapping_mul(5));
-            h = h.swap_bytes();
-            f = f.wrapping_add(a0);
-            permute3(&mut f, &mut h, &mut g);
-            offset += 20;
-            iters -= 1;
-            if iters == 0 {
-                break;
-            }
-        }
-        g = rotate32(g, 11).wrapping_mul(C1);
-        g = rotate32(g, 17).wrapping_mul(C1);
-        f = rotate32(f, 11).wrapping_mul(C1);
-        f = rotate32(f, 17).wrapping_mul(C1);
-        h = rotate32(h.wrapping_add(g), 19);
-        h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
-        h = rotate32(h, 17).wrapping_mul(C1);
-        h = rotate32(h.wrapping_add(f), 19);
-        h = h.wrapping_mul(5).wrapping_add(0xe6546b64);
-        h = rotate32(h, 17).wrapping_mul(C1);
-        h
-    }
-
-    fn hash64(&self) -> u64 {
-        if self.len() <= 32 {
-            if self.len() <= 16 {
-                return self.hash64_len_0_to_16();
-            } else {
-                return self.hash64_len_17_to_32();
-            }
-        } else if self.len() <= 64 {
-            return self.hash64_len_33_to_64();
-        }
-
-        // For strings over 64 bytes we hash the end first, and then as we
-        // loop we keep 56 bytes of state: v, w, x, y, and z.
-        let mut x = self.fetch64(self.len() - 40);
-        let mut y = self
-            .fetch64(self.len() - 16)
-            .wrapping_add(self.fetch64(self.len() - 56));
-        let mut z = hash_len_16_u64(
-            self.fetch64(self.len() - 48)
-                .wrapping_add(self.len() as u64),
-            self.fetch64(self.len() - 24),
-        );
-        let mut v = self.weak_hash_len_32_with_seeds(self.len() - 64, self.len() as u64, z);
-        let mut w = self.weak_hash_len_32_with_seeds(self.len() - 32, y.wrapping_add(K1), x);
-        x = x.wrapping_mul(K1).wrapping_add(self.fetch64(0));
-
-        // Decrease len to the nearest multiple of 64, and operate on 64-byte chunks.
-        for chunk in self.0[0..self.len() - 1].chunks_exact(64) {
-            let chunk = Self(chunk);
-            x = rotate64(
-                x.wrapping_add(y)
-                    .wrapping_add(v.0)
-                    .wrapping_add(chunk.fetch64(8)),
-                37,
-            )
-            .wrapping_mul(K1);
-            y = rotate64(y.wrapping_add(v.1).wrapping_add(chunk.fetch64(48)), 42).wrapping_mul(K1);
-            x ^= w.1;
-            y = y.wrapping_add(v.0.wrapping_add(chunk.fetch64(40)));
-            z = rotate64(z.wrapping_add(w.0), 33).wrapping_mul(K1);
-            v = chunk.weak_hash_len_32_with_seeds(0, v.1.wrapping_mul(K1), x.wrapping_add(w.0));
-            w = chunk.weak_hash_len_32_with_seeds(
-                32,
-                z.wrapping_add(w.1),
-                y.wrapping_add(chunk.fetch64(16)),
-            );
-            mem::swap(&mut z, &mut x);
-        }
-        hash_len_16_u64(
-            hash_len_16_u64(v.0, w.0).wrapping_add(shift_mix(y).wrapping_mul(K1).wrapping_add(z)),
-            hash_len_16_u64(v.1, w.1).wrapping_add(x),
-        )
-    }
-
-    fn hash64_len_0_to_16(&self) -> u64 {
-        if self.len() >= 8 {
-            let mul = K2.wrapping_add((self.len() as u64).wrapping_mul(2));
-            let a = self.fetch64(0).wrapping_add(K2);
-            let b = self.fetch64(self.len() - 8);
-            let c = rotate64(b, 37).wrapping_mul(mul).wrapping_add(a);
-            let d = rotate64(a, 25).wrapping_add(b).wrapping_mul(mul);
-            hash_len_16_with_mul(c, d, mul)
-        } else if self.len() >= 4 {
-            let mul = K2.wrapping_add((self.len() as u64).wrapping_mul(2));
-            let a = self.fetch32(0) as u64;
-            hash_len_16_with_mul(
-                (self.len() as u64).wrapping_add(a << 3),
-                self.fetch32(self.len() - 4) as u64,
-                mul,
-            )
-        } else if self.len() > 0 {
-            let a = self.0[0] as u32;
-            let b = self.0[self.len() >> 1] as u32;
-            let c = self.0[self.len() - 1] as u32;
-            let y = a.wrapping_add(b << 8);
-            let z = (self.len() as u32).wrapping_add(c << 2);
-            shift_mix((y as u64).wrapping_mul(K2) ^ (z as u64).wrapping_mul(K0)).wrapping_mul(K2)
-        } else {
-            K2
-        }
-    }
-
-    fn hash64_len_17_to_32(&self) -> u64 {
-        let mul = K2.wrapping_add(self.len() as u64 * 2);
-        let a = self.fetch64(0).wrapping_mul(K1);
-        let b = self.fetch64(8);
-        let c = self.fetch64(self.len() - 8).wrapping_mul(mul);
-        let d = self.fetch64(self.len() - 16).wrapping_mul(K2);
-        hash_len_16_with_mul(
-            rotate64(a.wrapping_add(b), 43)
-                .wrapping_add(rotate64(c, 30))
-                .wrapping_add(d),
-            a.wrapping_add(rotate64(b.wrapping_add(K2), 18))
-                .wrapping_add(c),
-            mul,
-        )
-    }
-
-    fn hash64_len_33_to_64(&self) -> u64 {
-        let mul = K2.wrapping_add(self.len() as u64 * 2);
-        let a = self.fetch64(0).wrapping_mul(K2);
-        let b = self.fetch64(8);
-        let c = self.fetch64(self.len() - 24);
-        let d = self.fetch64(self.len() - 32);
-        let e = self.fetch64(16).wrapping_mul(K2);
-        let f = self.fetch64(24).wrapping_mul(9);
-        let g = self.fetch64(self.len() - 8);
-        let h = self.fetch64(self.len() - 16).wrapping_mul(mul);
-        let u = rotate64(a.wrapping_add(g), 43)
-            .wrapping_add(rotate64(b, 30).wrapping_add(c).wrapping_mul(9));
-        let v = (a.wrapping_add(g) ^ d).wrapping_add(f).wrapping_add(1);
-        let w = ((u.wrapping_add(v)).wrapping_mul(mul))
-            .swap_bytes()
-            .wrapping_add(h);
-        let x = rotate64(e.wrapping_add(f), 42).wrapping_add(c);
-        let y = (((v.wrapping_add(w)).wrapping_mul(mul))
-            .swap_bytes()
-            .wrapping_add(g))
-        .wrapping_mul(mul);
-        let z = e.wrapping_add(f).wrapping_add(c);
-        let a = ((x.wrapping_add(z)).wrapping_mul(mul).wrapping_add(y))
-            .swap_bytes()
-            .wrapping_add(b);
-        let b = shift_mix(
-            (z.wrapping_add(a))
-                .wrapping_mul(mul)
-                .wrapping_add(d)
-                .wrapping_add(h),
-        )
-        .wrapping_mul(mul);
-        b.wrapping_add(x)
-    }
-
-    fn weak_hash_len_32_with_seeds(&self, offset: usize, a: u64, b: u64) -> (u64, u64) {
-        weak_hash_len_32_with_seeds(
-            self.fetch64(offset),
-            self.fetch64(offset + 8),
-            self.fetch64(offset + 16),
-            self.fetch64(offset + 24),
-            a,
-            b,
-        )
-    }
-}
-
-fn hash_len_16_u64(u: u64, v: u64) -> u64 {
-    const MUL: u64 = 0x9ddfea08eb382d69;
-    hash_len_16_with_mul(u, v, MUL)
-}
-
-fn hash_len_16_with_mul(u: u64, v: u64, mul: u64) -> u64 {
-    // Murmur-inspired hashing.
-    let mut a = (u ^ v).wrapping_mul(mul);
-    a ^= a >> 47;
-    let mut b = (v ^ a).wrapping_mul(mul);
-    b ^= b >> 47;
-    b.wrapping_mul(mul)
-}
-
-// Some primes between 2^63 and 2^64 for various uses.
-const K0: u64 = 0xc3a5c85c97cb3127;
-const K1: u64 = 0xb492b66fbe98f273;
-const K2: u64 = 0x9ae16a3b2f90404f;
-
-// Magic numbers for 32-bit hashing.  Copied from Murmur3.
-const C1: u32 = 0xcc9e2d51;
-const C2: u32 = 0x1b873593;
-
-fn fmix(mut h: u32) -> u32 {
-    h ^= h >> 16;
-    h = h.wrapping_mul(0x85ebca6b);
-    h ^= h >> 13;
-    h = h.wrapping_mul(0xc2b2ae35);
-    h ^= h >> 16;
-    h
-}
-
-fn rotate32(val: u32, shift: u32) -> u32 {
-    if shift == 0 {
-        val
-    } else {
-        (val >> shift) | (val << (32 - shift))
-    }
-}
-fn rotate64(val: u64, shift: u64) -> u64 {
-    if shift == 0 {
-        val
-    } else {
-        (val >> shift) | (val << (64 - shift))
-    }
-}
-
-fn shift_mix(val: u64) -> u64 {
-    val ^ (val >> 47)
-}
-
-fn permute3<T>(a: &mut T, b: &mut T, c: &mut T) {
-    mem::swap(a, b);
-    mem::swap(a, c);
-}
-
-fn mur(mut a: u32, mut h: u32) -> u32 {
-    // Helper from Murmur3 for combining two 32-bit values.
-    a = a.wrapping_mul(C1);
-    a = rotate32(a, 17);
-    a = a.wrapping_mul(C2);
-    h ^= a;
-    h = rotate32(h, 19);
-    h.wrapping_mul(5).wrapping_add(0xe6546b64)
-}
-
-fn weak_hash_len_32_with_seeds(w: u64, x: u64, y: u64, z: u64, a: u64, b: u64) -> (u64, u64) {
-    let a = a.wrapping_add(w);
-    let b = rotate64(b.wrapping_add(a).wrapping_add(z), 21);
-    let c = a;
-    let a = a.wrapping_add(x);
-    let a = a.wrapping_add(y);
-    let b = b.wrapping_add(rotate64(a, 44));
-    (a.wrapping_add(z), b.wrapping_add(c))
-}
+// The algorithms are shared with `cityhasher-macros` through `cityhasher-core`.
+use city::{hash64_len_1_to_3, hash64_len_4_to_7, hash64_len_8_to_16, hash_len_16_u64, Input, K2};
+use cityhasher_core as city;
 
 #[cfg(test)]
 mod tests;
@@ -404,10 +89,16 @@ mod tests;
 ///
 /// The exact implementation is decided upon by `T`:
 ///
-/// |  `T`  | C++ Function Equivalent |
-/// |-------|-------------------------|
-/// | `u32` | `CityHash32`            |
-/// | `u64` | `CityHash64`            |
+/// |         `T`          | C++ Function Equivalent |
+/// |----------------------|-------------------------|
+/// | `u32`                | `CityHash32`            |
+/// | `u64`                | `CityHash64`            |
+/// | `u128`               | `CityHash128`           |
+/// | [`Fingerprint64`]    | `CityHash64`            |
+/// | [`Fingerprint128`]   | `CityHash128`           |
+///
+/// 128-bit hashes are returned with `Uint128High64` in the upper 64 bits and
+/// `Uint128Low64` in the lower 64 bits.
 ///
 /// ```rust
 /// let hello: u32 = cityhasher::hash("hello");
@@ -433,8 +124,31 @@ pub trait FromCityHash: sealed::Sealed {}
 pub trait FromSeededCityHash: sealed::SealedSeeded {}
 
 mod sealed {
-    pub trait Sealed {
+    pub trait Sealed: Sized {
         fn from_city_hash(data: &[u8]) -> Self;
+
+        #[inline]
+        fn from_city_hash_batch(inputs: &[&[u8]], out: &mut [Self]) {
+            for (input, out) in inputs.iter().zip(out) {
+                *out = Self::from_city_hash(input);
+            }
+        }
+
+        #[inline]
+        fn from_city_hash_fixed_stride(data: &[u8], stride: usize, out: &mut [Self]) {
+            for (record, out) in data.chunks_exact(stride).zip(out) {
+                *out = Self::from_city_hash(record);
+            }
+        }
+
+        /// Returns the conversion from a `CityHash64` value if `Self` is
+        /// produced by `CityHash64`, allowing the hash to be computed in
+        /// pieces.
+        #[cfg(feature = "std")]
+        #[inline]
+        fn from_city_hash64() -> Option<fn(u64) -> Self> {
+            None
+        }
     }
 
     pub trait SealedSeeded {
@@ -458,6 +172,22 @@ impl sealed::Sealed for u64 {
     fn from_city_hash(data: &[u8]) -> Self {
         Input(data).hash64()
     }
+
+    #[inline]
+    fn from_city_hash_batch(inputs: &[&[u8]], out: &mut [Self]) {
+        batch::hash64_batch(inputs, out);
+    }
+
+    #[inline]
+    fn from_city_hash_fixed_stride(data: &[u8], stride: usize, out: &mut [Self]) {
+        batch::hash64_fixed_stride(data, stride, out);
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn from_city_hash64() -> Option<fn(u64) -> Self> {
+        Some(|hash| hash)
+    }
 }
 
 impl FromSeededCityHash for u64 {}
@@ -465,17 +195,51 @@ impl FromSeededCityHash for u64 {}
 impl sealed::SealedSeeded for u64 {
     #[inline]
     fn from_city_hash_with_seed(data: &[u8], seed: Self) -> Self {
-        hash_len_16_u64(Input(data).hash64().wrapping_sub(K2), seed)
+        Input(data).hash64_with_seeds(K2, seed)
+    }
+}
+
+impl FromCityHash for u128 {}
+
+impl sealed::Sealed for u128 {
+    #[inline]
+    fn from_city_hash(data: &[u8]) -> Self {
+        u128_from_halves(Input(data).hash128())
+    }
+}
+
+impl FromSeededCityHash for u128 {}
+
+impl sealed::SealedSeeded for u128 {
+    #[inline]
+    fn from_city_hash_with_seed(data: &[u8], seed: Self) -> Self {
+        u128_from_halves(Input(data).hash128_with_seed(u128_to_halves(seed)))
     }
 }
 
+/// Combines a `(low, high)` pair into a single `u128`.
+const fn u128_from_halves((low, high): (u64, u64)) -> u128 {
+    (high as u128) << 64 | low as u128
+}
+
+/// Splits a `u128` into a `(low, high)` pair.
+const fn u128_to_halves(value: u128) -> (u64, u64) {
+    (value as u64, (value >> 64) as u64)
+}
+
 /// Hashes `data` with a seed value, using the [CityHash][cityhash] algorithm.
 ///
 /// The exact implementation is decided upon by `T`:
 ///
-/// |  `T`  | C++ Function Equivalent |
-/// |-------|-------------------------|
-/// | `u64` | `CityHash64WithSeed`    |
+/// |         `T`          | C++ Function Equivalent |
+/// |----------------------|-------------------------|
+/// | `u64`                | `CityHash64WithSeed`    |
+/// | `u128`               | `CityHash128WithSeed`   |
+/// | [`Fingerprint64`]    | `CityHash64WithSeed`    |
+/// | [`Fingerprint128`]   | `CityHash128WithSeed`   |
+///
+/// 128-bit seeds are split into the reference `uint128` with the upper 64 bits
+/// as `Uint128High64` and the lower 64 bits as `Uint128Low64`.
 ///
 /// ```rust
 /// let hello: u64 = cityhasher::hash("hello");
@@ -493,6 +257,54 @@ where
     T::from_city_hash_with_seed(data.as_ref(), seed)
 }
 
+/// Hashes `data` with two seed values, using the [CityHash][cityhash]
+/// algorithm.
+///
+/// This function is equivalent to the C++ function `CityHash64WithSeeds`.
+/// [`hash_with_seed`] is equivalent to calling this function with a `seed0`
+/// of `0x9ae16a3b2f90404f`.
+///
+/// ```rust
+/// let seeded: u64 = cityhasher::hash_with_seed("hello", 1);
+/// let double_seeded = cityhasher::hash_with_seeds("hello", 2, 1);
+///
+/// assert_ne!(seeded, double_seeded);
+/// assert_eq!(
+///     seeded,
+///     cityhasher::hash_with_seeds("hello", 0x9ae16a3b2f90404f, 1)
+/// );
+/// ```
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_with_seeds(data: impl AsRef<[u8]>, seed0: u64, seed1: u64) -> u64 {
+    Input(data.as_ref()).hash64_with_seeds(seed0, seed1)
+}
+
+/// Hashes a 128-bit value down to 64 bits.
+///
+/// This function is equivalent to the C++ function `Hash128to64`, with the
+/// upper 64 bits of `value` as `Uint128High64` and the lower 64 bits as
+/// `Uint128Low64`. CityHash uses it to combine pairs of 64-bit values, and it
+/// can be used to combine hashes of multiple values into one. The
+/// [`combine`] module builds on it to fingerprint sequences and sets.
+///
+/// ```rust
+/// let seeds = cityhasher::hash_with_seeds("hello", 1, 2);
+/// let hash: u64 = cityhasher::hash("hello");
+///
+/// assert_eq!(
+///     cityhasher::hash128_to_64((2 << 64) | u128::from(hash.wrapping_sub(1))),
+///     seeds
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub const fn hash128_to_64(value: u128) -> u64 {
+    let (low, high) = u128_to_halves(value);
+    hash_len_16_u64(low, high)
+}
+
 /// A seedable [`Hasher`] and [`BuildHasher`] implementation using the
 /// [CityHash][cityhash] algorithm.
 ///
@@ -505,6 +317,11 @@ where
 /// This crate provides type aliases for [`HashMap`] and [`HashSet`] that
 /// utilize this type as the hasher.
 ///
+/// Each call to [`Hasher::write`] rehashes the previous state with the new
+/// bytes, so the result depends on how the input is split across writes. When
+/// the result must match [`hash`] over the concatenated bytes, use
+/// `BufferingCityHasher` instead.
+///
 /// # Using a Seeded Hasher with a HashMap
 ///
 /// ```rust
@@ -533,17 +350,97 @@ impl CityHasher {
     }
 }
 
+impl CityHasher {
+    /// Incorporates `hash`, the `CityHash64` of the bytes being written, into
+    /// this hasher's state.
+    #[inline]
+    fn combine(&mut self, hash: u64) {
+        self.0 = Some(match self.0 {
+            Some(seed) => hash_len_16_u64(hash.wrapping_sub(K2), seed),
+            None => hash,
+        });
+    }
+}
+
+/// Integers are hashed as if their native-endian bytes were passed to
+/// [`Hasher::write`], but without dispatching on the input length at runtime.
 impl Hasher for CityHasher {
     fn finish(&self) -> u64 {
         self.0.unwrap_or(0)
     }
 
+    #[inline]
     fn write(&mut self, bytes: &[u8]) {
-        self.0 = Some(if let Some(seed) = self.0 {
-            hash_with_seed(bytes, seed)
-        } else {
-            hash(bytes)
-        });
+        self.combine(Input(bytes).hash64());
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.combine(hash64_len_1_to_3(i, i, i, 1));
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        let [first, last] = i.to_ne_bytes();
+        self.combine(hash64_len_1_to_3(first, last, last, 2));
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        let word = u32::from_le_bytes(i.to_ne_bytes());
+        self.combine(hash64_len_4_to_7(word, word, 4));
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        let word = u64::from_le_bytes(i.to_ne_bytes());
+        self.combine(hash64_len_8_to_16(word, word, 8));
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        let words = u128::from_le_bytes(i.to_ne_bytes());
+        self.combine(hash64_len_8_to_16(words as u64, (words >> 64) as u64, 16));
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        #[cfg(target_pointer_width = "16")]
+        self.write_u16(i as u16);
+        #[cfg(target_pointer_width = "32")]
+        self.write_u32(i as u32);
+        #[cfg(target_pointer_width = "64")]
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.write_u8(i as u8);
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write_usize(i as usize);
     }
 }
 
@@ -563,3 +460,27 @@ pub type HashMap<K, V, S = CityHasher> = std::collections::HashMap<K, V, S>;
 /// [`CityHasher`].
 #[cfg(feature = "std")]
 pub type HashSet<K, S = CityHasher> = std::collections::HashSet<K, S>;
+/// A type alias for [`std::collections::HashMap`] that hashes its keys using
+/// [`CityHasher`] with a random seed for each map.
+#[cfg(feature = "std")]
+pub type RandomHashMap<K, V> = std::collections::HashMap<K, V, RandomCityState>;
+/// A type alias for [`std::collections::HashSet`] that hashes its members using
+/// [`CityHasher`] with a random seed for each set.
+#[cfg(feature = "std")]
+pub type RandomHashSet<K> = std::collections::HashSet<K, RandomCityState>;
+/// A type alias for [`indexmap::IndexMap`] that hashes its keys using
+/// [`CityHasher`].
+#[cfg(feature = "indexmap")]
+pub type IndexMap<K, V, S = CityHasher> = ::indexmap::IndexMap<K, V, S>;
+/// A type alias for [`indexmap::IndexSet`] that hashes its members using
+/// [`CityHasher`].
+#[cfg(feature = "indexmap")]
+pub type IndexSet<K, S = CityHasher> = ::indexmap::IndexSet<K, S>;
+/// A type alias for [`indexmap::IndexMap`] that hashes its keys using
+/// [`CityHasher`] with a random seed for each map.
+#[cfg(feature = "indexmap")]
+pub type RandomIndexMap<K, V> = ::indexmap::IndexMap<K, V, RandomCityState>;
+/// A type alias for [`indexmap::IndexSet`] that hashes its members using
+/// [`CityHasher`] with a random seed for each set.
+#[cfg(feature = "indexmap")]
+pub type RandomIndexSet<K> = ::indexmap::IndexSet<K, RandomCityState>;
@@ -0,0 +1,89 @@
+use core::hash::BuildHasher;
+
+use crate::{hash, CityHasher};
+
+/// A [`BuildHasher`] that produces [`CityHasher`]s using a random seed.
+///
+/// [`CityHasher::new()`] uses no seed, which allows an attacker who controls
+/// the keys inserted into a map to precompute keys that collide. This type is
+/// the CityHash equivalent of [`std::collections::hash_map::RandomState`]:
+/// each instance created with [`RandomCityState::new()`] uses a different
+/// seed, derived from randomness provided by the operating system.
+///
+/// In `no_std` environments, the seed can be derived from caller-supplied
+/// entropy using [`RandomCityState::from_entropy()`].
+///
+/// # Limitations
+///
+/// CityHash is not a keyed pseudorandom function. Seeding raises the bar for
+/// precomputed collisions, but inputs that produce identical unseeded
+/// `CityHash64` values collide regardless of the seed. If keys are provided by
+/// an adversary that may invest in finding such collisions, a keyed hash such
+/// as SipHash is a more appropriate choice.
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use std::collections::HashMap;
+///
+/// use cityhasher::RandomCityState;
+///
+/// let mut map = HashMap::with_hasher(RandomCityState::new());
+/// map.insert(1, "hello");
+/// assert!(map.contains_key(&1));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RandomCityState {
+    seed: u64,
+}
+
+impl RandomCityState {
+    /// Returns a new state with a random seed.
+    ///
+    /// The seed is drawn from [`std::collections::hash_map::RandomState`],
+    /// which is initialized from the operating system's source of randomness
+    /// once per thread and produces a distinct seed for each call.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn new() -> Self {
+        use core::hash::Hasher;
+
+        let seed = std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish();
+        Self { seed }
+    }
+
+    /// Returns a new state whose seed is derived from `entropy`.
+    ///
+    /// `entropy` should contain at least 8 bytes from a source of randomness
+    /// that attackers cannot predict, such as a hardware random number
+    /// generator.
+    #[must_use]
+    pub fn from_entropy(entropy: impl AsRef<[u8]>) -> Self {
+        Self {
+            seed: hash(entropy),
+        }
+    }
+
+    /// Returns the seed used for the hashers this state builds.
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for RandomCityState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for RandomCityState {
+    type Hasher = CityHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        CityHasher::with_seed(self.seed)
+    }
+}
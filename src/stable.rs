@@ -0,0 +1,696 @@
+use core::marker::PhantomData;
+use core::time::Duration;
+
+use crate::city::{hash_len_16_u64, K2};
+use crate::vectored::{Segmented, Segments};
+use crate::{Fingerprint128, Fingerprint64};
+
+/// A type that can be hashed to a fingerprint that is identical on every
+/// platform.
+///
+/// Unlike [`Hash`](core::hash::Hash), whose output depends on the width of
+/// `usize`, the target's endianness and choices made by the standard library,
+/// this trait defines a canonical encoding of each value as bytes. Hashing the
+/// encoding with [`hash_stable`] produces the same fingerprint on every
+/// target, making it suitable for fingerprints that are persisted or compared
+/// across machines.
+///
+/// The encoding is the concatenation of each value's parts, with no
+/// separators or padding:
+///
+/// | Type                                     | Encoding |
+/// |------------------------------------------|----------|
+/// | `u8` through `u128`, `i8` through `i128` | little-endian bytes |
+/// | `usize`, `isize`                         | 8 little-endian bytes |
+/// | `bool`                                   | 1 byte, `0` or `1` |
+/// | `char`                                   | the scalar value as a `u32` |
+/// | `f32`, `f64`                             | the canonical bits of the value, see below |
+/// | `str`, `String`                          | the length in bytes as a `u64`, followed by the UTF-8 bytes |
+/// | `[T]`, `Vec<T>`, `VecDeque<T>`           | the number of elements as a `u64`, followed by each element |
+/// | `[T; N]`                                 | each element, as the length is part of the type |
+/// | tuples                                   | each element |
+/// | `()`, `PhantomData<T>`                   | nothing |
+/// | `Option<T>`                              | `None` as `0_u32`, or `1_u32` followed by the value |
+/// | `Result<T, E>`                           | `0_u32` followed by the `Ok` value, or `1_u32` followed by the `Err` value |
+/// | `BTreeMap<K, V>`, `BTreeSet<T>`          | the number of entries as a `u64`, followed by each key and value in order |
+/// | `HashMap<K, V>`, `HashSet<T>`            | the [`Unordered`](crate::combine::Unordered) fingerprint of each entry's [`hash_stable`], as a `u64` |
+/// | `hashbrown::HashMap<K, V>`, `hashbrown::HashSet<T>` | the same as `HashMap<K, V>` and `HashSet<T>` |
+/// | `Duration`                               | the seconds as a `u64`, followed by the nanoseconds as a `u32` |
+/// | [`Fingerprint64`], [`Fingerprint128`]    | the fingerprint's value |
+///
+/// References, `Box<T>`, `Rc<T>`, `Arc<T>` and `Cow<'_, T>` are encoded as the
+/// value they point to. Floats are canonicalized before their bits are
+/// encoded in little-endian: every NaN is encoded as the quiet NaN with no
+/// payload, and `-0.0` is encoded as `0.0`.
+///
+/// With the `macros` feature enabled, `#[derive(StableHash)]` implements this
+/// trait for structs and enums. Structs are encoded as each field in
+/// declaration order. Enums are encoded as the index of the variant in
+/// declaration order as a `u32`, followed by each of the variant's fields.
+/// Field names and explicit discriminants are not part of the encoding, so
+/// renaming a field does not change a fingerprint, but reordering fields or
+/// variants does. Type parameters are required to implement `StableHash`.
+///
+/// Implementations write their encoding using the methods of the
+/// [`StableHasher`] passed to [`stable_hash()`](Self::stable_hash):
+///
+/// ```rust
+/// use cityhasher::{StableHash, StableHasher};
+///
+/// struct Version {
+///     major: u16,
+///     minor: u16,
+///     tag: Option<&'static str>,
+/// }
+///
+/// impl StableHash for Version {
+///     fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+///         self.major.stable_hash(state);
+///         self.minor.stable_hash(state);
+///         self.tag.stable_hash(state);
+///     }
+/// }
+///
+/// let version = Version {
+///     major: 1,
+///     minor: 2,
+///     tag: None,
+/// };
+/// assert_eq!(
+///     cityhasher::hash_stable(&version),
+///     cityhasher::hash_stable(&(1_u16, 2_u16, None::<&str>))
+/// );
+/// ```
+pub trait StableHash {
+    /// Writes the canonical encoding of this value to `state`.
+    fn stable_hash<H: StableHasher>(&self, state: &mut H);
+
+    /// Writes the canonical encoding of each element of `data` to `state`.
+    ///
+    /// This does not write the number of elements. The default implementation
+    /// hashes each element in order. Implementations may only override it to
+    /// write the same bytes more efficiently.
+    fn stable_hash_slice<H: StableHasher>(data: &[Self], state: &mut H)
+    where
+        Self: Sized,
+    {
+        for value in data {
+            value.stable_hash(state);
+        }
+    }
+}
+
+/// Receives the canonical encoding of values implementing [`StableHash`].
+///
+/// This trait is sealed. Each method appends the documented encoding of its
+/// argument to the input being hashed.
+pub trait StableHasher: sealed::Sealed {
+    /// Writes `bytes` unchanged.
+    ///
+    /// The length of `bytes` is not written. If it can vary, write it first
+    /// using [`write_len()`](Self::write_len).
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_raw(bytes);
+    }
+
+    /// Writes `value` as 1 byte.
+    #[inline]
+    fn write_u8(&mut self, value: u8) {
+        self.write_raw(&[value]);
+    }
+
+    /// Writes `value` as 2 little-endian bytes.
+    #[inline]
+    fn write_u16(&mut self, value: u16) {
+        self.write_raw(&value.to_le_bytes());
+    }
+
+    /// Writes `value` as 4 little-endian bytes.
+    #[inline]
+    fn write_u32(&mut self, value: u32) {
+        self.write_raw(&value.to_le_bytes());
+    }
+
+    /// Writes `value` as 8 little-endian bytes.
+    #[inline]
+    fn write_u64(&mut self, value: u64) {
+        self.write_raw(&value.to_le_bytes());
+    }
+
+    /// Writes `value` as 16 little-endian bytes.
+    #[inline]
+    fn write_u128(&mut self, value: u128) {
+        self.write_raw(&value.to_le_bytes());
+    }
+
+    /// Writes `value` as 8 little-endian bytes, regardless of the width of
+    /// `usize`.
+    #[inline]
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+
+    /// Writes `value` as 1 byte.
+    #[inline]
+    fn write_i8(&mut self, value: i8) {
+        self.write_u8(value as u8);
+    }
+
+    /// Writes `value` as 2 little-endian bytes.
+    #[inline]
+    fn write_i16(&mut self, value: i16) {
+        self.write_u16(value as u16);
+    }
+
+    /// Writes `value` as 4 little-endian bytes.
+    #[inline]
+    fn write_i32(&mut self, value: i32) {
+        self.write_u32(value as u32);
+    }
+
+    /// Writes `value` as 8 little-endian bytes.
+    #[inline]
+    fn write_i64(&mut self, value: i64) {
+        self.write_u64(value as u64);
+    }
+
+    /// Writes `value` as 16 little-endian bytes.
+    #[inline]
+    fn write_i128(&mut self, value: i128) {
+        self.write_u128(value as u128);
+    }
+
+    /// Writes `value` as 8 little-endian bytes, regardless of the width of
+    /// `isize`.
+    #[inline]
+    fn write_isize(&mut self, value: isize) {
+        self.write_i64(value as i64);
+    }
+
+    /// Writes `len`, the length of a variable-length value, as 8
+    /// little-endian bytes.
+    #[inline]
+    fn write_len(&mut self, len: usize) {
+        self.write_usize(len);
+    }
+
+    /// Writes `value` as 1 byte, `0` or `1`.
+    #[inline]
+    fn write_bool(&mut self, value: bool) {
+        self.write_u8(u8::from(value));
+    }
+
+    /// Writes the scalar value of `value` as 4 little-endian bytes.
+    #[inline]
+    fn write_char(&mut self, value: char) {
+        self.write_u32(u32::from(value));
+    }
+
+    /// Writes the canonical bits of `value` as 4 little-endian bytes.
+    ///
+    /// Every NaN is written as `0x7fc0_0000`, and `-0.0` is written as `0.0`.
+    #[inline]
+    fn write_f32(&mut self, value: f32) {
+        let bits = if value.is_nan() {
+            0x7fc0_0000
+        } else if value == 0.0 {
+            0
+        } else {
+            value.to_bits()
+        };
+        self.write_u32(bits);
+    }
+
+    /// Writes the canonical bits of `value` as 8 little-endian bytes.
+    ///
+    /// Every NaN is written as `0x7ff8_0000_0000_0000`, and `-0.0` is written
+    /// as `0.0`.
+    #[inline]
+    fn write_f64(&mut self, value: f64) {
+        let bits = if value.is_nan() {
+            0x7ff8_0000_0000_0000
+        } else if value == 0.0 {
+            0
+        } else {
+            value.to_bits()
+        };
+        self.write_u64(bits);
+    }
+
+    /// Writes the length of `value` in bytes as 8 little-endian bytes,
+    /// followed by its UTF-8 bytes.
+    #[inline]
+    fn write_str(&mut self, value: &str) {
+        self.write_len(value.len());
+        self.write_raw(value.as_bytes());
+    }
+
+    /// Writes the index of an enum variant, in declaration order, as 4
+    /// little-endian bytes.
+    #[inline]
+    fn write_variant(&mut self, index: u32) {
+        self.write_u32(index);
+    }
+}
+
+mod sealed {
+    pub trait Sealed {
+        fn write_raw(&mut self, bytes: &[u8]);
+    }
+}
+
+/// A [`StableHasher`] that passes each write to `F`.
+struct Sink<F>(F);
+
+impl<F> StableHasher for Sink<F> where F: FnMut(&[u8]) {}
+
+impl<F> sealed::Sealed for Sink<F>
+where
+    F: FnMut(&[u8]),
+{
+    #[inline]
+    fn write_raw(&mut self, bytes: &[u8]) {
+        (self.0)(bytes);
+    }
+}
+
+/// The canonical encoding of a value.
+struct StableSegments<'a, T: ?Sized>(&'a T);
+
+impl<T> Segments for StableSegments<'_, T>
+where
+    T: StableHash + ?Sized,
+{
+    fn for_each_segment<F>(&self, f: F)
+    where
+        F: FnMut(&[u8]),
+    {
+        self.0.stable_hash(&mut Sink(f));
+    }
+}
+
+/// Hashes the canonical encoding of `value` using the `CityHash64` function
+/// of the [CityHash][cityhash] algorithm.
+///
+/// The result is identical to [`hash`](crate::hash) of the encoding described
+/// by [`StableHash`], and is the same on every target.
+///
+/// Like [`hash_value`](crate::hash_value), the encoding is never buffered.
+/// Values whose encoding is longer than 64 bytes are encoded twice: once to
+/// find the length and the final bytes of the encoding, and once to hash the
+/// remaining bytes.
+///
+/// ```rust
+/// let hash = cityhasher::hash_stable(&(1_u32, "hello"));
+///
+/// let mut bytes = 1_u32.to_le_bytes().to_vec();
+/// bytes.extend_from_slice(&5_u64.to_le_bytes());
+/// bytes.extend_from_slice(b"hello");
+/// let expected: u64 = cityhasher::hash(&bytes);
+///
+/// assert_eq!(hash, expected);
+/// ```
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_stable<T>(value: &T) -> u64
+where
+    T: StableHash + ?Sized,
+{
+    Segmented::<64, _>::new(StableSegments(value)).hash64()
+}
+
+/// Hashes the canonical encoding of `value` with a seed, using the
+/// `CityHash64WithSeed` function of the [CityHash][cityhash] algorithm.
+///
+/// The result is identical to [`hash_with_seed`](crate::hash_with_seed) of the
+/// encoding described by [`StableHash`].
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_stable_with_seed<T>(value: &T, seed: u64) -> u64
+where
+    T: StableHash + ?Sized,
+{
+    hash_len_16_u64(hash_stable(value).wrapping_sub(K2), seed)
+}
+
+macro_rules! impl_write {
+    ($($type:ty => $write:ident),+ $(,)?) => {
+        $(
+            impl StableHash for $type {
+                #[inline]
+                fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+                    state.$write(*self);
+                }
+            }
+        )+
+    };
+}
+
+impl_write!(
+    u16 => write_u16,
+    u32 => write_u32,
+    u64 => write_u64,
+    u128 => write_u128,
+    usize => write_usize,
+    i8 => write_i8,
+    i16 => write_i16,
+    i32 => write_i32,
+    i64 => write_i64,
+    i128 => write_i128,
+    isize => write_isize,
+    bool => write_bool,
+    char => write_char,
+    f32 => write_f32,
+    f64 => write_f64,
+);
+
+impl StableHash for u8 {
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+        state.write_u8(*self);
+    }
+
+    #[inline]
+    fn stable_hash_slice<H: StableHasher>(data: &[Self], state: &mut H) {
+        state.write_bytes(data);
+    }
+}
+
+impl StableHash for str {
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+        state.write_str(self);
+    }
+}
+
+impl<T> StableHash for [T]
+where
+    T: StableHash,
+{
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+        state.write_len(self.len());
+        T::stable_hash_slice(self, state);
+    }
+}
+
+impl<T, const N: usize> StableHash for [T; N]
+where
+    T: StableHash,
+{
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+        T::stable_hash_slice(self, state);
+    }
+}
+
+impl<T> StableHash for &T
+where
+    T: StableHash + ?Sized,
+{
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+        T::stable_hash(*self, state);
+    }
+}
+
+impl<T> StableHash for &mut T
+where
+    T: StableHash + ?Sized,
+{
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+        T::stable_hash(*self, state);
+    }
+}
+
+impl<T> StableHash for PhantomData<T>
+where
+    T: ?Sized,
+{
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, _state: &mut H) {}
+}
+
+impl<T> StableHash for Option<T>
+where
+    T: StableHash,
+{
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+        match self {
+            None => state.write_variant(0),
+            Some(value) => {
+                state.write_variant(1);
+                value.stable_hash(state);
+            }
+        }
+    }
+}
+
+impl<T, E> StableHash for Result<T, E>
+where
+    T: StableHash,
+    E: StableHash,
+{
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+        match self {
+            Ok(value) => {
+                state.write_variant(0);
+                value.stable_hash(state);
+            }
+            Err(error) => {
+                state.write_variant(1);
+                error.stable_hash(state);
+            }
+        }
+    }
+}
+
+impl StableHash for Duration {
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+        state.write_u64(self.as_secs());
+        state.write_u32(self.subsec_nanos());
+    }
+}
+
+impl StableHash for Fingerprint64 {
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+        state.write_u64(self.get());
+    }
+}
+
+impl StableHash for Fingerprint128 {
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+        state.write_u128(self.get());
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($name:ident)*) => {
+        impl<$($name),*> StableHash for ($($name,)*)
+        where
+            $($name: StableHash,)*
+        {
+            #[inline]
+            #[allow(non_snake_case, unused_variables)]
+            fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+                let ($($name,)*) = self;
+                $($name.stable_hash(state);)*
+            }
+        }
+    };
+}
+
+impl_tuple!();
+impl_tuple!(A);
+impl_tuple!(A B);
+impl_tuple!(A B C);
+impl_tuple!(A B C D);
+impl_tuple!(A B C D E);
+impl_tuple!(A B C D E F);
+impl_tuple!(A B C D E F G);
+impl_tuple!(A B C D E F G I);
+impl_tuple!(A B C D E F G I J);
+impl_tuple!(A B C D E F G I J K);
+impl_tuple!(A B C D E F G I J K L);
+impl_tuple!(A B C D E F G I J K L M);
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use alloc::borrow::{Cow, ToOwned};
+    use alloc::boxed::Box;
+    use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+    use alloc::rc::Rc;
+    use alloc::string::String;
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+
+    use super::{StableHash, StableHasher};
+
+    impl StableHash for String {
+        #[inline]
+        fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+            state.write_str(self);
+        }
+    }
+
+    impl<T> StableHash for Vec<T>
+    where
+        T: StableHash,
+    {
+        #[inline]
+        fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+            self.as_slice().stable_hash(state);
+        }
+    }
+
+    impl<T> StableHash for VecDeque<T>
+    where
+        T: StableHash,
+    {
+        #[inline]
+        fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+            let (front, back) = self.as_slices();
+            state.write_len(self.len());
+            T::stable_hash_slice(front, state);
+            T::stable_hash_slice(back, state);
+        }
+    }
+
+    impl<K, V> StableHash for BTreeMap<K, V>
+    where
+        K: StableHash,
+        V: StableHash,
+    {
+        fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+            state.write_len(self.len());
+            for (key, value) in self {
+                key.stable_hash(state);
+                value.stable_hash(state);
+            }
+        }
+    }
+
+    impl<T> StableHash for BTreeSet<T>
+    where
+        T: StableHash,
+    {
+        fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+            state.write_len(self.len());
+            for value in self {
+                value.stable_hash(state);
+            }
+        }
+    }
+
+    macro_rules! impl_pointer {
+        ($($pointer:ident),+) => {
+            $(
+                impl<T> StableHash for $pointer<T>
+                where
+                    T: StableHash + ?Sized,
+                {
+                    #[inline]
+                    fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+                        T::stable_hash(self, state);
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_pointer!(Box, Rc, Arc);
+
+    impl<T> StableHash for Cow<'_, T>
+    where
+        T: StableHash + ToOwned + ?Sized,
+    {
+        #[inline]
+        fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+            T::stable_hash(self, state);
+        }
+    }
+}
+
+/// Writes the [`Unordered`](crate::combine::Unordered) fingerprint of each
+/// of `entries`' [`hash_stable`] to `state`.
+///
+/// Each entry is encoded into a buffer once. Using [`hash_stable`] instead
+/// would encode long entries twice, and collections nested inside those
+/// entries twice more at each level.
+#[cfg(feature = "alloc")]
+fn write_unordered<H, I>(entries: I, state: &mut H)
+where
+    H: StableHasher,
+    I: Iterator,
+    I::Item: StableHash,
+{
+    let mut fingerprint = crate::combine::Unordered::new();
+    let mut buffer = alloc::vec::Vec::new();
+    for entry in entries {
+        buffer.clear();
+        entry.stable_hash(&mut Sink(|bytes: &[u8]| buffer.extend_from_slice(bytes)));
+        fingerprint.insert(crate::hash(&buffer));
+    }
+    state.write_u64(fingerprint.finish());
+}
+
+#[cfg(feature = "std")]
+mod std_impls {
+    use std::collections::{HashMap, HashSet};
+
+    use super::{write_unordered, StableHash, StableHasher};
+
+    impl<K, V, S> StableHash for HashMap<K, V, S>
+    where
+        K: StableHash,
+        V: StableHash,
+    {
+        fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+            write_unordered(self.iter(), state);
+        }
+    }
+
+    impl<T, S> StableHash for HashSet<T, S>
+    where
+        T: StableHash,
+    {
+        fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+            write_unordered(self.iter(), state);
+        }
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+mod hashbrown_impls {
+    use ::hashbrown::{HashMap, HashSet};
+
+    use super::{write_unordered, StableHash, StableHasher};
+
+    impl<K, V, S> StableHash for HashMap<K, V, S>
+    where
+        K: StableHash,
+        V: StableHash,
+    {
+        fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+            write_unordered(self.iter(), state);
+        }
+    }
+
+    impl<T, S> StableHash for HashSet<T, S>
+    where
+        T: StableHash,
+    {
+        fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+            write_unordered(self.iter(), state);
+        }
+    }
+}
@@ -1,12 +1,15 @@
 use std::vec;
 use std::vec::Vec;
 
-use crate::K0;
+use crate::city::{Input, K0};
+use crate::crc::{CrcInput, Software};
 extern crate std;
 
 const TEST_COUNT: usize = 300;
 const DATA_SIZE: usize = 1 << 20;
 const KSEED0: u64 = 1234567;
+const KSEED1: u64 = K0;
+const KSEED128: u128 = (KSEED1 as u128) << 64 | KSEED0 as u128;
 
 #[test]
 fn suite() {
@@ -33,6 +36,46 @@ fn setup() -> Vec<u8> {
     data
 }
 
+#[test]
+fn suite_v1_0_2() {
+    let data = setup_v1_0_2();
+    for i in 0..TEST_COUNT - 1 {
+        let offset = i * i;
+        test_v1_0_2(&TESTDATA_V1_0_2[i], &data[offset..offset + i]);
+    }
+    test_v1_0_2(&TESTDATA_V1_0_2[299], &data);
+}
+
+/// Generates the test input used by the v1.0.2 reference release, which
+/// differs from later releases.
+fn setup_v1_0_2() -> Vec<u8> {
+    let mut data = vec![0_u8; DATA_SIZE];
+    let mut a = 9_u64;
+    let mut b = 777_u64;
+    for (i, data) in data.iter_mut().enumerate() {
+        a = (a ^ (a >> 41)).wrapping_mul(K0).wrapping_add(b);
+        b = (b ^ (b >> 41)).wrapping_mul(K0).wrapping_add(i as u64);
+        *data = (b >> 37) as u8;
+    }
+    data
+}
+
+fn test_v1_0_2(expected: &[u64; 7], data: &[u8]) {
+    use crate::v1_0_2;
+
+    std::println!("Testing v1.0.2 {}", data.len());
+    let hash: u64 = v1_0_2::hash(data);
+    assert_eq!(expected[0], hash);
+    assert_eq!(expected[1], v1_0_2::hash_with_seed(data, KSEED0));
+    assert_eq!(expected[2], v1_0_2::hash_with_seeds(data, KSEED0, KSEED1));
+    let hash: u128 = v1_0_2::hash(data);
+    assert_eq!(expected[3], hash as u64);
+    assert_eq!(expected[4], (hash >> 64) as u64);
+    let hash: u128 = v1_0_2::hash_with_seed(data, KSEED128);
+    assert_eq!(expected[5], hash as u64);
+    assert_eq!(expected[6], (hash >> 64) as u64);
+}
+
 fn test(expected: &[u64; 16], data: &[u8]) {
     std::println!("Testing {}", data.len());
     // 32-bit hash
@@ -42,6 +85,1302 @@ fn test(expected: &[u64; 16], data: &[u8]) {
     assert_eq!(expected[0], crate::hash(data));
     // 64-bit hash with seed
     assert_eq!(expected[1], crate::hash_with_seed(data, KSEED0));
+    // Compile-time implementations
+    assert_eq!(expected[15], crate::hash32(data) as u64);
+    assert_eq!(expected[0], crate::hash64(data));
+    assert_eq!(expected[1], crate::hash64_with_seed(data, KSEED0));
+    // 64-bit hash with two seeds
+    assert_eq!(expected[2], crate::hash_with_seeds(data, KSEED0, KSEED1));
+    // CityHash64WithSeeds is defined in terms of Hash128to64
+    let seeded = (KSEED1 as u128) << 64 | u128::from(expected[0].wrapping_sub(KSEED0));
+    assert_eq!(expected[2], crate::hash128_to_64(seeded));
+    // 128-bit hash
+    let hash: u128 = crate::hash(data);
+    assert_eq!(expected[3], hash as u64);
+    assert_eq!(expected[4], (hash >> 64) as u64);
+    // 128-bit hash with seed
+    let hash: u128 = crate::hash_with_seed(data, KSEED128);
+    assert_eq!(expected[5], hash as u64);
+    assert_eq!(expected[6], (hash >> 64) as u64);
+    // CRC32-accelerated 128-bit hash
+    let hash: u128 = crate::hash_crc(data);
+    assert_eq!(expected[7], hash as u64);
+    assert_eq!(expected[8], (hash >> 64) as u64);
+    // CRC32-accelerated 128-bit hash with seed
+    let hash: u128 = crate::hash_crc_with_seed(data, KSEED128);
+    assert_eq!(expected[9], hash as u64);
+    assert_eq!(expected[10], (hash >> 64) as u64);
+    // CRC32-accelerated 256-bit hash
+    let hash: [u64; 4] = crate::hash_crc(data);
+    assert_eq!(expected[11..15], hash);
+    // The portable CRC32 implementation must produce identical output.
+    let hash = Input(data).hash_crc256_with(|input, seed| input.hash_crc256_long::<Software>(seed));
+    assert_eq!(expected[11..15], hash);
+}
+
+#[test]
+fn const_hash_in_const_items() {
+    const SHORT32: u32 = crate::hash32(b"hello");
+    const LONG32: u32 = crate::hash32(b"The quick brown fox jumps over the lazy dog");
+    const SHORT64: u64 = crate::hash64(b"hello");
+    const LONG64: u64 = crate::hash64(&[0xa5; 200]);
+    const SEEDED64: u64 = crate::hash64_with_seed(b"hello", KSEED0);
+
+    assert_eq!(SHORT32, crate::hash("hello"));
+    assert_eq!(
+        LONG32,
+        crate::hash("The quick brown fox jumps over the lazy dog")
+    );
+    assert_eq!(SHORT64, crate::hash("hello"));
+    assert_eq!(LONG64, crate::hash([0xa5; 200]));
+    assert_eq!(SEEDED64, crate::hash_with_seed("hello", KSEED0));
+}
+
+#[test]
+#[cfg(feature = "macros")]
+fn macros_match_runtime() {
+    macro_rules! check {
+        ($literal:expr, $bytes:expr) => {{
+            let expected32: u32 = crate::hash($bytes);
+            let expected64: u64 = crate::hash($bytes);
+            assert_eq!(crate::cityhash32!($literal), expected32);
+            assert_eq!(crate::cityhash64!($literal), expected64);
+        }};
+    }
+
+    check!("", "");
+    check!("abc", "abc");
+    check!(b"\x00\xff\n", [0, 0xff, b'\n']);
+    check!("event.login", "event.login");
+    check!(r#"quoted "text" \n"#, "quoted \"text\" \\n");
+    check!("\u{1F600} unicode", "\u{1F600} unicode");
+    check!(
+        "The quick brown fox jumps over the lazy dog",
+        "The quick brown fox jumps over the lazy dog"
+    );
+    check!(
+        b"0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789",
+        "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789"
+    );
+}
+
+#[test]
+#[cfg(feature = "macros")]
+fn macros_in_match_arms() {
+    fn event_name(id: u64) -> Option<&'static str> {
+        match id {
+            crate::cityhash64!("event.login") => Some("login"),
+            crate::cityhash64!("event.logout") => Some("logout"),
+            _ => None,
+        }
+    }
+
+    let login: u64 = crate::hash("event.login");
+    let logout: u64 = crate::hash("event.logout");
+    assert_eq!(event_name(login), Some("login"));
+    assert_eq!(event_name(logout), Some("logout"));
+    assert_eq!(event_name(0), None);
+}
+
+#[test]
+fn hasher_integer_writes_match_bytes() {
+    use core::hash::Hasher;
+
+    use crate::CityHasher;
+
+    fn check(write_integer: impl Fn(&mut CityHasher), write_bytes: impl Fn(&mut CityHasher)) {
+        for mut hasher in [CityHasher::new(), CityHasher::with_seed(KSEED0)] {
+            for _ in 0..3 {
+                let mut integer = hasher;
+                write_integer(&mut integer);
+                let mut bytes = hasher;
+                write_bytes(&mut bytes);
+                assert_eq!(integer, bytes);
+                hasher = integer;
+            }
+        }
+    }
+
+    let data = setup();
+    for chunk in data[..4096].chunks_exact(16) {
+        let value = u128::from_le_bytes(chunk.try_into().unwrap());
+        check(
+            |h| h.write_u8(value as u8),
+            |h| h.write(&(value as u8).to_ne_bytes()),
+        );
+        check(
+            |h| h.write_u16(value as u16),
+            |h| h.write(&(value as u16).to_ne_bytes()),
+        );
+        check(
+            |h| h.write_u32(value as u32),
+            |h| h.write(&(value as u32).to_ne_bytes()),
+        );
+        check(
+            |h| h.write_u64(value as u64),
+            |h| h.write(&(value as u64).to_ne_bytes()),
+        );
+        check(|h| h.write_u128(value), |h| h.write(&value.to_ne_bytes()));
+        check(
+            |h| h.write_usize(value as usize),
+            |h| h.write(&(value as usize).to_ne_bytes()),
+        );
+        check(
+            |h| h.write_i8(value as i8),
+            |h| h.write(&(value as i8).to_ne_bytes()),
+        );
+        check(
+            |h| h.write_i16(value as i16),
+            |h| h.write(&(value as i16).to_ne_bytes()),
+        );
+        check(
+            |h| h.write_i32(value as i32),
+            |h| h.write(&(value as i32).to_ne_bytes()),
+        );
+        check(
+            |h| h.write_i64(value as i64),
+            |h| h.write(&(value as i64).to_ne_bytes()),
+        );
+        check(
+            |h| h.write_i128(value as i128),
+            |h| h.write(&(value as i128).to_ne_bytes()),
+        );
+        check(
+            |h| h.write_isize(value as isize),
+            |h| h.write(&(value as isize).to_ne_bytes()),
+        );
+    }
+}
+
+#[test]
+fn random_state_from_entropy() {
+    use core::hash::BuildHasher;
+
+    use crate::{CityHasher, RandomCityState};
+
+    let state = RandomCityState::from_entropy(KSEED0.to_le_bytes());
+    assert_eq!(state, RandomCityState::from_entropy(KSEED0.to_le_bytes()));
+    assert_ne!(state, RandomCityState::from_entropy(K0.to_le_bytes()));
+    assert_eq!(
+        state.build_hasher(),
+        CityHasher::with_seed(crate::hash(KSEED0.to_le_bytes()))
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn random_state_seeds_differ() {
+    use crate::{RandomCityState, RandomHashMap};
+
+    // Two independently drawn 64-bit seeds should never be equal in practice.
+    assert_ne!(RandomCityState::new().seed(), RandomCityState::new().seed());
+
+    let mut map = RandomHashMap::default();
+    map.insert("hello", 1);
+    assert_eq!(map.get("hello"), Some(&1));
+}
+
+/// A xorshift generator, used to split the test inputs at arbitrary points.
+#[cfg(feature = "alloc")]
+struct SplitPoints(u64);
+
+#[cfg(feature = "alloc")]
+impl SplitPoints {
+    fn next_len(&mut self, remaining: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        // Favor short writes, but occasionally write large pieces.
+        let max = if self.0 & 0xf == 0 { remaining } else { 80 };
+        (self.0 >> 8) as usize % (max.min(remaining) + 1)
+    }
+
+    fn write_all(&mut self, hasher: &mut impl core::hash::Hasher, mut data: &[u8]) {
+        while !data.is_empty() {
+            let (piece, rest) = data.split_at(self.next_len(data.len()));
+            hasher.write(piece);
+            data = rest;
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn buffering_random_splits() {
+    use core::hash::Hasher;
+
+    use crate::BufferingCityHasher;
+
+    let data = setup();
+    let mut splits = SplitPoints(0x2545_f491_4f6c_dd1d);
+    let inputs = (0..TEST_COUNT - 1)
+        .map(|i| &data[i * i..i * i + i])
+        .chain(Some(&data[..]));
+    for (expected, input) in TESTDATA.iter().zip(inputs) {
+        for _ in 0..4 {
+            let mut hasher = BufferingCityHasher::new();
+            splits.write_all(&mut hasher, input);
+            assert_eq!(hasher.bytes(), input);
+            assert_eq!(expected[0], hasher.finish());
+
+            let mut hasher = BufferingCityHasher::with_seed(KSEED0);
+            splits.write_all(&mut hasher, input);
+            assert_eq!(expected[1], hasher.finish());
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn buffering_empty() {
+    use core::hash::Hasher;
+
+    use crate::BufferingCityHasher;
+
+    assert_eq!(BufferingCityHasher::new().finish(), TESTDATA[0][0]);
+    assert_eq!(
+        BufferingCityHasher::with_seed(KSEED0).finish(),
+        TESTDATA[0][1]
+    );
+
+    let mut hasher = BufferingCityHasher::with_seed(KSEED0);
+    hasher.write(b"hello");
+    hasher.reset();
+    assert_eq!(hasher.finish(), TESTDATA[0][1]);
+}
+
+#[test]
+#[cfg(feature = "digest")]
+fn digest_matches_hash() {
+    use digest::{Digest, FixedOutputReset, Update};
+
+    use crate::{CityHash128Digest, CityHash64Digest};
+
+    let data = setup();
+    for i in [0, 1, 16, 64, 65, 128, 298] {
+        let expected = &TESTDATA[i];
+        let input = &data[i * i..i * i + i];
+        let mut hasher = CityHash64Digest::new();
+        for piece in input.chunks(7) {
+            Digest::update(&mut hasher, piece);
+        }
+        assert_eq!(hasher.finalize()[..], expected[0].to_be_bytes());
+        let mut hasher = CityHash64Digest::with_seed(KSEED0);
+        Update::update(&mut hasher, input);
+        assert_eq!(hasher.finalize_fixed_reset()[..], expected[1].to_be_bytes());
+        assert_eq!(hasher, CityHash64Digest::with_seed(KSEED0));
+
+        let hash = (u128::from(expected[4]) << 64) | u128::from(expected[3]);
+        assert_eq!(CityHash128Digest::digest(input)[..], hash.to_be_bytes());
+        let hash = (u128::from(expected[6]) << 64) | u128::from(expected[5]);
+        let mut hasher = CityHash128Digest::with_seed(KSEED128);
+        Update::update(&mut hasher, input);
+        assert_eq!(hasher.finalize()[..], hash.to_be_bytes());
+    }
+}
+
+#[test]
+#[cfg(feature = "hashbrown")]
+fn hashbrown_aliases() {
+    use crate::hashbrown::{HashMap, HashSet, RandomHashMap};
+    use crate::RandomCityState;
+
+    let mut map: HashMap<_, _> = HashMap::default();
+    map.insert("hello", 1);
+    assert_eq!(map.get("hello"), Some(&1));
+    let mut set: HashSet<_> = HashSet::default();
+    assert!(set.insert(1));
+    assert!(!set.insert(1));
+    let mut map = RandomHashMap::with_hasher(RandomCityState::from_entropy([1; 8]));
+    map.insert(1, "hello");
+    assert_eq!(map.get(&1), Some(&"hello"));
+}
+
+#[test]
+#[cfg(feature = "indexmap")]
+fn indexmap_aliases() {
+    use crate::{IndexMap, IndexSet, RandomCityState, RandomIndexSet};
+
+    let mut map: IndexMap<_, _> = IndexMap::default();
+    map.insert("b", 1);
+    map.insert("a", 2);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["b", "a"]);
+    let set: IndexSet<_> = [3, 1, 2].into_iter().collect();
+    assert_eq!(set.get_index(1), Some(&1));
+    let mut set = RandomIndexSet::with_hasher(RandomCityState::from_entropy([1; 8]));
+    assert!(set.insert(1));
+    assert!(set.contains(&1));
+}
+
+#[test]
+fn fingerprints() {
+    use std::format;
+    use std::string::ToString;
+
+    use crate::{Fingerprint128, Fingerprint64};
+
+    let data = setup();
+    for i in [0, 1, 16, 64, 65, 128, 298] {
+        let expected = &TESTDATA[i];
+        let input = &data[i * i..i * i + i];
+
+        let fingerprint: Fingerprint64 = crate::hash(input);
+        assert_eq!(fingerprint.get(), expected[0]);
+        assert_eq!(fingerprint.to_string(), format!("{:016x}", expected[0]));
+        assert_eq!(
+            format!("{fingerprint:#x}"),
+            format!("{:#018x}", expected[0])
+        );
+        assert_eq!(fingerprint.to_string().parse(), Ok(fingerprint));
+        assert_eq!(
+            Fingerprint64::from_bytes(&fingerprint.to_be_bytes()),
+            Ok(fingerprint)
+        );
+        let seeded = crate::hash_with_seed(input, Fingerprint64::new(KSEED0));
+        assert_eq!(seeded.get(), expected[1]);
+
+        let fingerprint: Fingerprint128 = crate::hash(input);
+        assert_eq!(
+            (fingerprint.low(), fingerprint.high()),
+            (expected[3], expected[4])
+        );
+        let hex = format!("{:016x}{:016x}", expected[3], expected[4]);
+        assert_eq!(fingerprint.to_string(), hex);
+        assert_eq!(format!("{fingerprint:x}"), hex);
+        assert_eq!(hex.to_uppercase().parse(), Ok(fingerprint));
+        assert_eq!(
+            Fingerprint128::from_bytes(&fingerprint.to_be_bytes()),
+            Ok(fingerprint)
+        );
+        let seeded = crate::hash_with_seed(input, Fingerprint128::new(KSEED128));
+        assert_eq!(
+            Fingerprint128::from_halves(expected[5], expected[6]),
+            seeded
+        );
+    }
+
+    // Fingerprints sort like their strings and bytes, by `Uint128Low64` first.
+    let low = Fingerprint128::from_halves(0, u64::MAX);
+    let high = Fingerprint128::from_halves(1, 0);
+    assert!(low < high);
+    assert!(low.to_string() < high.to_string());
+    assert!(low.to_be_bytes() < high.to_be_bytes());
+    assert!(low.get() > high.get());
+    assert_eq!(format!("{low:>34}"), format!("  {low}"));
+
+    assert!("".parse::<Fingerprint64>().is_err());
+    assert!("+123456789abcdef".parse::<Fingerprint64>().is_err());
+    assert!("0123456789abcdef0".parse::<Fingerprint64>().is_err());
+    assert!("0123456789abcdef".parse::<Fingerprint128>().is_err());
+    assert!(Fingerprint128::from_bytes(&[0; 8]).is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn fingerprints_serde() {
+    use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+    use crate::{Fingerprint128, Fingerprint64};
+
+    let fingerprint = Fingerprint64::new(0x0123_4567_89ab_cdef);
+    assert_tokens(&fingerprint.readable(), &[Token::Str("0123456789abcdef")]);
+    assert_tokens(
+        &fingerprint.compact(),
+        &[Token::Bytes(&[
+            0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef,
+        ])],
+    );
+
+    let fingerprint = Fingerprint128::from_halves(1, 2);
+    assert_tokens(
+        &fingerprint.readable(),
+        &[Token::Str("00000000000000010000000000000002")],
+    );
+    const BYTES: &[u8] = &[0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2];
+    assert_tokens(&fingerprint.compact(), &[Token::Bytes(BYTES)]);
+    let mut seq = std::vec![Token::Seq { len: Some(16) }];
+    seq.extend(BYTES.iter().map(|&byte| Token::U8(byte)));
+    seq.push(Token::SeqEnd);
+    assert_de_tokens(&fingerprint.compact(), &seq);
+}
+
+#[test]
+fn combinators() {
+    use crate::combine::{Ordered, Unordered};
+
+    let hashes = &TESTDATA[..32].iter().map(|row| row[0]).collect::<Vec<_>>();
+
+    let mut ordered = Ordered::new();
+    let mut state = 0;
+    for &hash in hashes {
+        ordered.push(hash);
+        state = crate::hash128_to_64(u128::from(hash) << 64 | u128::from(state));
+    }
+    assert_eq!(ordered.len(), 32);
+    assert_eq!(
+        ordered.finish(),
+        crate::hash128_to_64(32 << 64 | u128::from(state))
+    );
+    let reversed: Ordered = hashes.iter().rev().copied().collect();
+    assert_ne!(reversed.finish(), ordered.finish());
+    assert_ne!(Ordered::new().finish(), Ordered::from_iter([0]).finish());
+
+    let all: Unordered = hashes.iter().copied().collect();
+    let reversed: Unordered = hashes.iter().rev().copied().collect();
+    assert_eq!(all, reversed);
+    assert_eq!(all.len(), 32);
+
+    let mut evens: Unordered = hashes.iter().step_by(2).copied().collect();
+    let odds: Unordered = hashes.iter().skip(1).step_by(2).copied().collect();
+    assert_ne!(evens.finish(), all.finish());
+    evens.merge(&odds);
+    assert_eq!(evens.finish(), all.finish());
+
+    let mut removed = all;
+    for &hash in hashes.iter().skip(1).step_by(2) {
+        removed.remove(hash);
+    }
+    assert_eq!(
+        removed.finish(),
+        Unordered::from_iter(hashes.iter().step_by(2).copied()).finish()
+    );
+    for &hash in hashes.iter().step_by(2) {
+        removed.remove(hash);
+    }
+    assert!(removed.is_empty());
+    assert_eq!(removed.finish(), Unordered::new().finish());
+
+    // Duplicates are counted, so a multiset differs from its set of elements.
+    let twice: Unordered = hashes.iter().chain(hashes).copied().collect();
+    assert_ne!(twice.finish(), all.finish());
+    // Mixing prevents hashes whose sums match from colliding.
+    let pair: Unordered = [1, 2].into_iter().collect();
+    let shifted: Unordered = [0, 3].into_iter().collect();
+    assert_ne!(pair.finish(), shifted.finish());
+}
+
+#[test]
+fn vectored_matches_hash() {
+    use crate::Fingerprint64;
+
+    let data = setup();
+    let mut splits = 0x2545_f491_4f6c_dd1d_u64;
+    let inputs = (0..TEST_COUNT - 1)
+        .map(|i| &data[i * i..i * i + i])
+        .chain(Some(&data[..]));
+    for (expected, input) in TESTDATA.iter().zip(inputs) {
+        for max_segment in [1, 7, 20, 63, 64, 65, 200, usize::MAX] {
+            let mut segments = Vec::new();
+            let mut rest = input;
+            while !rest.is_empty() {
+                splits ^= splits << 13;
+                splits ^= splits >> 7;
+                splits ^= splits << 17;
+                let len = (splits as usize % max_segment.min(rest.len())) + 1;
+                let (segment, remaining) = rest.split_at(len);
+                segments.push(segment);
+                // Empty segments are allowed anywhere.
+                if splits & 0x10 == 0 {
+                    segments.push(&[]);
+                }
+                rest = remaining;
+            }
+
+            assert_eq!(expected[0], crate::hash_vectored(&segments));
+            assert_eq!(
+                expected[15],
+                u64::from(crate::hash_vectored::<u32>(&segments))
+            );
+            let hash: Fingerprint64 = crate::hash_vectored_iter(segments.iter().copied());
+            assert_eq!(expected[0], hash.get());
+        }
+    }
+
+    assert_eq!(TESTDATA[0][0], crate::hash_vectored(&[]));
+}
+
+#[test]
+fn hash_value_matches_encoding() {
+    use core::hash::{Hash, Hasher};
+
+    /// Writes `data` in pieces of `piece` bytes.
+    struct Pieces<'a> {
+        data: &'a [u8],
+        piece: usize,
+    }
+
+    impl Hash for Pieces<'_> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            for piece in self.data.chunks(self.piece) {
+                state.write(piece);
+            }
+        }
+    }
+
+    let data = setup();
+    for (i, expected) in TESTDATA.iter().enumerate().take(TEST_COUNT - 1) {
+        let input = &data[i * i..i * i + i];
+        for piece in [1, 3, 64, 65, usize::MAX] {
+            let value = Pieces { data: input, piece };
+            assert_eq!(expected[0], crate::hash_value(&value));
+            assert_eq!(expected[1], crate::hash_value_with_seed(&value, KSEED0));
+        }
+    }
+
+    let value = (
+        1_u8,
+        -2_i16,
+        3_u32,
+        -4_i64,
+        5_u128,
+        6_usize,
+        -7_isize,
+        true,
+        'c',
+        "hello",
+        [8_u8, 9].as_slice(),
+    );
+    let mut bytes = Vec::new();
+    bytes.push(1);
+    bytes.extend_from_slice(&(-2_i16).to_le_bytes());
+    bytes.extend_from_slice(&3_u32.to_le_bytes());
+    bytes.extend_from_slice(&(-4_i64).to_le_bytes());
+    bytes.extend_from_slice(&5_u128.to_le_bytes());
+    bytes.extend_from_slice(&6_u64.to_le_bytes());
+    bytes.extend_from_slice(&(-7_i64).to_le_bytes());
+    bytes.push(1);
+    bytes.extend_from_slice(&u32::from('c').to_le_bytes());
+    bytes.extend_from_slice(b"hello\xff");
+    bytes.extend_from_slice(&2_u64.to_le_bytes());
+    bytes.extend_from_slice(&[8, 9]);
+    let expected: u64 = crate::hash(&bytes);
+    assert_eq!(expected, crate::hash_value(&value));
+    let expected: u64 = crate::hash_with_seed(&bytes, KSEED0);
+    assert_eq!(expected, crate::hash_value_with_seed(&value, KSEED0));
+
+    // Unsized values can be hashed directly.
+    let expected: u64 = crate::hash(b"hello\xff");
+    assert_eq!(expected, crate::hash_value("hello"));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn stable_hash_matches_encoding() {
+    use std::borrow::Cow;
+    use std::boxed::Box;
+    use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+    use std::rc::Rc;
+    use std::string::String;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::{hash_stable, Fingerprint128, Fingerprint64};
+
+    let value = (
+        (1_u8, -2_i16, 3_u32, -4_i64, 5_u128, 6_usize, -7_isize),
+        (true, 'c', "hello", [8_u8, 9].as_slice(), [10_u16, 11], ()),
+    );
+    let mut bytes = Vec::new();
+    bytes.push(1);
+    bytes.extend_from_slice(&(-2_i16).to_le_bytes());
+    bytes.extend_from_slice(&3_u32.to_le_bytes());
+    bytes.extend_from_slice(&(-4_i64).to_le_bytes());
+    bytes.extend_from_slice(&5_u128.to_le_bytes());
+    bytes.extend_from_slice(&6_u64.to_le_bytes());
+    bytes.extend_from_slice(&(-7_i64).to_le_bytes());
+    bytes.push(1);
+    bytes.extend_from_slice(&u32::from('c').to_le_bytes());
+    bytes.extend_from_slice(&5_u64.to_le_bytes());
+    bytes.extend_from_slice(b"hello");
+    bytes.extend_from_slice(&2_u64.to_le_bytes());
+    bytes.extend_from_slice(&[8, 9]);
+    bytes.extend_from_slice(&10_u16.to_le_bytes());
+    bytes.extend_from_slice(&11_u16.to_le_bytes());
+    let expected: u64 = crate::hash(&bytes);
+    assert_eq!(expected, hash_stable(&value));
+    let expected: u64 = crate::hash_with_seed(&bytes, KSEED0);
+    assert_eq!(expected, crate::hash_stable_with_seed(&value, KSEED0));
+
+    // Enums are encoded as their variant index followed by their fields.
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&0_u32.to_le_bytes());
+    bytes.extend_from_slice(&1_u32.to_le_bytes());
+    bytes.extend_from_slice(&2_u16.to_le_bytes());
+    bytes.extend_from_slice(&1_u32.to_le_bytes());
+    bytes.extend_from_slice(&3_u64.to_le_bytes());
+    bytes.extend_from_slice(&4_u32.to_le_bytes());
+    let value: (Option<u16>, Option<u16>, Result<u8, Duration>) =
+        (None, Some(2), Err(Duration::new(3, 4)));
+    let expected: u64 = crate::hash(&bytes);
+    assert_eq!(expected, hash_stable(&value));
+
+    // Floats are canonicalized.
+    let bits = 0x3ff0_0000_0000_0000_u64;
+    assert_eq!(hash_stable(&1.0_f64), hash_stable(&bits));
+    assert_eq!(hash_stable(&-0.0_f64), hash_stable(&0_u64));
+    assert_eq!(
+        hash_stable(&f64::from_bits(0xfff0_0000_dead_beef)),
+        hash_stable(&0x7ff8_0000_0000_0000_u64)
+    );
+    assert_eq!(hash_stable(&-f32::NAN), hash_stable(&0x7fc0_0000_u32));
+    assert_eq!(hash_stable(&-0.0_f32), hash_stable(&0_u32));
+
+    // Containers are encoded as the values they contain.
+    let data = setup();
+    let expected = hash_stable(&data[..1000]);
+    assert_eq!(expected, hash_stable(&data[..1000].to_vec()));
+    assert_eq!(expected, hash_stable(&Box::<[u8]>::from(&data[..1000])));
+    assert_eq!(expected, hash_stable(&Rc::<[u8]>::from(&data[..1000])));
+    assert_eq!(expected, hash_stable(&Arc::<[u8]>::from(&data[..1000])));
+    assert_eq!(expected, hash_stable(&Cow::Borrowed(&data[..1000])));
+    let mut deque: VecDeque<u8> = data[500..1000].iter().copied().collect();
+    for &byte in data[..500].iter().rev() {
+        deque.push_front(byte);
+    }
+    assert_eq!(expected, hash_stable(&deque));
+    let words: Vec<u64> = (0..100).collect();
+    assert_eq!(
+        hash_stable(&words),
+        hash_stable(&VecDeque::from(words.clone()))
+    );
+    assert_eq!(hash_stable("hello"), hash_stable(&String::from("hello")));
+    assert_eq!(hash_stable(&Fingerprint64::new(1)), hash_stable(&1_u64));
+    assert_eq!(hash_stable(&Fingerprint128::new(1)), hash_stable(&1_u128));
+
+    // Sorted collections are encoded in order, and hashed collections are
+    // encoded independently of their order.
+    let map: BTreeMap<u32, &str> = [(2, "b"), (1, "a")].into_iter().collect();
+    assert_eq!(
+        hash_stable(&map),
+        hash_stable(&(2_u64, 1_u32, "a", 2_u32, "b"))
+    );
+    let mut forward = HashMap::new();
+    let mut reverse = HashMap::with_capacity(1000);
+    for i in 0..100_u32 {
+        forward.insert(i, i * 2);
+        reverse.insert(99 - i, (99 - i) * 2);
+    }
+    let entries: crate::combine::Unordered =
+        forward.iter().map(|entry| hash_stable(&entry)).collect();
+    assert_eq!(hash_stable(&forward), hash_stable(&entries.finish()));
+    assert_eq!(hash_stable(&forward), hash_stable(&reverse));
+    let forward: HashSet<u32> = forward.into_keys().collect();
+    let reverse: HashSet<u32> = reverse.into_keys().collect();
+    assert_eq!(hash_stable(&forward), hash_stable(&reverse));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn stable_hash_nested_collections() {
+    use std::cell::Cell;
+    use std::collections::HashMap;
+
+    use crate::combine::Unordered;
+    use crate::{hash_stable, StableHash, StableHasher};
+
+    /// Writes 100 bytes, counting how many times it is encoded.
+    struct Counted<'a>(&'a Cell<usize>);
+
+    impl StableHash for Counted<'_> {
+        fn stable_hash<H: StableHasher>(&self, state: &mut H) {
+            self.0.set(self.0.get() + 1);
+            state.write_bytes(&[0; 100]);
+        }
+    }
+
+    // Each entry of a hashed collection is encoded once, however deeply the
+    // collections are nested.
+    let count = Cell::new(0);
+    let inner: HashMap<u8, Counted<'_>> = [(1, Counted(&count))].into_iter().collect();
+    let middle: HashMap<u8, _> = [(2, inner)].into_iter().collect();
+    let outer: HashMap<u8, _> = [(3, middle)].into_iter().collect();
+    let hash = hash_stable(&outer);
+    assert_eq!(count.get(), 1);
+
+    let fingerprint = |entry: u64| core::iter::once(entry).collect::<Unordered>().finish();
+    let entry = hash_stable(&(1_u8, Counted(&count)));
+    let entry = hash_stable(&(2_u8, fingerprint(entry)));
+    let entry = hash_stable(&(3_u8, fingerprint(entry)));
+    assert_eq!(hash, hash_stable(&fingerprint(entry)));
+}
+
+#[test]
+#[cfg(all(feature = "hashbrown", feature = "std"))]
+fn stable_hash_hashbrown() {
+    use crate::hash_stable;
+    use crate::hashbrown::{HashMap, HashSet};
+
+    let map: std::collections::HashMap<u32, u32> = (0..100).map(|i| (i, i * 2)).collect();
+    let hashbrown_map: HashMap<u32, u32> = map.clone().into_iter().collect();
+    assert_eq!(hash_stable(&map), hash_stable(&hashbrown_map));
+
+    let set: std::collections::HashSet<u32> = map.into_keys().collect();
+    let hashbrown_set: HashSet<u32> = set.iter().copied().collect();
+    assert_eq!(hash_stable(&set), hash_stable(&hashbrown_set));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn stable_hash_golden() {
+    use std::collections::BTreeMap;
+    use std::string::String;
+    use std::time::Duration;
+
+    use crate::hash_stable;
+
+    // These values must never change, as fingerprints produced by
+    // `hash_stable` may be persisted and compared across platforms.
+    let data = setup();
+    let map: BTreeMap<String, Vec<u16>> = [
+        (String::from("one"), vec![1]),
+        (String::from("two"), vec![1, 2]),
+    ]
+    .into_iter()
+    .collect();
+    let hash_map: std::collections::HashMap<&str, u32> =
+        [("one", 1), ("two", 2)].into_iter().collect();
+    let golden = [
+        (hash_stable(&()), 0x9ae1_6a3b_2f90_404f),
+        (hash_stable(&0_u8), 0xbe60_56ed_f5e9_4b54),
+        (hash_stable(&-1_i32), 0x2f8f_c2fd_6291_b6a4),
+        (hash_stable(&u64::MAX), 0x2fff_e7a4_1fb5_9155),
+        (hash_stable(&usize::MAX), 0x2fff_e7a4_1fb5_9155),
+        (hash_stable(&1_u128), 0x89b7_9444_ffef_f003),
+        (hash_stable(&true), 0x47a2_4c13_b17e_583e),
+        (hash_stable(&'\u{1f980}'), 0xbc22_d9db_dd0a_0f45),
+        (hash_stable(&1.5_f32), 0x48bd_e0df_4838_b540),
+        (hash_stable(&f64::NAN), 0x48e8_775e_0846_be91),
+        (hash_stable(""), 0x1439_7a23_417a_f284),
+        (hash_stable("hello, world"), 0x5568_4a45_5b12_de9b),
+        (hash_stable(&[1_u32, 2, 3]), 0xd8a2_4d66_546c_f3c5),
+        (hash_stable(&[1_u32, 2, 3][..]), 0x3336_fd9d_57da_0367),
+        (hash_stable(&Some("value")), 0xf00a_2ffd_c58d_5dc4),
+        (hash_stable(&Ok::<u8, u8>(1)), 0x23b0_3ad0_11e9_5100),
+        (
+            hash_stable(&Duration::from_millis(1500)),
+            0xa9ee_0160_c50c_5b01,
+        ),
+        (hash_stable(&map), 0x6bc2_2d77_fe39_0d47),
+        (hash_stable(&hash_map), 0xeb8e_5318_5e45_8368),
+        (hash_stable(&data[..]), 0xbd46_4ad2_ed9e_9d98),
+        (
+            crate::hash_stable_with_seed("hello, world", KSEED0),
+            0xda3a_c885_9e36_b1db,
+        ),
+    ];
+    for (i, (hash, expected)) in golden.iter().enumerate() {
+        assert_eq!(hash, expected, "golden value {i} changed");
+    }
+}
+
+#[test]
+#[cfg(all(feature = "macros", feature = "std"))]
+fn stable_hash_derive() {
+    use core::marker::PhantomData;
+    use std::string::String;
+
+    use crate::{hash_stable, StableHash};
+
+    #[derive(StableHash)]
+    struct Named {
+        #[allow(dead_code)]
+        pub id: u32,
+        pub(crate) name: String,
+        tags: Vec<(String, Option<u8>)>,
+    }
+
+    #[derive(StableHash)]
+    struct Tuple(u8, pub i64);
+
+    #[derive(StableHash)]
+    struct Unit;
+
+    #[derive(StableHash)]
+    struct Generic<'a, T, U: Clone = u8, const N: usize = 2>
+    where
+        T: ?Sized,
+    {
+        borrowed: &'a T,
+        array: [U; N],
+        callback: PhantomData<fn(U) -> Option<U>>,
+    }
+
+    #[derive(StableHash)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Rectangle { width: f64, height: f64 },
+        Polygon(Vec<(i32, i32)>),
+    }
+
+    #[derive(StableHash)]
+    #[repr(u8)]
+    enum Level {
+        Low = 1 << 4,
+        High = 1,
+    }
+
+    #[derive(StableHash)]
+    #[allow(dead_code)]
+    enum Never {}
+
+    let named = Named {
+        id: 1,
+        name: String::from("name"),
+        tags: vec![(String::from("tag"), Some(2))],
+    };
+    let expected = hash_stable(&(1_u32, "name", [("tag", Some(2_u8))].as_slice()));
+    assert_eq!(expected, hash_stable(&named));
+    assert_eq!(hash_stable(&(1_u8, -2_i64)), hash_stable(&Tuple(1, -2)));
+    assert_eq!(hash_stable(&()), hash_stable(&Unit));
+
+    let generic = Generic {
+        borrowed: "hello",
+        array: [1_u8, 2],
+        callback: PhantomData,
+    };
+    assert_eq!(hash_stable(&("hello", [1_u8, 2])), hash_stable(&generic));
+
+    let shapes = [
+        Shape::Empty,
+        Shape::Circle(1.0),
+        Shape::Rectangle {
+            width: 2.0,
+            height: -0.0,
+        },
+        Shape::Polygon(vec![(0, 0), (1, 1), (0, 1)]),
+    ];
+    assert_eq!(hash_stable(&0_u32), hash_stable(&shapes[0]));
+    assert_eq!(hash_stable(&(1_u32, 1.0_f64)), hash_stable(&shapes[1]));
+    assert_eq!(
+        hash_stable(&(2_u32, 2.0_f64, 0.0_f64)),
+        hash_stable(&shapes[2])
+    );
+    assert_eq!(
+        hash_stable(&(3_u32, [(0_i32, 0_i32), (1, 1), (0, 1)].as_slice())),
+        hash_stable(&shapes[3])
+    );
+    // The variant index is encoded rather than the discriminant.
+    assert_eq!(hash_stable(&0_u32), hash_stable(&Level::Low));
+    assert_eq!(hash_stable(&1_u32), hash_stable(&Level::High));
+
+    // These values must never change.
+    let golden = [
+        (hash_stable(&named), 0x49fd_d2db_ecfe_7b61),
+        (hash_stable(&Tuple(1, -2)), 0x1a3f_f037_b889_23de),
+        (hash_stable(&generic), 0xa2c0_a322_42c0_0c8e),
+        (hash_stable(&shapes[..]), 0x953a_fff7_b663_7a9f),
+    ];
+    for (i, (hash, expected)) in golden.iter().enumerate() {
+        assert_eq!(hash, expected, "golden value {i} changed");
+    }
+}
+
+/// A buffer that returns its contents one byte at a time, and does not
+/// implement [`bytes::Buf::chunks_vectored`].
+#[cfg(feature = "bytes")]
+struct Bytewise<'a>(&'a [u8]);
+
+#[cfg(feature = "bytes")]
+impl bytes::Buf for Bytewise<'_> {
+    fn remaining(&self) -> usize {
+        self.0.len()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        &self.0[..self.0.len().min(1)]
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        self.0 = &self.0[cnt..];
+    }
+}
+
+#[test]
+#[cfg(feature = "bytes")]
+fn hash_buf_matches_hash() {
+    use std::boxed::Box;
+    use std::collections::VecDeque;
+
+    use bytes::{Buf, Bytes, BytesMut};
+
+    use crate::hash_buf;
+
+    let data = Bytes::from(setup());
+    for i in [0, 1, 16, 24, 25, 64, 65, 128, 298] {
+        let expected = &TESTDATA[i];
+        let input = data.slice(i * i..i * i + i);
+
+        assert_eq!(expected[0], hash_buf(input.clone()));
+        assert_eq!(expected[0], hash_buf(BytesMut::from(&input[..])));
+        let hash: u32 = hash_buf(&input[..]);
+        assert_eq!(expected[15], u64::from(hash));
+
+        let (first, second) = input.split_at(i / 3);
+        let chained = first.chain(second).chain(Bytes::new());
+        assert_eq!(expected[0], hash_buf(chained));
+        // Rotating the deque wraps its contents around the end of its buffer.
+        let mut deque = VecDeque::from(input.to_vec());
+        deque.rotate_left(i / 2);
+        deque.rotate_right(i / 2);
+        let hash: u32 = hash_buf(deque);
+        assert_eq!(expected[15], u64::from(hash));
+
+        // More chunks than fit in the initial slices gathered from the buffer.
+        let mut chunks: Box<dyn Buf> = Box::new(Bytes::new());
+        for byte in input.chunks(1) {
+            chunks = Box::new(chunks.chain(byte));
+        }
+        assert_eq!(expected[0], hash_buf(chunks));
+
+        // A buffer that only exposes its first chunk, which is copied.
+        assert_eq!(expected[0], hash_buf(Bytewise(&input[..])));
+    }
+
+    let mut buf = data.clone().chain(&b"trailing"[..]);
+    buf.advance(1);
+    let flattened = [&data[1..], b"trailing"].concat();
+    let hash: u64 = hash_buf(buf.take(flattened.len() - 4));
+    assert_eq!(hash, crate::hash(&flattened[..flattened.len() - 4]));
+}
+
+/// A writer that accepts at most `limit` bytes per write.
+#[cfg(feature = "std")]
+struct ShortWrites {
+    written: Vec<u8>,
+    limit: usize,
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for ShortWrites {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(self.limit);
+        self.written.extend_from_slice(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn io_adapters_match_hash() {
+    use std::io::{self, Write};
+
+    use crate::{CityHashReader, CityHashWriter};
+
+    let data = setup();
+    for (len, limit) in [
+        (0, 1),
+        (5, 2),
+        (63, 7),
+        (143, 50),
+        (144, 1),
+        (200, 64),
+        (400, 128),
+        (DATA_SIZE, 4000),
+        (DATA_SIZE - 1, 1 << 16),
+    ] {
+        let input = &data[..len];
+        let expected: u128 = crate::hash(input);
+
+        let mut reader = CityHashReader::with_len(input, len as u64);
+        let mut writer = CityHashWriter::with_len(
+            ShortWrites {
+                written: Vec::new(),
+                limit,
+            },
+            len as u64,
+        );
+        assert_eq!(
+            writer.finish128(),
+            if len == 0 { Some(expected) } else { None }
+        );
+        io::copy(&mut reader, &mut writer).unwrap();
+
+        assert_eq!(reader.finish128(), Some(expected), "len {len}");
+        assert_eq!(reader.finish64(), None);
+        assert_eq!(reader.remaining(), Some(0));
+        assert_eq!(writer.finish128(), Some(expected), "len {len}");
+        assert!(writer.write(&[0]).is_err());
+        assert_eq!(writer.into_inner().written, input);
+
+        // Without a length, every byte seen is hashed.
+        let mut reader = CityHashReader::new(input);
+        let mut writer = CityHashWriter::new(ShortWrites {
+            written: Vec::new(),
+            limit,
+        });
+        io::copy(&mut reader, &mut writer).unwrap();
+
+        let expected64: u64 = crate::hash(input);
+        assert_eq!(reader.finish64(), Some(expected64), "len {len}");
+        assert_eq!(reader.finish128(), Some(expected), "len {len}");
+        assert_eq!(reader.remaining(), None);
+        assert_eq!(writer.finish64(), Some(expected64), "len {len}");
+        assert_eq!(writer.finish128(), Some(expected), "len {len}");
+        assert_eq!(writer.into_inner().written, input);
+    }
+
+    // An inner reader that is longer than the length given is an error, rather
+    // than a silently truncated copy.
+    let mut reader = CityHashReader::with_len(&data[..200], 150);
+    let err = io::copy(&mut reader, &mut io::sink()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn hash_file_matches_hash() {
+    let data = setup_tree(3 * DATA_SIZE + 5);
+    let path = std::env::temp_dir().join(std::format!("cityhasher-test-{}", std::process::id()));
+    for len in [0, 100, 65_536, 65_601, 70_000, 3 * DATA_SIZE + 5] {
+        let input = &data[..len];
+        std::fs::write(&path, input).unwrap();
+
+        let file: u32 = crate::fs::hash_file(&path).unwrap();
+        let expected: u32 = crate::hash(input);
+        assert_eq!(file, expected, "len {len}");
+
+        let mut progress = Vec::new();
+        let file: u64 =
+            crate::fs::hash_file_with_progress(&path, |hashed| progress.push(hashed)).unwrap();
+        let expected: u64 = crate::hash(input);
+        assert_eq!(file, expected, "len {len}");
+        assert_eq!(progress.last(), Some(&(len as u64)));
+        assert!(progress.windows(2).all(|pair| pair[0] < pair[1]));
+        if len > DATA_SIZE {
+            assert!(progress.len() > 1);
+        }
+
+        let file: crate::Fingerprint64 = crate::fs::hash_file(&path).unwrap();
+        assert_eq!(u64::from(file), expected, "len {len}");
+
+        let mut progress = Vec::new();
+        let file: u128 =
+            crate::fs::hash_file_with_progress(&path, |hashed| progress.push(hashed)).unwrap();
+        let expected: u128 = crate::hash(input);
+        assert_eq!(file, expected, "len {len}");
+        assert_eq!(progress.last(), Some(&(len as u64)));
+        assert!(progress.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn batch_matches_hash() {
+    let data = setup();
+    let mut inputs = (0..TEST_COUNT - 1)
+        .map(|i| &data[i * i..i * i + i])
+        .chain(Some(&data[..]))
+        .collect::<Vec<_>>();
+
+    let mut hashes = vec![0_u64; TEST_COUNT];
+    crate::hash_batch(&inputs, &mut hashes);
+    for (expected, hash) in TESTDATA.iter().zip(&hashes) {
+        assert_eq!(expected[0], *hash);
+    }
+
+    let mut hashes = vec![0_u32; TEST_COUNT];
+    crate::hash_batch(&inputs, &mut hashes);
+    for (expected, hash) in TESTDATA.iter().zip(&hashes) {
+        assert_eq!(expected[15], u64::from(*hash));
+    }
+
+    let mut hashes = vec![0_u128; TEST_COUNT];
+    crate::hash_batch(&inputs, &mut hashes);
+    for (expected, hash) in TESTDATA.iter().zip(&hashes) {
+        assert_eq!(expected[3], *hash as u64);
+        assert_eq!(expected[4], (*hash >> 64) as u64);
+    }
+
+    // Sorting by length produces runs of inputs within the same length range,
+    // exercising the grouped implementations.
+    inputs.sort_by_key(|input| input.len());
+    let mut hashes = vec![0_u64; TEST_COUNT];
+    crate::hash_batch(&inputs, &mut hashes);
+    for (input, hash) in inputs.iter().zip(&hashes) {
+        let expected: u64 = crate::hash(input);
+        assert_eq!(expected, *hash);
+    }
+}
+
+#[test]
+fn fixed_stride_matches_hash() {
+    let data = setup();
+    // Odd record counts exercise both full groups and the remainder.
+    for count in [1, 4, 9] {
+        for stride in 1..=200 {
+            let data = &data[..stride * count];
+            let mut hashes = vec![0_u64; count];
+            crate::hash_fixed_stride(data, stride, &mut hashes);
+            let mut hashes32 = vec![0_u32; count];
+            crate::hash_fixed_stride(data, stride, &mut hashes32);
+            for ((record, hash), hash32) in data.chunks_exact(stride).zip(&hashes).zip(&hashes32) {
+                let expected: u64 = crate::hash(record);
+                assert_eq!(expected, *hash, "stride {stride}");
+                let expected: u32 = crate::hash(record);
+                assert_eq!(expected, *hash32, "stride {stride}");
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic = "data must contain exactly out.len() records of stride bytes"]
+fn fixed_stride_partial_record() {
+    crate::hash_fixed_stride(&[0; 15], 8, &mut [0_u64; 2]);
+}
+
+#[test]
+#[cfg(all(feature = "intrinsics", feature = "std", target_arch = "x86_64"))]
+fn crc32_software_matches_sse42() {
+    use crate::crc::{Crc32, Sse42};
+
+    if !crate::crc::sse42_available() {
+        return;
+    }
+
+    let data = setup();
+    let mut software = 0;
+    let mut hardware = 0;
+    for chunk in data.chunks_exact(8) {
+        let value = u64::from_le_bytes(chunk.try_into().unwrap());
+        software = Software::crc32_u64(software, value);
+        hardware = Sse42::crc32_u64(hardware, value);
+        assert_eq!(software, hardware);
+    }
+}
+
+/// Verifies that `V` hashes every supported length identically to the scalar
+/// implementation.
+fn check_lanes<V: crate::lanes::U64x4>() {
+    use crate::lanes::{hash64_lanes, LanesClass, LANES, MAX_LEN};
+
+    let data = setup();
+    for len in 0..=MAX_LEN {
+        let class = LanesClass::of(len).unwrap();
+        for group in data.chunks_exact(len.max(1) * LANES).take(64) {
+            let inputs = [0, 1, 2, 3].map(|lane| &group[lane * len..(lane + 1) * len]);
+            let hashes = hash64_lanes::<V>(&inputs, class);
+            for (input, hash) in inputs.iter().zip(hashes) {
+                let expected: u64 = crate::hash(input);
+                assert_eq!(expected, hash, "len {len}");
+            }
+        }
+
+        // Inputs of different lengths in the same class.
+        let lens = [0, 1, 2, 3].map(|lane| {
+            (len.saturating_sub(lane)..=len)
+                .rev()
+                .find(|&other| LanesClass::of(other) == Some(class))
+                .unwrap()
+        });
+        let inputs = [0, 1, 2, 3].map(|lane| &data[lane * 64..lane * 64 + lens[lane]]);
+        let hashes = hash64_lanes::<V>(&inputs, class);
+        for (input, hash) in inputs.iter().zip(hashes) {
+            let expected: u64 = crate::hash(input);
+            assert_eq!(expected, hash, "len {}", input.len());
+        }
+    }
+}
+
+#[test]
+fn lanes_portable_matches_scalar() {
+    check_lanes::<crate::lanes::Portable>();
+}
+
+#[test]
+#[cfg(all(feature = "intrinsics", feature = "std", target_arch = "x86_64"))]
+fn lanes_avx2_matches_scalar() {
+    if !crate::lanes::avx2_available() {
+        return;
+    }
+
+    check_lanes::<crate::lanes::Avx2>();
+}
+
+#[test]
+#[cfg(all(
+    feature = "intrinsics",
+    target_arch = "aarch64",
+    target_feature = "neon"
+))]
+fn lanes_neon_matches_scalar() {
+    check_lanes::<crate::lanes::Neon>();
+}
+
+/// Returns `len` bytes of test data, which is long enough to contain multiple
+/// tree-hashing leaves.
+fn setup_tree(len: usize) -> Vec<u8> {
+    setup()
+        .into_iter()
+        .cycle()
+        .enumerate()
+        .map(|(i, b)| b ^ (i >> 20) as u8)
+        .take(len)
+        .collect()
+}
+
+#[test]
+fn tree_matches_definition() {
+    use crate::tree::{ALGORITHM_ID, LEAF_SIZE};
+
+    let tag: u64 = crate::hash(ALGORITHM_ID);
+    for len in [0, 1, 1000, LEAF_SIZE, LEAF_SIZE + 1, 3 * LEAF_SIZE + 7] {
+        let data = setup_tree(len);
+        let mut leaves = data.chunks(LEAF_SIZE).collect::<Vec<_>>();
+        if leaves.is_empty() {
+            leaves.push(&[]);
+        }
+
+        let mut state = u128::from(tag) << 64 | len as u128;
+        for leaf in leaves {
+            let digest: u128 = crate::hash(leaf);
+            let (low, high) = crate::u128_to_halves(digest);
+            let encoded = [low.to_le_bytes(), high.to_le_bytes()].concat();
+            state = crate::hash_with_seed(encoded, state);
+        }
+        let (low, high) = crate::u128_to_halves(state);
+
+        let tree128: u128 = crate::tree::hash(&data);
+        let tree64: u64 = crate::tree::hash(&data);
+        assert_eq!(tree128, state, "len {len}");
+        assert_eq!(tree64, crate::city::hash_len_16_u64(low, high), "len {len}");
+    }
+}
+
+/// The tree hash of `"hello"`, which must only change along with
+/// `tree::ALGORITHM_ID`.
+const TREE_HELLO: u64 = 0xf1946a8b30f3813e;
+
+#[test]
+fn tree_differs_from_reference() {
+    let tree: u64 = crate::tree::hash("hello");
+    assert_eq!(tree, TREE_HELLO);
+    let reference: u64 = crate::hash("hello");
+    assert_ne!(tree, reference);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn tree_parallel_matches_sequential() {
+    use crate::tree::LEAF_SIZE;
+
+    for len in [0, LEAF_SIZE, LEAF_SIZE + 1, 5 * LEAF_SIZE + 3] {
+        let data = setup_tree(len);
+        let expected: u128 = crate::tree::hash(&data);
+        for threads in [1, 2, 3, 8] {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let parallel: u128 = pool.install(|| crate::tree::par_hash(&data));
+            assert_eq!(parallel, expected, "len {len}, {threads} threads");
+        }
+    }
 }
 
 static TESTDATA: [[u64; 16]; TEST_COUNT] = [
@@ -5446,3 +6785,2708 @@ static TESTDATA: [[u64; 16]; TEST_COUNT] = [
         0x5398210c,
     ],
 ];
+
+/// Test vectors from the CityHash v1.0.2 reference release, which only tests
+/// 64-bit and 128-bit outputs.
+static TESTDATA_V1_0_2: [[u64; 7]; TEST_COUNT] = [
+    [
+        0x9ae16a3b2f90404f,
+        0x75106db890237a4a,
+        0x3feac5f636039766,
+        0x3df09dfc64c09a2b,
+        0x3cb540c392e51e29,
+        0x6b56343feac0663,
+        0x5b7bc50fd8e8ad92,
+    ],
+    [
+        0x75e9dee28ded761d,
+        0x931992c1b14334c5,
+        0x245eeb25ba2c172e,
+        0x1290f0e8a5caa74d,
+        0xca4c6bf7583f5cda,
+        0xe1d60d51632c536d,
+        0xcbc54a1db641910a,
+    ],
+    [
+        0x75de892fdc5ba914,
+        0xf89832e71f764c86,
+        0x39a82df1f278a297,
+        0xb4af8ae673acb930,
+        0x992b7acb203d8885,
+        0x57b533f3f8b94d50,
+        0xbbb69298a5dcf1a1,
+    ],
+    [
+        0x69cfe9fca1cc683a,
+        0xe65f2a81e19b8067,
+        0x20575ea6370a9d14,
+        0x8f52532fc6f005b7,
+        0x4ebe60df371ec129,
+        0xc6ef8a7f8deb8116,
+        0x83df17e3c9bb9a67,
+    ],
+    [
+        0x675b04c582a34966,
+        0x53624b5ef8cd4f45,
+        0xc412e0931ac8c9b1,
+        0x798637e677c65a3,
+        0x83e3b06adc4cd3ff,
+        0xf3e76e8a7135852f,
+        0x111e66cfbb05366d,
+    ],
+    [
+        0x46fa817397ea8b68,
+        0xcc960c1c15ce2d20,
+        0xe5f9f947bafb9e79,
+        0xb342cdf0d7ac4b2a,
+        0x66914d44b373b232,
+        0x261194e76cb43966,
+        0x45a0010190365048,
+    ],
+    [
+        0x406e959cdffadec7,
+        0xe80dc125dca28ed1,
+        0xe5beb146d4b79a21,
+        0xe66d5c1bb441541a,
+        0xd14961bc1fd265a2,
+        0xe4cc669d4fc0577f,
+        0xabf4a51e36da2702,
+    ],
+    [
+        0x46663908b4169b95,
+        0x4e7e90b5c426bf1d,
+        0xdc660b58daaf8b2c,
+        0xb298265ebd1bd55f,
+        0x4a5f6838b55c0b08,
+        0xfc003c97aa05d397,
+        0x2fb5adad3380c3bc,
+    ],
+    [
+        0xf214b86cffeab596,
+        0x5fccb0b132da564f,
+        0x86e7aa8b4154b883,
+        0x763529c8d4189ea8,
+        0x860d77e7fef74ca3,
+        0x3b1ba41191219b6b,
+        0x722b25dfa6d0a04b,
+    ],
+    [
+        0xeba670441d1a4f7d,
+        0xeb6b272502d975fa,
+        0x69f8d424d50c083e,
+        0x313d49cb51b8cd2c,
+        0x6e982d8b4658654a,
+        0xdd59629a17e5492d,
+        0x81cb23bdab95e30e,
+    ],
+    [
+        0x172c17ff21dbf88d,
+        0x1f5104e320f0c815,
+        0x1e34e9f1fa63bcef,
+        0x3506ae8fae368d2a,
+        0x59fa2b2de5306203,
+        0x67d1119dcfa6007e,
+        0x1f7190c648ad9aef,
+    ],
+    [
+        0x5a0838df8a019b8c,
+        0x73fc859b4952923,
+        0x45e39daf153491bd,
+        0xa9b91459a5fada46,
+        0xde0fbf8800a2da3,
+        0x21800e4b5af9dedb,
+        0x517c3726ae0dbae7,
+    ],
+    [
+        0x8f42b1fbb2fc0302,
+        0x5ae31626076ab6ca,
+        0xb87f0cb67cb75d28,
+        0x2498586ac2e1fab2,
+        0xe683f9cbea22809a,
+        0xa9728d0b2bbe377c,
+        0x46baf5cae53dc39a,
+    ],
+    [
+        0x72085e82d70dcea9,
+        0x32f502c43349ba16,
+        0x5ebc98c3645a018f,
+        0xc7fa762238fd90ac,
+        0x8d03b5652d615677,
+        0xa3f5226e51d42217,
+        0x46d5010a7cae8c1e,
+    ],
+    [
+        0x32b75fc2223b5032,
+        0x246fff80eb230868,
+        0xa6fdbc82c9aeecc0,
+        0xc089498074167021,
+        0xab094a9f9ab81c23,
+        0x4facf3d9466bcb03,
+        0x57aa9c67938cf3eb,
+    ],
+    [
+        0xe1dd010487d2d647,
+        0x12352858295d2167,
+        0xacc5e9b6f6b02dbb,
+        0x1c66ceea473413df,
+        0xdc3f70a124b25a40,
+        0x66a6dfe54c441cd8,
+        0xb436dabdaaa37121,
+    ],
+    [
+        0x2994f9245194a7e2,
+        0xb7cd7249d6db6c0c,
+        0x2170a7d119c5c6c3,
+        0x8505c996b70ee9fc,
+        0xb92bba6b5d778eb7,
+        0x4db4c57f3a7a4aee,
+        0x3cfd441cb222d06f,
+    ],
+    [
+        0x32e2ed6fa03e5b22,
+        0x58baf09d7c71c62b,
+        0xa9c599f3f8f50b5b,
+        0x1660a2c4972d0fa1,
+        0x1a1538d6b50a57c,
+        0x8a5362485bbc9363,
+        0xe8eec3c84fd9f2f8,
+    ],
+    [
+        0x37a72b6e89410c9f,
+        0x139fec53b78cee23,
+        0x4fccd8f0da7575c3,
+        0x3a5f04166518ac75,
+        0xf49afe05a44fc090,
+        0xcb01b4713cfda4bd,
+        0x9027bd37ffc0a5de,
+    ],
+    [
+        0x10836563cb8ff3a1,
+        0xd36f67e2dfc085f7,
+        0xedc1bb6a3dcba8df,
+        0xbd4f3a0566df3bed,
+        0x81fc8230c163dcbe,
+        0x4168bc8417a8281b,
+        0x7100c9459827c6a6,
+    ],
+    [
+        0x4dabcb5c1d382e5c,
+        0x9a868c608088b7a4,
+        0x7b2b6c389b943be5,
+        0xc914b925ab69fda0,
+        0x6bafe864647c94d7,
+        0x7a48682dd4afa22,
+        0x40fe01210176ba10,
+    ],
+    [
+        0x296afb509046d945,
+        0xc38fe9eb796bd4be,
+        0xd7b17535df110279,
+        0xdd2482b87d1ade07,
+        0x662785d2e3e78ddf,
+        0xeae39994375181bb,
+        0x9994500c077ee1db,
+    ],
+    [
+        0xf7c0257efde772ea,
+        0xaf6af9977ecf7bff,
+        0x1cdff4bd07e8d973,
+        0xfab1f4acd2cd4ab4,
+        0xb4e19ba52b566bd,
+        0x7f1db45725fe2881,
+        0x70276ff8763f8396,
+    ],
+    [
+        0x61e021c8da344ba1,
+        0xcf9c720676244755,
+        0x354ffa8e9d3601f6,
+        0x44e40a03093fbd92,
+        0xbda9481cc5b93cae,
+        0x986b589cbc0cf617,
+        0x210f59f074044831,
+    ],
+    [
+        0xc0a86ed83908560b,
+        0x440c8b6f97bd1749,
+        0xa99bf2891726ea93,
+        0xac0c0b84df66df9d,
+        0x3ee2337b437eb264,
+        0x8a341daed9a25f98,
+        0xcc665499aa38c78c,
+    ],
+    [
+        0x35c9cf87e4accbf3,
+        0x2267eb4d2191b2a3,
+        0x80217695666b2c9,
+        0xcd43a24abbaae6d,
+        0xa88abf0ea1b2a8ff,
+        0xe297ff01427e2a9d,
+        0x935d545695b2b41d,
+    ],
+    [
+        0xe74c366b3091e275,
+        0x522e657c5da94b06,
+        0xca9afa806f1a54ac,
+        0xb545042f67929471,
+        0x90d10e75ed0e75d8,
+        0x3ea60f8f158df77e,
+        0x8863eff3c2d670b7,
+    ],
+    [
+        0xa3f2ca45089ad1a6,
+        0x13f6270fe56fbce4,
+        0x1f93a534bf03e705,
+        0xaaea14288ae2d90c,
+        0x1be3cd51ef0f15e8,
+        0xe8b47c84d5a4aac1,
+        0x297d27d55b766782,
+    ],
+    [
+        0xe5181466d8e60e26,
+        0xcf31f3a2d582c4f3,
+        0xd9cee87cb71f75b2,
+        0x4750ca6050a2d726,
+        0xd6e6dd8940256849,
+        0xf3b3749fdab75b0,
+        0xc55d8a0f85ba0ccf,
+    ],
+    [
+        0xfb528a8dd1e48ad7,
+        0x98c4fd149c8a63dd,
+        0x4abd8fc3377ae1f,
+        0xd7a9304abbb47cc5,
+        0x7f2b9a27aa57f99,
+        0x353ab332d4ef9f18,
+        0x47d56b8d6c8cf578,
+    ],
+    [
+        0xda6d2b7ea9d5f9b6,
+        0x57b11153ee3b4cc8,
+        0x7d3bd1256037142f,
+        0x90b16ff331b719b5,
+        0xfc294e7ad39e01e6,
+        0xd2145386bab41623,
+        0x7045a63d44d76011,
+    ],
+    [
+        0x61d95225bc2293e,
+        0xf6c52cb6be9889a8,
+        0x91a0667a7ed6a113,
+        0x441133d221486a3d,
+        0xfb9c5a40e19515b,
+        0x6c967b6c69367c2d,
+        0x145bd9ef258c4099,
+    ],
+    [
+        0x81247c01ab6a9cc1,
+        0xfbccea953e810636,
+        0xae18965000c31be0,
+        0x15bb46383daec2a5,
+        0x716294063b4ba089,
+        0xf3bd691ce02c3014,
+        0x14ccaad685a20764,
+    ],
+    [
+        0xc17f3ebd3257cb8b,
+        0xe9e68c939c118c8d,
+        0x72a5572be35bfc1b,
+        0xf6916c341cb31f2a,
+        0x591da1353ee5f31c,
+        0xf1313c98a836b407,
+        0xe0b8473eada48cd1,
+    ],
+    [
+        0x9802438969c3043b,
+        0x6cd07575c948dd82,
+        0x83e26b6830ea8640,
+        0xd52f1fa190576961,
+        0x11d182e4f0d419cc,
+        0x5d9ccf1b56617424,
+        0xc8a16debb585e452,
+    ],
+    [
+        0x3dd8ed248a03d754,
+        0xd8c1fcf001cb62e0,
+        0x87a822141ed64927,
+        0x4bfaf6fd26271f47,
+        0xaefeae8222ad3c77,
+        0xcfb7b24351a60585,
+        0x8678904e9e890b8f,
+    ],
+    [
+        0xc5bf48d7d3e9a5a3,
+        0x8f0249b5c5996341,
+        0xc6d2c8a606f45125,
+        0xfd1779db740e2c48,
+        0x1950ef50fefab3f8,
+        0xe4536426a6196809,
+        0x699556c502a01a6a,
+    ],
+    [
+        0xbc4a21d00cf52288,
+        0x28df3eb5a533fa87,
+        0x6081bbc2a18dd0d,
+        0x8eed355d219e58b9,
+        0x2d7b9f1a3d645165,
+        0x5758d1aa8d85f7b2,
+        0x9c90c65920041dff,
+    ],
+    [
+        0x172c8674913ff413,
+        0x1815a22400e832bf,
+        0x7e011f9467a06650,
+        0x161be43353a31dd0,
+        0x79a8afddb0642ac3,
+        0xdf43af54e3e16709,
+        0x6e12553a75b43f07,
+    ],
+    [
+        0x17a361dbdaaa7294,
+        0xc67d368223a3b83c,
+        0xf49cf8d51ab583d2,
+        0x666eb21e2eaa596,
+        0x778f3e1b6650d56,
+        0x3f6be451a668fe2d,
+        0x5452892b0b101388,
+    ],
+    [
+        0x5cc268bac4bd55f,
+        0x232717a35d5b2f1,
+        0x38da1393365c961d,
+        0x2d187f89c16f7b62,
+        0x4eb504204fa1be8,
+        0x222bd53d2efe5fa,
+        0xa4dcd6d721ddb187,
+    ],
+    [
+        0xdb04969cc06547f1,
+        0xfcacc8a75332f120,
+        0x967ccec4ed0c977e,
+        0xac5d1087e454b6cd,
+        0xc1f8b2e284d28f6c,
+        0xcc3994f4a9312cfa,
+        0x8d61606dbc4e060d,
+    ],
+    [
+        0x25bd8d3ca1b375b2,
+        0x4ad34c2c865816f9,
+        0x9be30ad32f8f28aa,
+        0x7755ea02dbccad6a,
+        0xcb8aaf8886247a4a,
+        0x8f6966ce7ea1b6e6,
+        0x3f2863090fa45a70,
+    ],
+    [
+        0x166c11fbcbc89fd8,
+        0xcce1af56c48a48aa,
+        0x78908959b8ede084,
+        0x19032925ba2c951a,
+        0xa53ed6e81b67943a,
+        0xedc871a9e8ef4bdf,
+        0xae66cf46a8371aba,
+    ],
+    [
+        0x3565bcc4ca4ce807,
+        0xec35bfbe575819d5,
+        0x6a1f690d886e0270,
+        0x1ab8c584625f6a04,
+        0xccfcdafb81b572c4,
+        0x53b04ba39fef5af9,
+        0x64ce81828eefeed4,
+    ],
+    [
+        0xb7897fd2f274307d,
+        0x6d43a9e5dd95616d,
+        0x31a2218e64d8fce0,
+        0x664e581fc1cf769b,
+        0x415110942fc97022,
+        0x7a5d38fee0bfa763,
+        0xdc87ddb4d7495b6c,
+    ],
+    [
+        0xaba98113ab0e4a16,
+        0x287f883aede0274d,
+        0x3ecd2a607193ba3b,
+        0xe131f6cc9e885c28,
+        0xb399f98d827e4958,
+        0x6eb90c8ed6c9090c,
+        0xec89b378612a2b86,
+    ],
+    [
+        0x17f7796e0d4b636c,
+        0xddba5551d716137b,
+        0x65f9735375df1ada,
+        0xa39e946d02e14ec2,
+        0x1c88cc1d3822a193,
+        0x663f8074a5172bb4,
+        0x8ad2934942e4cb9c,
+    ],
+    [
+        0x33c0128e62122440,
+        0xb23a588c8c37ec2b,
+        0xf2608199ca14c26a,
+        0xacab0139dc4f36df,
+        0x9502b1605ca1345a,
+        0x32174ef1e06a5e9c,
+        0xd824b7869258192b,
+    ],
+    [
+        0x988bc5d290b97aef,
+        0x6754bb647eb47666,
+        0x44b5cf8b5b8106a8,
+        0xa1c5ba961937f723,
+        0x32d6bc7214dfcb9b,
+        0x6863397e0f4c6758,
+        0xe644bcb87e3eef70,
+    ],
+    [
+        0x23c8c25c2ab72381,
+        0xd6bc672da4175fba,
+        0x6aef5e6eb4a4eb10,
+        0x3df880c945e68aed,
+        0x5e08a75e956d456f,
+        0xf984f088d1a322d7,
+        0x7d44a1b597b7a05e,
+    ],
+    [
+        0x450fe4acc4ad3749,
+        0x3111b29565e4f852,
+        0xdb570fc2abaf13a9,
+        0x35107d593ba38b22,
+        0xfd8212a125073d88,
+        0x72805d6e015bfacf,
+        0x6b22ae1a29c4b853,
+    ],
+    [
+        0x48e1eff032d90c50,
+        0xdee0fe333d962b62,
+        0xc845776990c96775,
+        0x8ea71758346b71c9,
+        0xd84258cab79431fd,
+        0xaf566b4975cce10a,
+        0x5c5c7e70a91221d2,
+    ],
+    [
+        0xc048604ba8b6c753,
+        0x21ea6d24b417fdb6,
+        0x4e40a127ad2d6834,
+        0x5234231bf173c51,
+        0x62319525583eaf29,
+        0x87632efa9144cc04,
+        0x1749de70c8189067,
+    ],
+    [
+        0x67ff1cbe469ebf84,
+        0x3a828ac9e5040eb0,
+        0x85bf1ad6b363a14b,
+        0x2fc6c0783390d035,
+        0xef78307f5be5524e,
+        0xa46925b7a1a77905,
+        0xfea37470f9a51514,
+    ],
+    [
+        0xb45c7536bd7a5416,
+        0xe2d17c16c4300d3c,
+        0xb70b641138765ff5,
+        0xa5a859ab7d0ddcfc,
+        0x8730164a0b671151,
+        0xaf93810c10348dd0,
+        0x7256010c74f5d573,
+    ],
+    [
+        0x215c2eaacdb48f6f,
+        0x33b09acf1bfa2880,
+        0x78c4e94ba9f28bf,
+        0x981b7219224443d1,
+        0x1f476fc4344d7bba,
+        0xabad36e07283d3a5,
+        0x831bf61190eaaead,
+    ],
+    [
+        0x241baf16d80e0fe8,
+        0xb6b3c5b53a3ce1d,
+        0x6ae6b36209eecd70,
+        0xa560b6a4aa3743a4,
+        0xb3e04f202b7a99b,
+        0x3b3b1573f4c97d9f,
+        0xccad8715a65af186,
+    ],
+    [
+        0xd10a9743b5b1c4d1,
+        0xf16e0e147ff9ccd6,
+        0xfbd20a91b6085ed3,
+        0x43d309eb00b771d5,
+        0xa6d1f26105c0f61b,
+        0xd37ad62406e5c37e,
+        0x75d9b28c717c8cf7,
+    ],
+    [
+        0x919ef9e209f2edd1,
+        0x684c33fb726a720a,
+        0x540353f94e8033,
+        0x26da1a143e7d4ec4,
+        0x55095eae445aacf4,
+        0x31efad866d075938,
+        0xf9b580cff4445f94,
+    ],
+    [
+        0xb5f9519b6c9280b,
+        0x7823a2fe2e103803,
+        0xd379a205a3bd4660,
+        0x466ec55ee4b4302a,
+        0x714f1b9985deeaf0,
+        0x728595f26e633cf7,
+        0x25ecd0738e1bee2b,
+    ],
+    [
+        0x77a75e89679e6757,
+        0x25d31fee616b5dd0,
+        0xd81f2dfd08890060,
+        0x7598df8911dd40a4,
+        0x3b6dda517509b41b,
+        0x7dae29d248dfffae,
+        0x6697c427733135f,
+    ],
+    [
+        0x9d709e1b086aabe2,
+        0x4d6d6a6c543e3fec,
+        0xdf73b01acd416e84,
+        0xd54f613658e35418,
+        0xfcc88fd0567afe77,
+        0xd18f2380980db355,
+        0xec3896137dfbfa8b,
+    ],
+    [
+        0x91c89971b3c20a8a,
+        0x87b82b1d55780b5,
+        0xbc47bb80dfdaefcd,
+        0x87e11c0f44454863,
+        0x2df1aedb5871cc4b,
+        0xba72fd91536382c8,
+        0x52cebef9e6ea865d,
+    ],
+    [
+        0x16468c55a1b3f2b4,
+        0x40b1e8d6c63c9ff4,
+        0x143adc6fee592576,
+        0x4caf4deeda66a6ee,
+        0x264720f6f35f7840,
+        0x71c3aef9e59e4452,
+        0x97886ca1cb073c55,
+    ],
+    [
+        0x8015f298161f861e,
+        0x3b4a12bf2e24a16,
+        0x37b223562c48b473,
+        0xd82489179f16d4e8,
+        0xa3c59f65e2913cc5,
+        0x36cbaecdc3532b3b,
+        0xf1b454616cfeca41,
+    ],
+    [
+        0x71e244d7e2843a41,
+        0x2132bd3f394ac99d,
+        0x1791b7a5b93ad1f9,
+        0xfd7feb3d2956875e,
+        0xd7192a886b8b01b6,
+        0x16e71dba55f5b85a,
+        0x93dabd3ff22ff144,
+    ],
+    [
+        0x5d3cb0d2c7ccf11f,
+        0x1215f183d5a24092,
+        0xea833d94dca4809a,
+        0xb5b472960ece11ec,
+        0x13935c99b9abbf53,
+        0x3e80d95687f0432c,
+        0x3516ab536053be5,
+    ],
+    [
+        0xd6cffe6c223aba65,
+        0xb19224aad3a69ef1,
+        0x67268f8829a9f99d,
+        0x62e33ba258712d51,
+        0xfa085c15d779c0e,
+        0x2c15d9142308c5ad,
+        0xfeb517011f27be9e,
+    ],
+    [
+        0x8a17c5054e85e2be,
+        0x15e35d5a33726681,
+        0x9b345fa359c4e8e3,
+        0xe4041579de57c879,
+        0xbbf513cb7bab5553,
+        0x66ad0373099d5fa0,
+        0x44bb6b21b87f3407,
+    ],
+    [
+        0x77d112a0b7084c6a,
+        0x2f869c2d79d95e45,
+        0xa3c8f877e8ebc840,
+        0x16fde90d02a1343b,
+        0xad14e0ed6e165185,
+        0x8df6e0b2f24085dd,
+        0xcaa8a47292d50263,
+    ],
+    [
+        0x708f2a6e8bd57583,
+        0x688e0faea5f15272,
+        0xd28955c99ed63d38,
+        0x8459801016414808,
+        0x6fbf75735353c2d1,
+        0x6e69aaf2d93ed647,
+        0x85bb5b90167cce5e,
+    ],
+    [
+        0x50bc8f76b62c8de9,
+        0x88b4d8ebe13cbd79,
+        0xda08ee1bf528e82e,
+        0xaad20d70e231582b,
+        0xeab92d70d9a22e54,
+        0xcc5ab266375580c0,
+        0x85091463e3630dce,
+    ],
+    [
+        0x8b15a656b553641a,
+        0x611c74d4137bf21b,
+        0xa051cbbf796013c1,
+        0x38a42e0db55a4275,
+        0x585971da56bb56d6,
+        0xcd957009adc1482e,
+        0xd6a96021e427567d,
+    ],
+    [
+        0x6ba74ccf722a52be,
+        0x75e2d5362c0050b1,
+        0x32e95f14d29a1c01,
+        0x3526d9b950a1d910,
+        0xa58ba01135bca7c0,
+        0xcbad32e86d60a87c,
+        0xadde1962aad3d730,
+    ],
+    [
+        0xfb317bb7533454d0,
+        0x15f9898fd61f0209,
+        0x593d179631ddf22c,
+        0x7c909e8cd5261727,
+        0xc5acb3d5fbdc832e,
+        0x54eff5c782ad3cdd,
+        0x9d54397f3caf5bfa,
+    ],
+    [
+        0x8eec643f62c90fea,
+        0xdf17fc55b1d4a915,
+        0x62fa77ed321e937f,
+        0x479f936b6d496dca,
+        0xdc2dc93d63739d4a,
+        0x27e4151c3870498c,
+        0x3a3a22ba512d13ba,
+    ],
+    [
+        0x81ce6becdf10dff2,
+        0x4182c78d3d609461,
+        0xdb5cb16e44cb1e37,
+        0x464f1adf4c68577,
+        0xacf3961e1c9d897f,
+        0x985b01ab89b41fe1,
+        0x6972d6237390aac0,
+    ],
+    [
+        0x549c669fb0049f69,
+        0xa01f16549b0a628e,
+        0x675a9a86499cd4e4,
+        0x8af42343888843c,
+        0x191433ffcbab7800,
+        0x7eb45fc94f88a71,
+        0x31bc5418ffb88fa8,
+    ],
+    [
+        0x2b6a3433940bbf2d,
+        0xdda5e942a8098f8b,
+        0x812bcb2a17f1f652,
+        0xdc46069eec17bfdf,
+        0xcacb63fe65d9e3e,
+        0x362fb57287d530c6,
+        0x5854a4fbe1762d9,
+    ],
+    [
+        0xd80b7a3c691401b7,
+        0xe205b8266ea761cb,
+        0x8e44beb4b7cde31b,
+        0x69437142dae5a255,
+        0xf2980cc4816965ac,
+        0xdbbe76ba1d9adfcf,
+        0x49c18025c0a8b0b5,
+    ],
+    [
+        0xab3bf6b494f66ef3,
+        0x530b0467dcaf3c4b,
+        0x383cc50df33afc6f,
+        0x5e351e20f30377bf,
+        0x91b3805daf12972c,
+        0x94417fa6452a265e,
+        0xbfa301a26765a7c,
+    ],
+    [
+        0x83f7b824a3911d44,
+        0x921947a8a2668a44,
+        0x13c001ebba408aaa,
+        0xfd39b7642cecf78f,
+        0x104f1af4e9201df5,
+        0xab1a3cc7eaeab609,
+        0xcee3363f210a3d8b,
+    ],
+    [
+        0x3fb8d482d0d9d03f,
+        0xd911bf94d3017ee1,
+        0x96ebbf1ceac7b4cb,
+        0x18865ff87619fd8f,
+        0xdec5293e665663d8,
+        0xea07c345872d3201,
+        0x6fce64da038a17ab,
+    ],
+    [
+        0xad346a1f100b3944,
+        0x3934eb0f8d35a797,
+        0x77664abec282db4c,
+        0xb5f630ac75a8ce03,
+        0x7cf71ae74fa8566a,
+        0xe068f2b4618df5d,
+        0x369df952ad3fd0b8,
+    ],
+    [
+        0xdb210eb547a3dbc5,
+        0xe1013615221cb0d7,
+        0x4ca87abbb73194d8,
+        0x1b0118c5c60a99c7,
+        0x6ae919ef932301b8,
+        0xcde25defa089c2fc,
+        0xc2a3776e3a7716c4,
+    ],
+    [
+        0xe55fab4f920abdc0,
+        0x7fa81600f789f5a6,
+        0x6f67cf7344c18fce,
+        0x2a5e555fd35627db,
+        0x55d5da439c42f3b8,
+        0xa758e451732a1c6f,
+        0x18caa6b46664b484,
+    ],
+    [
+        0x3b530fff7e848c5e,
+        0x152e3fec5a21ed68,
+        0x4340e5798860241a,
+        0x1944ec723253392b,
+        0x7ea6aa6a2f278ea5,
+        0x5ff786af8113b3d5,
+        0x194832eb9b0b8d0f,
+    ],
+    [
+        0xbde3379279d1cae1,
+        0xf3596e48364bdaac,
+        0x9f070e7509abc6bf,
+        0x81d90ddff0d00fdb,
+        0x2c8c7ce1173b5c77,
+        0x18c6b6c8d3f91dfb,
+        0x415d5cbbf7d9f717,
+    ],
+    [
+        0x4008062bc7755b37,
+        0x8873f772dc7d1ea2,
+        0x4502cd3133c94d7d,
+        0x381068e0f65f708b,
+        0xb4f3762e451b12a6,
+        0x6d61ed2f6d4e741,
+        0x8b3b9df537b91a2c,
+    ],
+    [
+        0x76a66ce0ee8094d1,
+        0x99dc87d517229612,
+        0x83b12c2aff5dd46e,
+        0x6e8e8ff107799274,
+        0x24a2ef180891b531,
+        0xc0eaf33a074bcb9d,
+        0x1fa399a82974e17e,
+    ],
+    [
+        0x2bc3dfb3b1756918,
+        0x3e0269476ab76f14,
+        0x52c60b61184e08de,
+        0xf5f8b21ec30bd3a0,
+        0x80a442fd5c6482a8,
+        0x4fde11e5ccde5169,
+        0x55671451f661a885,
+    ],
+    [
+        0xd060dc1e8ca204ee,
+        0xce494f4b2198e36f,
+        0x1f120ffb0524d537,
+        0xcaac64f5865d87f3,
+        0x771b9fdbd3aa4bd2,
+        0x88446393c3606c2d,
+        0xbc3d3dcd5b7d6d7f,
+    ],
+    [
+        0xc8ec4fc839254a74,
+        0x4d8b8b116ea60b09,
+        0xd6a77d7a8c6d11f4,
+        0x401a0581221957e2,
+        0xfc04e99ae3a283ce,
+        0xfe895303ab2d1e3e,
+        0x35ab7c498403975b,
+    ],
+    [
+        0x7cdf98a07b1315b0,
+        0x5b7132d0a9ee6608,
+        0xd2480e4e97602ad,
+        0x330b7e93663affbd,
+        0x3c59913fcf0d603f,
+        0xe207e6572672fd0a,
+        0x8a5dc17019c8a667,
+    ],
+    [
+        0x78284cb5c0143ed8,
+        0xac6af8e6f7820e82,
+        0x71d171a63a6187b5,
+        0x77fbb70409d316e2,
+        0xc864432c5208e583,
+        0xd3f593922668c184,
+        0x23307562648bdb54,
+    ],
+    [
+        0x5c2c485bdc8e3317,
+        0x7bfe5915c5e0fa2d,
+        0x6b433526b05fc4d8,
+        0x20085827a39ff749,
+        0x42e6c504df174606,
+        0x839da16331fea7ac,
+        0x7fd768552b10ffc6,
+    ],
+    [
+        0x6e38acb798627f75,
+        0x55ac9c4d9d32fed7,
+        0x766ef46cf807f655,
+        0xd0884af223fd056b,
+        0xbb33aafc7b80b3e4,
+        0x36b722fea81a4c88,
+        0x6e72e3022c0ed97,
+    ],
+    [
+        0xc5fb48f0939b4878,
+        0x7b773f9bcd0ec27a,
+        0x6d36a844bb3f3360,
+        0x984cf3f611546e28,
+        0xd7d9c9c4e7efb5d7,
+        0xb3152c389532b329,
+        0x1c168b512ec5f659,
+    ],
+    [
+        0x292da6390260110,
+        0x7608d31cc4c96e48,
+        0xf843ecb8366f0809,
+        0x24940a3adac420b8,
+        0x5adf73051c52bce0,
+        0x1aa5030247ed3d32,
+        0xe1ae74ab6804c08b,
+    ],
+    [
+        0x1e0ee26b7044741b,
+        0x1b7f67a75b435af5,
+        0xb24891afcb0faa49,
+        0x2a55645640911e27,
+        0x4fac2eefbd36e26f,
+        0x79ad798fb4c5835c,
+        0x359aa2faec050131,
+    ],
+    [
+        0x69b8f7e762db77ec,
+        0xd845fd95e4f669e0,
+        0xb1e8e3f0f5c9037e,
+        0x10a7228693eb083e,
+        0x1054fb19cbacf01c,
+        0xa8f389d24587ebd8,
+        0xafcb783a39926dba,
+    ],
+    [
+        0x9b321366d6585031,
+        0x8ffcf9094b4ed2e2,
+        0x3b7321189816fdcc,
+        0x39756960441fbe2f,
+        0xfb68e5fedbe3d874,
+        0x3ff380fbdd27b8e,
+        0xf48832fdda648998,
+    ],
+    [
+        0x9375c89169bf70cf,
+        0x45d697d09989365f,
+        0x3ab599efd811ae97,
+        0xcba4c10e65410ba0,
+        0x3c250c8b2d72c1b6,
+        0x177e82f415595117,
+        0x8c8dcfb9e73d3f6,
+    ],
+    [
+        0xa8db1643cc52d94d,
+        0x47d3bfec129f7edd,
+        0x925b29c3dbfea463,
+        0x951f2078aa4b8099,
+        0xe68b7fefa1cfd190,
+        0x41525a4990ba6d4a,
+        0xc373552ef4b51712,
+    ],
+    [
+        0xcf7a9ea6a7a30dee,
+        0x9573ae0f07cb7c2e,
+        0x6793c6e1fad303dd,
+        0xb57ec44bc7101b96,
+        0x6cb710e77767a25a,
+        0x2f446152d5e3a6d0,
+        0xcd69172f94543ce3,
+    ],
+    [
+        0x42c2e9f84dc7f129,
+        0x7b482774b391095c,
+        0x216a0d505d49b80,
+        0xed094f47671e359d,
+        0xd9ebdb047d57611a,
+        0x1c620e4d301037a3,
+        0xdf6f401c172f68e8,
+    ],
+    [
+        0x394c2c1cca4e9271,
+        0x2573fb79ecb7111f,
+        0x5113e80f2555b54c,
+        0x5d765af4e88f3277,
+        0xd2abe1c63ad4d103,
+        0x342a8ce0bc7af6e4,
+        0x31bfda956f3e5058,
+    ],
+    [
+        0xd38df9e9740cb16c,
+        0x79be3445c5491402,
+        0xa15ead26a317837e,
+        0xa6814d3dc578b9df,
+        0x3372111a3292b691,
+        0xe97589c81d92b513,
+        0x74edd943d1b9b5bf,
+    ],
+    [
+        0xec12466d1379cfdf,
+        0x84aae38bd5b56932,
+        0x1407e7cad8d977df,
+        0x63672de7951e1853,
+        0x3ca0c763273b99db,
+        0x29e04fa994cccb98,
+        0xb02587d792be5ee8,
+    ],
+    [
+        0x9050986d9ced6a2e,
+        0x8bc353d8f72e4f9c,
+        0xb16a21f3ae8ddaf4,
+        0xa16cd2e8b445a3fd,
+        0xf0d4f9fb613c38ef,
+        0xeee7755d444d8f2f,
+        0xb530591eb67ae30d,
+    ],
+    [
+        0xc7362967930e8a48,
+        0xa61695f6772f5336,
+        0x96e9b973fe114561,
+        0x5386ef0b438d0330,
+        0xd39e03c686f8a2da,
+        0x9555249bb9073d78,
+        0x8c0b3623fdf0b156,
+    ],
+    [
+        0x47bd8137d464eab3,
+        0x236db8fed274d4d7,
+        0x499063daa6e4eae3,
+        0xe381f24ee1d9a97d,
+        0x7c5d95b2a3af2e08,
+        0xca714acc461cdc93,
+        0x1a8ee94bc847aa3e,
+    ],
+    [
+        0xcff30d9303db2dfe,
+        0x1afb5899ab9c8653,
+        0xb2d9cc739ab9f148,
+        0x4cbef49086e62678,
+        0xd77dfecc2819ef19,
+        0xc327e4deaf4c7e72,
+        0xb4d58c73a262a32d,
+    ],
+    [
+        0x8d086fc30b6694b2,
+        0x90533a6a1124ec0b,
+        0xf24a7ec2f48b6809,
+        0xbecb065dc12d8b4e,
+        0xebee135492a2018,
+        0xd3f07e65bcd9e13a,
+        0x85c933e85382e9f9,
+    ],
+    [
+        0xb7d681356bdd9e4f,
+        0x9e8e19b5cdbfb229,
+        0xe8f5fbafde7bea61,
+        0xbc944c1b5ba2184d,
+        0xab3d57e5e60e9714,
+        0x5d8d27e7dd0a365a,
+        0x4dd809e11740af1a,
+    ],
+    [
+        0x5bb01fcb2e6ad355,
+        0x895c355e71191ef4,
+        0x1f7a98978f1bf049,
+        0xaaa144fbe3e6fda2,
+        0x52a9291d1e212bc5,
+        0x2b4c68291f26b570,
+        0x45351ab332855267,
+    ],
+    [
+        0xcd2ff001a80d1b11,
+        0xc0f8d9d7d08c74b3,
+        0x5df56e499e9ca980,
+        0xb8c18d66154ac51,
+        0x5807350371ad7388,
+        0x81f783f4f5ab2b8,
+        0xfa4e659f90744de7,
+    ],
+    [
+        0x8bfbf611401100cd,
+        0x599edd1f5154a546,
+        0x56b61ed81d29796,
+        0xb744f5056e74ca86,
+        0x88aa27b96f3d84a5,
+        0xb4b1ee0470ac3826,
+        0xaeb46264f4e15d4f,
+    ],
+    [
+        0xec9ae0cf9290d012,
+        0x14368811a4a1621d,
+        0xde04cc7d2c562fcf,
+        0x4323852cc57e4af3,
+        0x1f5f638bbf9d2e5b,
+        0x578fb6ac89a31d9,
+        0x7792536d9ac4bf12,
+    ],
+    [
+        0x4ac2a5e9dc03176d,
+        0x78df6aca1dd90b2b,
+        0xe5fcbc1dfe65f7db,
+        0x766b71bff7d6f461,
+        0xb004f2c910a6659e,
+        0x4c0eb3848e1a7c8,
+        0x3f90439d05c3563b,
+    ],
+    [
+        0x5fd51f635bc557a8,
+        0x7e810b3d0b3db6dc,
+        0x603d74dd65a3baf6,
+        0xd178444a236c1f2d,
+        0x5576deee27f3f103,
+        0x943611bb5b1b0736,
+        0xa0fde17cb5c2316d,
+    ],
+    [
+        0xec3521e8efdb1779,
+        0x422da247696eedc,
+        0xa372b6a2f501313c,
+        0x7a265e37da616168,
+        0x6a1f06c34bafa27,
+        0xfbae175e7ed22a9c,
+        0xb144e84f6f33c098,
+    ],
+    [
+        0xa9147f0fb2e38bb1,
+        0xd2da27a1045272e7,
+        0x7bfb951842162784,
+        0x9fd4d9362494cbbc,
+        0xe562bc615befb1b9,
+        0x8096808d8646cfde,
+        0xc4084a587b9776ec,
+    ],
+    [
+        0xa080e609751f2e81,
+        0x2df3057a20f24b2e,
+        0xb9a0f8b45d79c318,
+        0xc84bb7b3881ab070,
+        0x36fe6c51023fbda0,
+        0xd62838514bb87ea4,
+        0x9eeb5e7934373d86,
+    ],
+    [
+        0x3bc578f69905fa2d,
+        0xbb2c316b7f3eb8c2,
+        0x18646edbbf14b0ed,
+        0x4473c8e2a3458ee0,
+        0x258053945ab4a39a,
+        0xf8d745ca41962817,
+        0x7afb6d40df9b8f71,
+    ],
+    [
+        0x9e6a5e0641d1c0d9,
+        0xba3905a52abd5805,
+        0x73c0bcd5a7366e15,
+        0xb5f52041a698da7,
+        0x29864874b5f1936d,
+        0x49b3a0c6d78f98da,
+        0x93a1a8c7d90de296,
+    ],
+    [
+        0x83b0cdb3c934c679,
+        0x88e72fd2e9c85618,
+        0x25cbcd575a21c446,
+        0x664ec3fad8521859,
+        0x406f082beb9ca29a,
+        0xb6b0fb3a7981c7c8,
+        0x3ebd280b598a9721,
+    ],
+    [
+        0xf174161497c5fa97,
+        0xdb3717129871fa98,
+        0x5eb7a9d5a724daed,
+        0x5414e385f5677a6d,
+        0x41ef105f8a682a28,
+        0x94ae9f66d82d71f0,
+        0x6b698643f5924cc6,
+    ],
+    [
+        0xd7262cb2f2755e70,
+        0xf87e6123e1e56dc7,
+        0x76a474a551662078,
+        0xd4bd358fed3e6aa5,
+        0x8a1ba396356197d9,
+        0x5bcf3e13a8184f6d,
+        0x5f17183906e40929,
+    ],
+    [
+        0x1444ce264e8784b7,
+        0x72dd36167600c3c4,
+        0x7e52afec3d9d166e,
+        0xdb0c32f76f5b7fc1,
+        0x5e41b711f0abd1a0,
+        0x41f387462b60ec17,
+        0x20cd7feaf6b0d5ea,
+    ],
+    [
+        0x532e6b5c95a2e229,
+        0x2e74b7cb427c4e8a,
+        0x90febf2e15deaaa5,
+        0x32d3a29cf49e2dc9,
+        0x3079c0b0c2269bd0,
+        0x906379f72fdcdff1,
+        0xea076563ae6ed4ce,
+    ],
+    [
+        0x183d112159f539eb,
+        0xe0a272f5325ccfce,
+        0x82051bab809a3bf3,
+        0xa54eaa5d7f3a7227,
+        0x9d26922965d54727,
+        0x858c68ea2e46ec2e,
+        0xe157ea542fd04d72,
+    ],
+    [
+        0x8f18272400b3ace9,
+        0xf936fba4e6c7f6f5,
+        0xa395a3524727f255,
+        0xea37f61c0c2f6d53,
+        0x9b0c2174f14a01f5,
+        0x631f4fbb52f7b4e1,
+        0x882e8ea542c5a526,
+    ],
+    [
+        0x43761e6a5f6f2fd6,
+        0x49ff8647ef7de2cd,
+        0x9db86e928d9c50d8,
+        0xa32c192f6e3c3f66,
+        0x8f10077b8a902d00,
+        0x85dcc88c159d5666,
+        0x2277bf43094b85be,
+    ],
+    [
+        0x44f615fcd096fbfe,
+        0xf91b1ef287c2b469,
+        0xb0153a7a31076599,
+        0xa82a7bb790678fc9,
+        0xd197682c421e4373,
+        0x92183ad53c7546c2,
+        0x80e483f6e47eb386,
+    ],
+    [
+        0x27613f9db818cf78,
+        0xffe86d35e2b6af5c,
+        0xbf8519db808bc3fa,
+        0x33322363b5f45216,
+        0x7e83f1fe4189e843,
+        0xe9fbbdca5076a660,
+        0xa025a44ad19f89a6,
+    ],
+    [
+        0x3f6984c7afaebd0b,
+        0xce1a85c17d6eaa95,
+        0x5b4fdfaf084a67cb,
+        0x33c6690937582317,
+        0xfe6d61a77985d7bb,
+        0xd33afeed3012068c,
+        0x9769fde4cc576926,
+    ],
+    [
+        0x8fc511284f47c772,
+        0xb4c0724c9dd30a7a,
+        0xd04d61891a700659,
+        0xf3d41b3d4717eb83,
+        0x2670d457dde68842,
+        0x8af262ab62293c,
+        0xc4bbf2c513a0d201,
+    ],
+    [
+        0x15ae5f12f88592e2,
+        0xaeec43559dd10188,
+        0x5bd91d548ffefd6e,
+        0xdd70e407984cfa80,
+        0x66996d6066db6e1a,
+        0xccdf7065727283f8,
+        0x11e3ac9d9fa63e16,
+    ],
+    [
+        0x905f995bddf92cb7,
+        0xf7847475e7e1e12,
+        0x4dc5bb06cd995cee,
+        0x9432536dd9f65229,
+        0x192dc54522da3e3d,
+        0x5eecf6aff92e88d4,
+        0x2d450eaa62f5f8cf,
+    ],
+    [
+        0xa23ac6bef8905fec,
+        0xf675b3d658210f05,
+        0xb363b9add66e1684,
+        0xb9aa5bead3352801,
+        0x8a6d9e02a19a4229,
+        0x481ba75a9a2f85cd,
+        0x17f5920e749edd3c,
+    ],
+    [
+        0x403b94a75160a06b,
+        0xc6eb4d9351f81e3a,
+        0xaa0739cef99075f8,
+        0x8d8e7c63385df78e,
+        0x16d55add72a5e25e,
+        0x5fc4a1f7b4b489f1,
+        0xb6bb01a378d6ff12,
+    ],
+    [
+        0x14d1ee05672fc19b,
+        0x703c1a85efd79788,
+        0xd065eb50cfb5c451,
+        0xce218d5b44f7825a,
+        0x2ae0c64765800d3a,
+        0x176c27079a2958b8,
+        0x9c886360f29d425d,
+    ],
+    [
+        0xf59376c617951a2a,
+        0x32082c76de4710ff,
+        0x5d291a873d8d9f9a,
+        0x1682f54521c291e2,
+        0x17ca7ab8d97ba0d9,
+        0xd8d6d62c4e314ae1,
+        0x269df5967d606f43,
+    ],
+    [
+        0x63982fdc37a9dc5,
+        0x8f7025774c4faea9,
+        0xe7d5ccc2c570c989,
+        0x8868a216a8f9946,
+        0x136b5ce2ede03238,
+        0xc19f0f6f73f2e30e,
+        0x9219f59f493aa238,
+    ],
+    [
+        0xeb480334ed838b48,
+        0x87f74500f9dd7bec,
+        0x39dfa7182359c93d,
+        0x6b7d8d04ae2db2b2,
+        0x703b85004b504bd6,
+        0x6b08bbdf158d17eb,
+        0xaa84994c75c80c1b,
+    ],
+    [
+        0xd0b9004efa0a1164,
+        0x9f68c1718dbde89f,
+        0xb5d9a7ab23808f7,
+        0x9463491a8439eb54,
+        0x2bd049dd3b9307dd,
+        0x6b8387ee8561f97e,
+        0x2db67c3e7c0097f2,
+    ],
+    [
+        0xb31f2b6cc2a15506,
+        0x28ad14cab53f717a,
+        0x341f7d6f1212356b,
+        0xeee5254624f99323,
+        0x50f935ad0b11b484,
+        0x56944e9d03e9b415,
+        0x886a205391ac0526,
+    ],
+    [
+        0x4f9da8a709bec12f,
+        0x69347f992ba0b479,
+        0xd82b32d7831a57aa,
+        0x5e1aaa3d321c7c55,
+        0xd808df942eb2b92b,
+        0x6f303048a4f26df9,
+        0xcaea0391727bdafd,
+    ],
+    [
+        0x5504000602e6f8cf,
+        0x17b80b6681b62db0,
+        0xd83dda14a4423630,
+        0x7926e11179d2b882,
+        0xe94fcaa22d091d09,
+        0xea51d34158cd60a8,
+        0xb6d56df73b12bacc,
+    ],
+    [
+        0x2d022d82f513a883,
+        0xda474eedd3ed528,
+        0xb339f8f80f556971,
+        0xbba2b0a0f1b18055,
+        0x1e72c71e6c54cb2e,
+        0xe43bfe7158c31dc3,
+        0x416e04cd551cf777,
+    ],
+    [
+        0xa87268205997eddb,
+        0xe58cd6fb05cb965,
+        0xc69eac6e1256a4d9,
+        0xe1dbe62ee1aec1a2,
+        0xdb97b9879cfc5bb0,
+        0xc1a96637db293163,
+        0xcdacf3525efecb1e,
+    ],
+    [
+        0xfde5f0a803c3affc,
+        0x9db3e206a3deda39,
+        0x6a678f40a8058ab0,
+        0xdd00bf5b13f1b349,
+        0x9441065c7bddf30a,
+        0xe6005534fbe17f8e,
+        0x32346eb79b5ac530,
+    ],
+    [
+        0xfa46e0e215c1aabd,
+        0x6b795034438df194,
+        0x14571d59c36d29ea,
+        0xfa36d64aa42643b7,
+        0xd2c8ac46f42c7ed,
+        0xf3d36aa6e975be3f,
+        0xe7f022266f7e0f3e,
+    ],
+    [
+        0x7e1f98b2c16f8b2b,
+        0xc492c4d36704af4c,
+        0xd23b89c36294385a,
+        0x244cf0d778a05ec5,
+        0x8380a5edd5a19005,
+        0x29a9617055f5a9d9,
+        0x7005e59f480fa82,
+    ],
+    [
+        0x65a58d22d8665e60,
+        0x41d64e7bbfe54f80,
+        0x14ce593a63d5a590,
+        0x97f748c8a8d4f327,
+        0x3445886827d5e08c,
+        0x634b32b334de0fa5,
+        0x5ff94df6197c1bc1,
+    ],
+    [
+        0xb781b9a55e7d6ab9,
+        0xed16ad5400bc87e,
+        0xb2ae79ab4b601bec,
+        0x1cfc0839227804c3,
+        0xaf51c631d33d0e65,
+        0xdd36799ebc883ef5,
+        0x548b458a566154a8,
+    ],
+    [
+        0xa88c857b1aeb0835,
+        0x1b2cdaa3a001518c,
+        0xb03b42ed85390bea,
+        0x47a8abfd4b36b416,
+        0xc28d691c93c456ae,
+        0x7b0890fd2c0a3e45,
+        0x757b11be8b925e40,
+    ],
+    [
+        0x2a25994979124643,
+        0x3a3e0c52948d116a,
+        0x8667e3420bb8d50,
+        0xee4ae633d60287f3,
+        0xbaf02bf6e52c4b9e,
+        0x31a0d0505fb5b745,
+        0xbed2029003e3afe5,
+    ],
+    [
+        0x17236ed61e669c6f,
+        0xb049f89783cd2c3f,
+        0x28fe56765c1b7ed,
+        0xe8e11d96868fd11e,
+        0xd7323d276887576a,
+        0xb6a62449ac769727,
+        0xdfcf0d8773df717a,
+    ],
+    [
+        0x304f56359ac375a8,
+        0x9b303c940f78c5b5,
+        0xd52df3445a1a027c,
+        0xfa406ca71ad32716,
+        0x103ee9820a95f912,
+        0x4d4cc990a9c5f759,
+        0x7a70381414b621cf,
+    ],
+    [
+        0x2e236ded6ce34194,
+        0x55cc633269f53f75,
+        0xb0b18d56850de1a5,
+        0xeb648960aa937c06,
+        0xac4d4ac05a66c83e,
+        0xb56ebc918ee8e99c,
+        0xacf505efb66a8d78,
+    ],
+    [
+        0x837ecb10d69f9bb9,
+        0xa51533872cb4f5b1,
+        0x620aaae18ac52da4,
+        0x94728cfb8d580043,
+        0x98dc3ea6890f66e4,
+        0x3373ffcf756e1a31,
+        0x5742e8e69476ef0e,
+    ],
+    [
+        0xc94bc80993d726f2,
+        0x13d4514a68dca054,
+        0x4b2b9d78cda610a0,
+        0xc6a9ed722f850d2,
+        0xff91629f0e2bda83,
+        0x637d74f5a36dc3d3,
+        0x788b506986bfbaba,
+    ],
+    [
+        0x463b54729349357a,
+        0x381b0b67f1b0e6cf,
+        0xab16e09e606fd74f,
+        0x783cc746f3e0ad94,
+        0x4cb37580460063a4,
+        0xbc693f29e8c8e068,
+        0xd8d02963f0137472,
+    ],
+    [
+        0x52e298a69bc61248,
+        0xf0c389219e5b280d,
+        0x45d0e8cbf8999a16,
+        0xabf9fdcb39df4494,
+        0xe5f29602c7c58f65,
+        0x41b808023b066957,
+        0xe2158bf17bd49509,
+    ],
+    [
+        0xf31bde28294be223,
+        0x6fcf217a39638e31,
+        0xb57c20d0886767cc,
+        0x9ff176e872b59750,
+        0xb79b82d5e03a36a7,
+        0xa05cd10a650a945c,
+        0xbf270f3283985d7d,
+    ],
+    [
+        0xd1d98f3bbaf26f1e,
+        0x6e1bb3126ee93a70,
+        0xfdb28b9faa7455cb,
+        0x77bccd28f0502dc2,
+        0x4704cff4be86c032,
+        0x2d7083cbb8dabf9,
+        0x40f403385f91540f,
+    ],
+    [
+        0x77969267e761a5e2,
+        0xef907093519fd3ff,
+        0x6a50d507180b9dd8,
+        0xf7671fba16e03cdd,
+        0x1d6da71263c3c627,
+        0x1f3fd89b2729c1a7,
+        0x823b6d2b1df14ad9,
+    ],
+    [
+        0x763f1101a3d8e5d6,
+        0x6de2bec8b2a9f0d9,
+        0xbdc2cec3b0c72126,
+        0xc684b15ed14c4849,
+        0x25370610ff0d1b07,
+        0x7b933b223c95a22f,
+        0x3a44eb381671ea69,
+    ],
+    [
+        0xb6ffcab942c26180,
+        0x1261443adbb2a65b,
+        0x8bc905dfc7b85595,
+        0xada824a1b0baddee,
+        0xdadce601b47afe19,
+        0x46c3679d5436da89,
+        0xc6251e4bebbd57da,
+    ],
+    [
+        0x65a85965268277a5,
+        0xf29a5b2c9a51c439,
+        0xaabf0324ece8814,
+        0x6521fe578234c086,
+        0x88294a1221e85ad5,
+        0x685d8b1b46910a3a,
+        0x600a02f24903a0a2,
+    ],
+    [
+        0x6579248c4cabcf91,
+        0x21b4fdd6da56df76,
+        0x727c8a9161e8cc9e,
+        0xf5ef59c60d0513ff,
+        0xa2c01a296e191385,
+        0x8a7be6557ac395e3,
+        0xfa772f9eee991c2e,
+    ],
+    [
+        0xfcea6deb6fbc95de,
+        0xfa083e475037dd9b,
+        0xa402e8b120895dcf,
+        0x128da31d726a4316,
+        0x6b12ccb52a7d436b,
+        0x86a73e06caadae8c,
+        0xf0106fd320ce8874,
+    ],
+    [
+        0xa5afb4dac88f15f0,
+        0xfa2d11898daaa5e1,
+        0xc118493d086eb030,
+        0x932d16df5d691fb1,
+        0x5dde659f15fe9b64,
+        0x6a55cbf2cd4cf502,
+        0x2c69b73b509c23e4,
+    ],
+    [
+        0x35f437b7acbfd454,
+        0x23e56c12e72a5eee,
+        0xb29d3d3f9fea4832,
+        0xbd6947ba8f8af57,
+        0xe2c925a1262dbc65,
+        0xcec8c16603d2616e,
+        0x4acfe590ee1f5942,
+    ],
+    [
+        0x8f45f63a2f2d77d5,
+        0x2f251880d8b83227,
+        0x7b457c2c096a4fef,
+        0x60386ba5baeb0a28,
+        0x4c8db172ac835d05,
+        0xc9f3245a03b60ffa,
+        0x1e40258aeb689328,
+    ],
+    [
+        0x62258e6fe64ea749,
+        0xe61da1411606c50d,
+        0x1e383042228a6524,
+        0xed27416d233bcc8c,
+        0xc36902f1d5236598,
+        0x72cc7a789bdc0df8,
+        0x16e1c593759df8ba,
+    ],
+    [
+        0xfc109f4192ba2587,
+        0xda1eb26735719b82,
+        0xda0616341a86759b,
+        0xbae2015903c43013,
+        0xded1d087f02e51df,
+        0x4865349424eee47d,
+        0xcfab56ebb783561b,
+    ],
+    [
+        0x5364968136715e44,
+        0x4d6df79cca67503c,
+        0x1b04a476a5af6319,
+        0x38769eab2c8333ca,
+        0x3babeb25b54f33e4,
+        0x70e9850467e4d7c4,
+        0x4b23b70a8351c781,
+    ],
+    [
+        0xdd84538848e07acb,
+        0x7e8b2c2ea4b31867,
+        0x4a1b7795ab30febc,
+        0x6264b317e74f1956,
+        0xe7baa170c2d01227,
+        0x1d6e44e71bfb4f79,
+        0x96c3307376e7264a,
+    ],
+    [
+        0x397d78f9c2fb2a8a,
+        0xa35668e29dd4493e,
+        0x4b5d814365f300c3,
+        0xb47c59e2352ab2de,
+        0xfee916bf69dca4f2,
+        0x31720599f9b05204,
+        0xcefd056a9a8cdb6d,
+    ],
+    [
+        0xa3a22aed573f4128,
+        0x6f441959a6a745a4,
+        0x61b303cec0f02d33,
+        0x7b245212b57b6416,
+        0x483eb1c2247030ca,
+        0x9e73ba2d0cf42603,
+        0x7caaf3b868c1fa6d,
+    ],
+    [
+        0x94bcd5be64b0caf0,
+        0x3eee5a2a3b25c93d,
+        0x465dd18f325b992d,
+        0xfd45ffd2387a5d25,
+        0x35a79122b1ac38d2,
+        0x45c691735698b5c,
+        0x87dfa15417044ee0,
+    ],
+    [
+        0x81d9fe1f35fe8dc,
+        0x2c5e1e21c57e9a0f,
+        0x9cee67a7a5c2455,
+        0xf92e222a71b413fe,
+        0xec8f28b36b4b2d43,
+        0x73416a88b65b673b,
+        0xcf89cf0d3d4148ae,
+    ],
+    [
+        0xaa21f88e4310c4aa,
+        0xfd678eaf1a2ef449,
+        0x9eb788a336487a2f,
+        0x6c43d6ff49cff14b,
+        0x88c3f8f93676751c,
+        0x2322a939c0b8bef3,
+        0x4adf71d19b5e462b,
+    ],
+    [
+        0x88e65c8bd8fd0dc3,
+        0x67506bfcc1350ac,
+        0x8e35bd6404cd4a19,
+        0x10f30b6a6ec67afe,
+        0xaeb8adee4a38102c,
+        0xc7c31205261346ba,
+        0xed3a7eb75f0bd93c,
+    ],
+    [
+        0xee7c287c7a74eaf6,
+        0xa4716c9b01821875,
+        0x15b4a29d0e8621fd,
+        0xde6e60dce8b3c61,
+        0x8c97da4b7dca945c,
+        0x315027b9093d6a5,
+        0xc60067ac2204a60,
+    ],
+    [
+        0x59492bfd26df7a46,
+        0x88ef9253d909b4e7,
+        0x2dabd903fca564b6,
+        0xd154dee359c629dd,
+        0x61da6808b6b00605,
+        0x1c862110cb1fa740,
+        0x877bc417d7060346,
+    ],
+    [
+        0x79471e68a2e7b4c3,
+        0xe2ecc811cd228f6c,
+        0x1c0c9618d88b7cf9,
+        0xc0eff8b2276af95f,
+        0x3ec1a9c95964b183,
+        0x9b480684e56c25dd,
+        0x8b849da7c70dbd8f,
+    ],
+    [
+        0xf806f8b0f54bbbf4,
+        0xc55ef4c3c37c3e8,
+        0xc57fbf8500049609,
+        0x48180ec114a019d9,
+        0x5dde07c8bebb3f74,
+        0xf3c14ac2fd15afc7,
+        0x18ba219f867fa279,
+    ],
+    [
+        0xaf0a9fa8d197fc2a,
+        0x83821f8be6e51915,
+        0x297afa7cc277321a,
+        0x5006b740dd5776ee,
+        0xf139123a5edbaaca,
+        0x40b1400056322a19,
+        0xb8d5d524f5b2d708,
+    ],
+    [
+        0xa93491c935028bfd,
+        0x2c36d238aac64cd6,
+        0xfc5dcf1134041bb3,
+        0xd813172f90f9ff47,
+        0xc65afeef14c7926f,
+        0xe0b91c18356984dd,
+        0x5b8bcaba09e98375,
+    ],
+    [
+        0x35fb344f57414e7e,
+        0xb4b10fbd03ba41bd,
+        0xf084e04093c69f21,
+        0x1f4862ff7005835b,
+        0xe3281b9e914c7ab1,
+        0xb551d8d1d75dfe2d,
+        0xcc64942fb0af0ab5,
+    ],
+    [
+        0x650c588ae7997006,
+        0x7b6516105738f40b,
+        0xfd225f2b4d05e97b,
+        0x76d74223d7c381f4,
+        0x84445fec43ac4ba9,
+        0xe457a2d9de2f0cb,
+        0x4f80e43844d5a721,
+    ],
+    [
+        0x8e83c18ec4fac9b2,
+        0xdd53e5013eea5434,
+        0x15a4f84e1fbb081f,
+        0x2494508d174f0a6a,
+        0xf8f421c15e7812a6,
+        0x15d14a45d852a3c1,
+        0xa12e6ceaa7b91862,
+    ],
+    [
+        0x35422c6582e3fa2e,
+        0xbabd15f9e3a1362d,
+        0xaac1901ece0f6dbe,
+        0xa1d206f25bcb2e04,
+        0x630172f8ae1fbfa7,
+        0x4df60c162fd13077,
+        0x24fcca03cbfd0d36,
+    ],
+    [
+        0xfc0cb7f55d516f4e,
+        0xa92f59467a97a76e,
+        0xf058bdc360787c2e,
+        0x2a428170995dc60e,
+        0x90bf382200c6050d,
+        0x522302fa40bdb9fa,
+        0x7d84077cabfda564,
+    ],
+    [
+        0xe6245e6273cd7da4,
+        0xbb6b62af69cce44f,
+        0xdef05217f2ba3b7e,
+        0x40bd603028fabdfe,
+        0x897f7885daba93,
+        0xd343910442a51554,
+        0xfe448c8b278337b5,
+    ],
+    [
+        0xbfb40261b25b0146,
+        0xfd74edf7a1e0a088,
+        0xd9de3386702c1efa,
+        0xac61387d34b8eba3,
+        0xf658eceff68e4f98,
+        0x77eb7ea3faf6af6f,
+        0xf2ae3f731cc6c3d1,
+    ],
+    [
+        0x298876b240a1f937,
+        0x81c826252bc3fd08,
+        0xf4ec81df6387b639,
+        0xa87f848ac3739101,
+        0x50a679588482b1c6,
+        0x5ac0460a499bdd23,
+        0x49cf4b738f9b6eb1,
+    ],
+    [
+        0xbf26833d8f21542e,
+        0x62d95f72f9dc8b6a,
+        0x978d2ce692fc2cd8,
+        0xb2fc06ae50b5bdc4,
+        0xb5ebdc6f7e689092,
+        0x1324ae1b8c471019,
+        0x235375036a20b675,
+    ],
+    [
+        0xff85120bd8fa3cd4,
+        0xf768d9c178d8e1a3,
+        0xa7a136668d023fec,
+        0x227a859ccfb575f7,
+        0x8fc9e6749312034b,
+        0xfe1358976e8d1d7f,
+        0x47e95007f7949411,
+    ],
+    [
+        0xa37277b9eb9b16fc,
+        0x2b780c1cd1dad364,
+        0xdd4a2f266cd6aa0f,
+        0xcce760ed2d17daf7,
+        0x2c5e908016d4ff3c,
+        0xc68cab0c465584c1,
+        0x67a3eafc44e4d677,
+    ],
+    [
+        0xb95c558eb132482f,
+        0x86deccc1c79e3da4,
+        0x8b43da48c6f5fd49,
+        0xd35063f44069c518,
+        0xbfa680a6399cae70,
+        0x14af385219857c8b,
+        0x1689871cfa0f2813,
+    ],
+    [
+        0xeb2a51b23ea2f82d,
+        0x7d3802b6045fbd2b,
+        0xa8670a39b06b41ce,
+        0x851073e9d44dc921,
+        0xb0aa6451924617c8,
+        0x7b3ca65185907aad,
+        0x4c1e01ba84283e44,
+    ],
+    [
+        0xc85dcc13ce7d29c0,
+        0x3a520e93ccdeb592,
+        0xea19bd7ee74ed003,
+        0x3628daac258a4a48,
+        0xafb35f7b660a441,
+        0x5cb7c82d5974037a,
+        0xf285ff762ef24753,
+    ],
+    [
+        0x8a8707d80cb54c7a,
+        0xa4135e3dcd129a6c,
+        0x411cbfa152de1998,
+        0x674142656124b5c7,
+        0x1280123d0a21a062,
+        0x1afc0b67eb7c5bf7,
+        0x6a666c0d97040f27,
+    ],
+    [
+        0x12c7ffecff1800ba,
+        0x1172a3993fff1057,
+        0x44bd867e67fc8ae4,
+        0x81b2a78e5319365c,
+        0xb3aa90916d02335f,
+        0xd36241c3a0745572,
+        0x5c6e74ae8dd3d88b,
+    ],
+    [
+        0xcb16c5c1e342e34d,
+        0x9087c1dc80a18b68,
+        0xd48a476057f23dc8,
+        0x41ff65495500e2e4,
+        0xb8fab1ad95574a61,
+        0xdbfd0326a7599b91,
+        0xae5eb38b876d3fa9,
+    ],
+    [
+        0x27fddd06bd368c50,
+        0xf7efaa7ef3bed090,
+        0xb505f7b0690e3f70,
+        0xed194c89f81522b9,
+        0x272a0528540527e1,
+        0x57e1e98c484b9f28,
+        0xe0d5a808989c1b7,
+    ],
+    [
+        0x5e6c6ee85cec7703,
+        0xdc33500e0dea1513,
+        0xb0d9d93b584d752f,
+        0x596dad7ffc69035,
+        0x1a31664d3d509c10,
+        0x8dcf0b12245cbae5,
+        0x6a01673fd8b513d,
+    ],
+    [
+        0x2117190446b50f9d,
+        0x8b48030b85d083ab,
+        0x68a79e0698163241,
+        0x716bb879de7fba1d,
+        0xa388cdfa3c61ab73,
+        0x411fada7499faf84,
+        0x14ef89ce95bee4a3,
+    ],
+    [
+        0xf3f12b62f51a9b55,
+        0x2866854cc1edef6c,
+        0x1476fb5f05a37391,
+        0xc40dceb74330b7b7,
+        0xb84db83f7ccc5f9,
+        0x9d0117552050ed7f,
+        0x25b93de89e85456c,
+    ],
+    [
+        0x2ee01b9e2a7692a6,
+        0x2a9920e8a8923bea,
+        0xc6cfbcd09cc47583,
+        0x80e53ddb1a7abd17,
+        0xd585cba8c327e538,
+        0xf4d313c03777336c,
+        0x7d136bd0afbaf9dc,
+    ],
+    [
+        0x53ca5e2da19191b7,
+        0xab8fba8b2c21655,
+        0xede0ddbd23d66d73,
+        0xce7dca3935c2fdc1,
+        0xbd19a32205fcc165,
+        0xa0a12ad442bce1f6,
+        0x15eae05c9c6a0e03,
+    ],
+    [
+        0xce6d0917744faa2f,
+        0x42d66b23a164a48a,
+        0xecece7b7f05004f,
+        0xe08c6a73a5559556,
+        0x889313a96c9a1323,
+        0x3253f1f2a3a66b1a,
+        0xa2767544b53fee4e,
+    ],
+    [
+        0xf9b8ca6b46052208,
+        0xd693bb3a5a89c965,
+        0x6e975d1339c4774a,
+        0xd72e8efd275e3db8,
+        0xc471d5f3d0c9b1b6,
+        0xc62c1d0fc7800ec2,
+        0xacb7f73f9b5a1109,
+    ],
+    [
+        0xfb1cb91d94d6cddb,
+        0xdfb1e3d6b795c8ac,
+        0xec4df9c3e90f1e24,
+        0x6c487cdea2c507de,
+        0x82d42d569ff4c955,
+        0x7c3767d754e62f5f,
+        0x3916c993f8cdca8c,
+    ],
+    [
+        0xa39e2eab5f174f15,
+        0x9f2a05a63d015c7b,
+        0xc2e46128224f249b,
+        0x3d2b520d3144119b,
+        0xc8ade99b9d8d5092,
+        0x65882e9c99a0ed12,
+        0x42a89a2be41263d9,
+    ],
+    [
+        0xe9bfc7e088623326,
+        0xa79c980a7458736f,
+        0xe7a09424c5bd6f77,
+        0xd623ef8d9e4750dd,
+        0xb329a5d0ce2c4320,
+        0x724fc6ee18c04a2f,
+        0x6f288c76ecde63bb,
+    ],
+    [
+        0x24d3561ce4eda075,
+        0x74fdf369a4ba7bd8,
+        0xc979f4ef12661fbd,
+        0xb2e2bf501c9bd4ee,
+        0xf66a2607c4d22a24,
+        0xb9709df0c8fa8889,
+        0xf70db2a5a9e6f385,
+    ],
+    [
+        0x3edb299037e41adc,
+        0x8e3327b45d22677f,
+        0x85d3af0877d1b233,
+        0x41a0a96292eebd12,
+        0xd331d1a9960dd15e,
+        0x45c06e443e3580ef,
+        0x8c9a4b60297b5822,
+    ],
+    [
+        0x4ccafed99120c34c,
+        0xc061a2298aacd9ee,
+        0xd8a2a419bbd61dd4,
+        0xee848fe0fa5feec3,
+        0xa1c2bbe4bea46de1,
+        0xf72fee59825eaba4,
+        0x1c6cbfcae94f761a,
+    ],
+    [
+        0x811039d76b0f5c10,
+        0x81c01b119d95abfb,
+        0xb9f230a525dd1a79,
+        0x82a0d5833ef0fe08,
+        0xbcab840f326aa717,
+        0xed7f80003ad9c7a8,
+        0xe1a7e9e27bfbb5ce,
+    ],
+    [
+        0xf26eca16e4f6b311,
+        0x32720d4ea0a72e4,
+        0x7e13d7dbee27de4e,
+        0x5c37936e56cf7e46,
+        0x295f982a83b30c99,
+        0xb0241eb8061d0f95,
+        0xe4dc70591f41cea4,
+    ],
+    [
+        0x8ce51e30cf1501bb,
+        0x35e452a0a514fbf6,
+        0xe12df99407eac10b,
+        0x2a4a1228a520332a,
+        0x7746e7c2193f936e,
+        0x814a4661f92c5f06,
+        0xdf8cbc1191bb982b,
+    ],
+    [
+        0x80d0fa7707773de4,
+        0x4be9c7b015a574a9,
+        0xd4cb1cb66a739318,
+        0xdf8dc7766f988303,
+        0x8188a46bb7a98536,
+        0xd9fcbdd211e305cb,
+        0x2c798285814ddf2e,
+    ],
+    [
+        0x698d6cc716818773,
+        0x845fe2403582149,
+        0x9502bc1422758522,
+        0x3d59e8cf894ec921,
+        0x5310828b8dbedfdb,
+        0x238dce16320651dd,
+        0x7b38b1f93ce8749b,
+    ],
+    [
+        0xcaaa5ff55032cbcf,
+        0x538e9005d8665c92,
+        0xe174f0f93d30f0bc,
+        0x9ad16d0b0a5892be,
+        0xa2ce93130b6539eb,
+        0xe50402009848b944,
+        0x5bf398fdd39286d5,
+    ],
+    [
+        0x3333d53faadbec42,
+        0x3f8857090ee7798b,
+        0x5c95401451994dac,
+        0xca985ee7a329cd7e,
+        0x76ecafcc948c9562,
+        0x268ce4e1a2a5a074,
+        0x21d353fba6630d78,
+    ],
+    [
+        0x10882aac3dd3587,
+        0xe0963a96a791586f,
+        0x2d2e1c962520b6de,
+        0xb2a78656df8faaa1,
+        0xddfae1420e3e858d,
+        0x1912b4f86123a4d6,
+        0x986e18713086add0,
+    ],
+    [
+        0xb11fde1059b22334,
+        0xefc2d98538f4ecfc,
+        0x36af0ce3f9940bdf,
+        0xd7a26a8e9c020084,
+        0xf5177c6dc6d8a5,
+        0x6926948892e970c8,
+        0x840b22073cf60998,
+    ],
+    [
+        0x8977ae72ed603d45,
+        0x60f4ffd92231c25b,
+        0xe2b1b66758d158fc,
+        0x70caf8189b6e929b,
+        0x1b80d6fcc87b4d5e,
+        0x77ae1691bcc4bbea,
+        0x5c619855527e1200,
+    ],
+    [
+        0xf65b17f58e2f82f6,
+        0x7b5e65ee7c85e9b9,
+        0x5e28d4218467b771,
+        0xc0a7d673c0ae5225,
+        0x31b05cd187dce5fc,
+        0x3bdaf6c1c3992de9,
+        0xaf7d1ee6c8d8e3ae,
+    ],
+    [
+        0x63689bb426fad75,
+        0xe65b123bfc973da6,
+        0xdb08275d11847a43,
+        0x92dc01e5daa6f8cf,
+        0xe2c4e337f7e3c4bf,
+        0x35339b7ca3a1be91,
+        0x80a30021da4c2964,
+    ],
+    [
+        0xf09d687ab01da414,
+        0x89e97db87314fab1,
+        0x2c48cf28ff80b17f,
+        0x9443d8392ae194e1,
+        0x929e316b17083568,
+        0x117dea737f1df80b,
+        0x1fc14678b5adf5ff,
+    ],
+    [
+        0xf9946308ce8bcec0,
+        0xcdfe313f59a7c805,
+        0x43f83dac819e8271,
+        0xf7ee9f4f36e9cfcb,
+        0x16c3965ae72f209e,
+        0x56f7bd99b0d467f3,
+        0xc7bc0fdcc7a4f542,
+    ],
+    [
+        0x5f2a932916c5c63f,
+        0xf4787134f14a7108,
+        0xfba7efcc1e2629e6,
+        0x9b0287c30033872d,
+        0x7fe7cd37d8292591,
+        0xa402891b4a428cbe,
+        0x473f085727dea256,
+    ],
+    [
+        0x3a7933b10ff2e831,
+        0x22ff143fefbbd3b9,
+        0x2e552c66fb8678d2,
+        0xf427ee42d5ee8003,
+        0x1eebdcf751988c45,
+        0xa4262cebab700e78,
+        0xf3b14f368783f74,
+    ],
+    [
+        0x41f45d562a6689b,
+        0xe23f0e34570f037c,
+        0x990e39e880dc1533,
+        0xbd8a072257c813e4,
+        0x547537b7deeece82,
+        0xf0a854abf63d7f2c,
+        0x6ad10c54bbf5f37e,
+    ],
+    [
+        0xbcec7d59b5858e63,
+        0xce27686675aca1b9,
+        0x6dbc1f5cd79cec30,
+        0x35792372c0a1f9ec,
+        0xb5c79c04405b7d56,
+        0x78be8b169f1d27ec,
+        0xee96813ea6366da7,
+    ],
+    [
+        0x82ea92d6830c37ad,
+        0x8839b9de78d0ead5,
+        0x15f84a79fe513c18,
+        0xccd8ee9c81f0fd31,
+        0xc052c7f03a00caf1,
+        0xe685a7c5e2dfefd8,
+        0x5d1e5b7cad442fc8,
+    ],
+    [
+        0x27cc4624e3a8fd6c,
+        0xc2c8aae889151aa7,
+        0xac3dce81ee301ebd,
+        0x27d10cde7611dbf4,
+        0xb1df219237f18451,
+        0xfdd47fef8b61284b,
+        0x529b0e44e4875fc5,
+    ],
+    [
+        0xbfa129745aeb3923,
+        0x490ee8b72fb3248e,
+        0x5daefa90bb67c95e,
+        0xd08e3a551657f581,
+        0x174c60b071a111d9,
+        0xe42f0ffcf7a8a264,
+        0x2dcd1114c8457f34,
+    ],
+    [
+        0x9b19fb3f08515329,
+        0x85dea11f9278c39e,
+        0x5d8dd543eb53b5c5,
+        0x62792122b242fbb0,
+        0x1339a529c030fb61,
+        0xd95fd1afa65a5ded,
+        0x8e6bffc81a339dd0,
+    ],
+    [
+        0xb944c2c819b2038d,
+        0xfc4cf4ef53de6f83,
+        0xbec7b4166eb32958,
+        0x98560aae6f6c1e35,
+        0xeea46b496f45722b,
+        0x74a0e05c8ef8afb6,
+        0x63efa8fb5359a688,
+    ],
+    [
+        0x6e8d2803df3b267a,
+        0xb94287ee66ec3f05,
+        0x4b19fa3db0bb8ae1,
+        0xc72b486a73ddfdb2,
+        0xf87aad46e1a788da,
+        0x5fae4d0974a5384e,
+        0xd7864668291c713d,
+    ],
+    [
+        0xa5ed64048af45d9d,
+        0x7b48feba418052fb,
+        0x7a4a22451f57afc2,
+        0x6ca5a10bb6dde0cf,
+        0x916d9f2c62b33970,
+        0xa7005fe34edfbc94,
+        0x14ed78a60e348f2,
+    ],
+    [
+        0x6d56acb61a9abe8e,
+        0xfdb4477c368483a0,
+        0x352075394f788b74,
+        0x4f626288a601b303,
+        0xfa445e36c5fc1bd8,
+        0x487f76509190057e,
+        0xc0c2ec27c850d93f,
+    ],
+    [
+        0x4f03f6750128b16f,
+        0xc2307541c9970984,
+        0xeba0e38bdfb2f415,
+        0x844490b6c94ff01d,
+        0x97d2f6acf11431c5,
+        0x31e7de47b6d4d6ad,
+        0xfcab3de1e8f50d67,
+    ],
+    [
+        0x6e717510c8e732c4,
+        0xd3e508a9e3855fab,
+        0x18e0ba1d43b19fe8,
+        0x848ad83b6e0d60f,
+        0xc09d282f51da855d,
+        0x82e7f74688a014ed,
+        0xd3fb00a2bfb9f821,
+    ],
+    [
+        0x6167f57448c6559b,
+        0x1bdac82270ba5daf,
+        0x99414798c789a18b,
+        0x5ca3088d1d613904,
+        0xb514e80fa4bd6173,
+        0xc7f333680ad450a,
+        0xba41a35ce36b4fdd,
+    ],
+    [
+        0x4c445bb3cc5dc033,
+        0x2f468b3f6e9ccf43,
+        0x60e78440ab5f7a3f,
+        0xc0a78fbbb4d9c7fe,
+        0x5d5372b2750b6a97,
+        0x9fc2a31931008d5a,
+        0xd236ea6530b29183,
+    ],
+    [
+        0x3d63ec327c84a0bf,
+        0x40dd9339cd2e68ff,
+        0xf0ba798fa143e548,
+        0x3ad5fe46fea96c61,
+        0x5b78b66fef8dea6f,
+        0x686332310340452d,
+        0xa826cb8d2394f95f,
+    ],
+    [
+        0xeab5f4a8d3ec6334,
+        0xa7e1065573315d35,
+        0x3381e6aeaa8906cd,
+        0xbb2fca5617f2c8c8,
+        0xa68ae975813669d,
+        0x21eb53b81a1608d7,
+        0x96a0a8cef0ab1adc,
+    ],
+    [
+        0x1ffad87ddc8ca76a,
+        0xe6b143d6ed7f42a0,
+        0x51fc65a5f15337a2,
+        0x281b10815ee6b36c,
+        0x131460a7e307fb49,
+        0x323ac05bb6f260f,
+        0x86504e553eeeb51b,
+    ],
+    [
+        0xfcc3b1db7bb174a0,
+        0x61bea0ed7dc160e8,
+        0x7882e4ab6c8cb280,
+        0xab914bed4e97e8d2,
+        0x6071c5a779cc97fc,
+        0xe5472aa9a23a7d31,
+        0x628dea5a3164c608,
+    ],
+    [
+        0xcffe79062bb4e7cd,
+        0x9e01b50f95301ea2,
+        0x2a616a3eb9110b32,
+        0x325894413570e9b0,
+        0x1ba9bdb939ee6d9b,
+        0x6a7c5f758b0f8a22,
+        0x5613c8af1381df60,
+    ],
+    [
+        0xa21717e2b3d282ee,
+        0x1ac0595d4f40cda4,
+        0x429a8a47cea11c02,
+        0xe680b930b66396ed,
+        0xd017b03635aece79,
+        0xb5547e06d64d2394,
+        0xcd8ee2c6d0f48658,
+    ],
+    [
+        0x7e4143da4d878be5,
+        0xde837a44b83df910,
+        0xc5096fd7847216c,
+        0xd1ffc6a0e63da251,
+        0xfc0b63ceaef917c0,
+        0xac5020cdbb7db31a,
+        0xf600187306f3f361,
+    ],
+    [
+        0x23b80b8bc4e75405,
+        0x29de38e234555045,
+        0xb58684b753996de8,
+        0x7be7ad6fb131552,
+        0x4c4e822573890072,
+        0x1b1bffc34b326bfe,
+        0xd4ca629a7c07f772,
+    ],
+    [
+        0xa6ae749a1ed10838,
+        0xa9c706bcdcfa891e,
+        0xde2661c018abc48c,
+        0x97ad0eeccf7beed9,
+        0xbf70a97987134ce1,
+        0xb0988e5caa387cb2,
+        0xc762a4b2a2126a63,
+    ],
+    [
+        0xd4b4a81be36638f2,
+        0x92aabc9931541a5a,
+        0x43ab0147e976e855,
+        0xc825b61dceb4e636,
+        0xe0809d70e918ada7,
+        0xc35b1dca85adbea,
+        0xf77b1cd8381a85a9,
+    ],
+    [
+        0x5bab2890f354896d,
+        0xa9d1f89bd9868dd,
+        0x3dcc900485630f0f,
+        0x4f22b5392f0b094e,
+        0xb0f6c85f71e717a6,
+        0x46957a3b2d65a038,
+        0xb083716110d971b4,
+    ],
+    [
+        0x4c0a184632b0499a,
+        0xe9c2e26d5bd7346,
+        0x3e4fd6dfe99c67d2,
+        0x84adabbc4885d2c,
+        0x7f13d1c57c1436ee,
+        0x816789354e143b64,
+        0x3df2247f878cc4a9,
+    ],
+    [
+        0xb45a39714746ec86,
+        0x96f58143107477ac,
+        0x2dd11909380bb2cd,
+        0xd91b8bb8672fd8f5,
+        0x740fccac7b4f751b,
+        0x30dece8f93a98d22,
+        0x4dfc62e32800ede8,
+    ],
+    [
+        0xc4b90839e91abfb2,
+        0xe82891efce710c00,
+        0x4a7ed592a3a82dd4,
+        0x7e607fe600517cd0,
+        0xe532f493827b0237,
+        0x395cda8e4fe45809,
+        0x54c07a612f99b802,
+    ],
+    [
+        0xe81d35c8ed7827fe,
+        0x1a262c26a7b07276,
+        0x207dc323cb840325,
+        0xc248f06ca75157a0,
+        0xd89f50212f3ce653,
+        0xbe6f8171f28a86d0,
+        0x429a914e8bcd778e,
+    ],
+    [
+        0x587c5ee43e034ebd,
+        0x17d007f9bc666c2d,
+        0xaf9c82c94dfcda1f,
+        0x2ca7857ac0ec7867,
+        0x4c7405c3f345264c,
+        0x7a5fcd4b620e0939,
+        0xfac9f4b7677b447f,
+    ],
+    [
+        0xb1ec87f8823040ac,
+        0xa7b6d2e2223d8bb0,
+        0xd41677026942ade4,
+        0xac5aadb9c48b988d,
+        0x850ad5a0d3650159,
+        0xe6f67795d6a04567,
+        0x5f9ba2bbfa36e575,
+    ],
+    [
+        0x7677dff12f92fbd9,
+        0xa1c9bc7d32f35ca1,
+        0xedcd974aa7488258,
+        0xe38fa487026a5a0b,
+        0x3b9b7540bf4802a5,
+        0xf3118d8cf9507c02,
+        0x6e8147b6eab1fe87,
+    ],
+    [
+        0xb69cea6e5a0e28fd,
+        0x3e24ba0592afe2e,
+        0x57dedae1b68ddd05,
+        0x6e5f6aded4efd69f,
+        0x41aaf253fd433093,
+        0x811156ade1688bfb,
+        0xf6be0584b63c47f3,
+    ],
+    [
+        0xf7180ae2e0f325e5,
+        0xd853c886b1187cb8,
+        0x500adca11f8e94de,
+        0xf3c31d687579578f,
+        0x2c3f467e63c5225f,
+        0x5b92432e0e17d7aa,
+        0xac7174bf58f98dec,
+    ],
+    [
+        0xa08d214869e84ccf,
+        0x12011850607c0dc0,
+        0x6ca21154a2193f1e,
+        0x7941a5bb3a6fb8e0,
+        0x31785fae4ea5cbd3,
+        0x1614b3e8ba5368d0,
+        0x7f23439c6eced206,
+    ],
+    [
+        0xcfff666740e2f99f,
+        0x434be89d9bd1b14,
+        0xd12ba6e50904b61f,
+        0xeaac07a345101168,
+        0x3ab8690ec91d6ed0,
+        0xa3e4855a45efe602,
+        0x34c0bbd016958641,
+    ],
+    [
+        0x2fc743551c71634e,
+        0x5a39844593357e83,
+        0x5ae234ee018ecf0b,
+        0x4b0b4fda75dafd7a,
+        0x1dbc42dfe207f246,
+        0x5a0d608f0148d695,
+        0x3fea6c290b1dd217,
+    ],
+    [
+        0x9bf4d77b464c9435,
+        0x3b3e99dd8dbe902d,
+        0xbad6a2d23cb69f3f,
+        0x694a09a072fb55d0,
+        0x554f4bef9ec86462,
+        0x62b67efddc71a65e,
+        0x742a639df5c8e97f,
+    ],
+    [
+        0x5e6b758083214c84,
+        0xcb50a5ebbc51fe29,
+        0x4a8d4e03e37c386a,
+        0xc39c9c99c57f6ca5,
+        0xb42e5c0f6facbc5d,
+        0xb61e56112182b0bd,
+        0x1e47e5eae9d39cc1,
+    ],
+    [
+        0x40548138ef68aa78,
+        0x5ba2ddd6b6414839,
+        0x9b08bb741da55929,
+        0xd1a6d32fe1544ce7,
+        0x2116dc3d7c295fd2,
+        0xda9bf65c101dfb2b,
+        0x28e657eb1e7ec91b,
+    ],
+    [
+        0x7c6b73ef50249070,
+        0x253fa259c87d5773,
+        0x6d3fd907de23f5ee,
+        0x48293bc212b01988,
+        0xbc97363f6593b1c6,
+        0xeb58e37ccf58a370,
+        0x43958d7b7ea6e3ea,
+    ],
+    [
+        0x462a1dc5b9cb1b3b,
+        0x7904069a5fa90c8b,
+        0x6b34023aa308a14d,
+        0x30ca8d4223d71e5c,
+        0x265806e3aa04ad6e,
+        0x99003da804259b22,
+        0x852d586b40d5fdc9,
+    ],
+    [
+        0xb8b156aa6c884b21,
+        0x6320356e9c8220eb,
+        0xdab9fa981067044f,
+        0xd87813c08592d18c,
+        0xb382d881f2c03851,
+        0x984c34fa745f8617,
+        0xe83b77ea9b8cb55,
+    ],
+    [
+        0xc7afcc722488f9e6,
+        0xad6f0985312a64a6,
+        0x97c923f4604fdcf4,
+        0x9ea08f9e3a9dcd7a,
+        0xc43489710d913809,
+        0xd93c98c6519cbb12,
+        0x440d5b0518ebbba7,
+    ],
+    [
+        0x7a45b5b10dc24dbc,
+        0x954a638cab780f0a,
+        0x7f807ade405b7144,
+        0xa06a1ff81f995ca6,
+        0x3f86a498bc53f3fb,
+        0x5754b5a8eaa5f9ae,
+        0xc9525aa3857aeac2,
+    ],
+    [
+        0xefe499d7a567391d,
+        0xcc2e34ce91112f84,
+        0xdb5b75c40a8d6871,
+        0x9baddec72034af7c,
+        0x76093c1151a9c334,
+        0x87e40485b73a54da,
+        0x60016735dade9c79,
+    ],
+    [
+        0xb60d26b461d05e25,
+        0x85e44ec2d4c15b5f,
+        0xa33941259a71f695,
+        0xdb65a2f99f10b462,
+        0x3a8a521b95aa4061,
+        0x926cb95f25d21bf0,
+        0xbd55cebbdeb0995b,
+    ],
+    [
+        0xc15d366b98d92986,
+        0x55bf21d6d14af29c,
+        0x5dc43a61cfcdab12,
+        0xa0f0e3426fd64509,
+        0xc83035f88a0b77ef,
+        0x2763e28e263f5dff,
+        0xc3ec793cc63dd03b,
+    ],
+    [
+        0x9addb551a523df05,
+        0xe60921a3a4aceffd,
+        0x80019fdf97a7e7af,
+        0x45b80999148290a8,
+        0xa28692320b415d8e,
+        0x2bb4caa23d8fc335,
+        0x886ba29327b7d888,
+    ],
+    [
+        0xbd0a37a2ad2465b9,
+        0x27f0829d19cd8cf2,
+        0xf13fdd1a783dea5b,
+        0xd865088b9158ab0d,
+        0x8dce24a60fd399d,
+        0x3c1c449046cf6093,
+        0xc868ae36645aa748,
+    ],
+    [
+        0xe7a7162d930c5056,
+        0xcca8d5eac07d1880,
+        0x98d7152acf6aead9,
+        0xd120fb01533ea3db,
+        0x87ba57b866606483,
+        0xd1ab19f981be7f77,
+        0x575d2739539d89da,
+    ],
+    [
+        0xb9982c5395b09406,
+        0xae9457448497d448,
+        0xccb79281b0518ea2,
+        0x750ad5690f9e2ea4,
+        0x480ea42b71c98703,
+        0x183a69ca58d0194b,
+        0x38d9e1c3079391c3,
+    ],
+    [
+        0xe41766d004eef8fd,
+        0xaadcffc4079be65,
+        0x1762a40c971c5256,
+        0x6c0dca72f7dfd702,
+        0xb72cd78f733b3838,
+        0x98fd59dc49501bb7,
+        0xd64b0dfa56080086,
+    ],
+    [
+        0xa3074a96c88c47de,
+        0x9dbfa7ee06c6d629,
+        0x7a3010c27a54bf5b,
+        0x1a646f82ee865034,
+        0x1cd75ba2ba2e18db,
+        0x7886c70238c13919,
+        0xa375790fbc0d4075,
+    ],
+    [
+        0x881caa3913271394,
+        0x914f7f9497f7ba94,
+        0x76ac64366b6f5660,
+        0x61f8efb455b1df84,
+        0x1c7d6d187eaa165b,
+        0xf4037a9c1b15a760,
+        0xaf6952534fbb79b4,
+    ],
+    [
+        0x77d95a600f824230,
+        0xe5a39f40ea41304f,
+        0x7547f8e9b3d7d3bc,
+        0x2ae7e1bb1086a0c9,
+        0x9db29c86d65743b9,
+        0x3499bfb7b01c552,
+        0x44bc45426b974a12,
+    ],
+    [
+        0x1984adb7bcfec495,
+        0xb07d8992ffb8817e,
+        0xc4d5f5af08537d31,
+        0xffb697b8cfa03374,
+        0x416e215f645d8cb,
+        0xf7a9783188157a80,
+        0xfe0671c77addf1fa,
+    ],
+    [
+        0x66f613698d2263a7,
+        0x2d5e27113b032421,
+        0x50723eb3c45bba59,
+        0x19fa48781ce2b326,
+        0xf34fc200e9ca457c,
+        0xaa074b3b25a4f79,
+        0xb030f76a048d3c4e,
+    ],
+    [
+        0x50cf2a1c284f5a5a,
+        0x2caca2361a3ba2e0,
+        0x3762f19bdf869c75,
+        0xfc34a738dc6b2cf,
+        0x1a72ebef9f3084a1,
+        0xe80baa530c593464,
+        0x745f6c0e1f21e62f,
+    ],
+];
@@ -0,0 +1,184 @@
+//! A tree-hashing mode built on CityHash, for hashing large inputs in
+//! parallel.
+//!
+//! **The values produced by this module are not CityHash values.** Reference
+//! CityHash processes its input sequentially, which limits hashing large
+//! inputs to a single core. This mode splits the input into leaves that can be
+//! hashed independently and combines the leaf digests into a single value. It
+//! is identified by [`ALGORITHM_ID`], which should be stored alongside any
+//! persisted values to prevent them from being confused with reference
+//! CityHash output.
+//!
+//! The algorithm is defined as follows:
+//!
+//! 1. The input is split into leaves of [`LEAF_SIZE`] bytes. The final leaf
+//!    contains the remaining bytes. An empty input consists of a single empty
+//!    leaf.
+//! 2. Each leaf is hashed using `CityHash128`.
+//! 3. The state is initialized to a `uint128` whose `Uint128Low64` is the
+//!    length of the input in bytes and whose `Uint128High64` is `CityHash64`
+//!    of [`ALGORITHM_ID`].
+//! 4. For each leaf in order, the state is replaced with `CityHash128WithSeed`
+//!    of the leaf's digest, using the current state as the seed. The digest is
+//!    encoded as its `Uint128Low64` followed by its `Uint128High64`, each as 8
+//!    little-endian bytes.
+//! 5. The final state is the 128-bit result. The 64-bit result is
+//!    `Hash128to64` of the final state.
+//!
+//! Because leaves are always the same size and are combined in order, the
+//! result does not depend on how many threads hash the leaves. [`hash`] and
+//! `par_hash`, which is enabled by the `rayon` feature, always produce the
+//! same value.
+//!
+//! ```rust
+//! let data = vec![0xAB; 3 * cityhasher::tree::LEAF_SIZE + 1];
+//! let tree: u64 = cityhasher::tree::hash(&data);
+//! let reference: u64 = cityhasher::hash(&data);
+//!
+//! assert_ne!(tree, reference);
+//! ```
+
+use crate::city::{hash_len_16_u64, Input};
+use crate::u128_from_halves;
+
+/// The identifier of the algorithm implemented by this module.
+///
+/// This identifier will change if the output of this module ever changes.
+pub const ALGORITHM_ID: &str = "cityhasher-tree-v1";
+
+/// The number of bytes hashed in each leaf.
+pub const LEAF_SIZE: usize = 1 << 20;
+
+/// Seeds the combining state, separating it from reference CityHash.
+const ALGORITHM_TAG: u64 = crate::hash64(ALGORITHM_ID.as_bytes());
+
+/// Hashes `data` using the CityHash tree-hashing mode, hashing leaves
+/// sequentially.
+///
+/// The result is not a CityHash value. See the [module
+/// documentation](crate::tree) for the algorithm definition.
+///
+/// The exact implementation is decided upon by `T`:
+///
+/// |  `T`  | Result                           |
+/// |-------|----------------------------------|
+/// | `u64` | `Hash128to64` of the final state |
+/// | `u128`| The final state                  |
+///
+/// 128-bit hashes are returned with `Uint128High64` in the upper 64 bits and
+/// `Uint128Low64` in the lower 64 bits.
+///
+/// ```rust
+/// let hello: u64 = cityhasher::tree::hash("hello");
+/// let world: u128 = cityhasher::tree::hash("world");
+///
+/// let hello_reference: u64 = cityhasher::hash("hello");
+/// let world_reference: u128 = cityhasher::hash("world");
+/// assert_ne!(hello, hello_reference);
+/// assert_ne!(world, world_reference);
+/// ```
+#[inline]
+pub fn hash<T>(data: impl AsRef<[u8]>) -> T
+where
+    T: FromTreeHash,
+{
+    let data = data.as_ref();
+    let mut root = Root::new(data.len());
+    if data.is_empty() {
+        root.push(hash_leaf(data));
+    } else {
+        for leaf in data.chunks(LEAF_SIZE) {
+            root.push(hash_leaf(leaf));
+        }
+    }
+    T::from_tree_hash(root.finish())
+}
+
+/// Hashes `data` using the CityHash tree-hashing mode, hashing leaves in
+/// parallel using [rayon].
+///
+/// The result is identical to [`hash`], regardless of the number of threads
+/// used. Inputs no longer than [`LEAF_SIZE`] are hashed on the current thread.
+///
+/// ```rust
+/// let data = vec![0xAB; 3 * cityhasher::tree::LEAF_SIZE + 1];
+/// let parallel: u128 = cityhasher::tree::par_hash(&data);
+/// let sequential: u128 = cityhasher::tree::hash(&data);
+///
+/// assert_eq!(parallel, sequential);
+/// ```
+///
+/// [rayon]: https://docs.rs/rayon
+#[cfg(feature = "rayon")]
+pub fn par_hash<T>(data: impl AsRef<[u8]>) -> T
+where
+    T: FromTreeHash,
+{
+    use alloc::vec::Vec;
+
+    use rayon::prelude::*;
+
+    let data = data.as_ref();
+    if data.len() <= LEAF_SIZE {
+        return hash(data);
+    }
+
+    let leaves: Vec<(u64, u64)> = data.par_chunks(LEAF_SIZE).map(hash_leaf).collect();
+    let mut root = Root::new(data.len());
+    for leaf in leaves {
+        root.push(leaf);
+    }
+    T::from_tree_hash(root.finish())
+}
+
+/// A type that can be produced by the CityHash tree-hashing mode.
+pub trait FromTreeHash: sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {
+        fn from_tree_hash(root: (u64, u64)) -> Self;
+    }
+}
+
+impl FromTreeHash for u64 {}
+
+impl sealed::Sealed for u64 {
+    #[inline]
+    fn from_tree_hash((low, high): (u64, u64)) -> Self {
+        hash_len_16_u64(low, high)
+    }
+}
+
+impl FromTreeHash for u128 {}
+
+impl sealed::Sealed for u128 {
+    #[inline]
+    fn from_tree_hash(root: (u64, u64)) -> Self {
+        u128_from_halves(root)
+    }
+}
+
+#[inline]
+fn hash_leaf(leaf: &[u8]) -> (u64, u64) {
+    Input(leaf).hash128()
+}
+
+/// The state that leaf digests are combined into.
+struct Root((u64, u64));
+
+impl Root {
+    fn new(len: usize) -> Self {
+        Self((len as u64, ALGORITHM_TAG))
+    }
+
+    fn push(&mut self, (low, high): (u64, u64)) {
+        let mut digest = [0; 16];
+        digest[..8].copy_from_slice(&low.to_le_bytes());
+        digest[8..].copy_from_slice(&high.to_le_bytes());
+        self.0 = Input(&digest).hash128_with_seed(self.0);
+    }
+
+    fn finish(self) -> (u64, u64) {
+        self.0
+    }
+}
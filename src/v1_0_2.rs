@@ -0,0 +1,405 @@
+//! CityHash v1.0.2, the algorithm version used by ClickHouse.
+//!
+//! CityHash v1.0.3 and v1.1 changed the output of `CityHash64` and
+//! `CityHash128`, but data produced by v1.0.2 remains in use. Most notably,
+//! ClickHouse's `cityHash64` function and the `CityHash128` checksums of its
+//! compressed blocks are computed using v1.0.2. The functions in this module
+//! reproduce the v1.0.2 reference release exactly, allowing those values to
+//! be computed and validated.
+//!
+//! v1.0.2 predates `CityHash32`, so only 64-bit and 128-bit outputs are
+//! available.
+//!
+//! ```rust
+//! let v1_0_2: u64 = cityhasher::v1_0_2::hash("hello");
+//! let v1_1: u64 = cityhasher::hash("hello");
+//!
+//! assert_eq!(v1_0_2, 0x23c7ada5f323c8df);
+//! assert_ne!(v1_0_2, v1_1);
+//! ```
+
+use core::mem;
+
+use crate::city::{hash_len_16_u64, rotate64, shift_mix, Input, K0, K1, K2};
+use crate::{u128_from_halves, u128_to_halves};
+
+const K3: u64 = 0xc949d7c7509e6557;
+
+/// Hashes `data` using version 1.0.2 of the [CityHash][cityhash] algorithm.
+///
+/// The exact implementation is decided upon by `T`:
+///
+/// |  `T`  | C++ Function Equivalent |
+/// |-------|-------------------------|
+/// | `u64` | `CityHash64`            |
+/// | `u128`| `CityHash128`           |
+///
+/// 128-bit hashes are returned with `Uint128High64` in the upper 64 bits and
+/// `Uint128Low64` in the lower 64 bits.
+///
+/// ```rust
+/// let hello: u64 = cityhasher::v1_0_2::hash("hello");
+/// let world: u128 = cityhasher::v1_0_2::hash("world");
+///
+/// let hello_v1_1: u64 = cityhasher::hash("hello");
+/// let world_v1_1: u128 = cityhasher::hash("world");
+/// assert_ne!(hello, hello_v1_1);
+/// assert_ne!(world, world_v1_1);
+/// ```
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash<T>(data: impl AsRef<[u8]>) -> T
+where
+    T: FromCityHash,
+{
+    T::from_city_hash(data.as_ref())
+}
+
+/// Hashes `data` with a seed value, using version 1.0.2 of the
+/// [CityHash][cityhash] algorithm.
+///
+/// The exact implementation is decided upon by `T`:
+///
+/// |  `T`  | C++ Function Equivalent |
+/// |-------|-------------------------|
+/// | `u64` | `CityHash64WithSeed`    |
+/// | `u128`| `CityHash128WithSeed`   |
+///
+/// 128-bit seeds are split into the reference `uint128` with the upper 64 bits
+/// as `Uint128High64` and the lower 64 bits as `Uint128Low64`.
+///
+/// ```rust
+/// let hello: u64 = cityhasher::v1_0_2::hash("hello");
+/// let hello_with_seed: u64 = cityhasher::v1_0_2::hash_with_seed("hello", 1);
+///
+/// assert_ne!(hello, hello_with_seed);
+/// ```
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_with_seed<T>(data: impl AsRef<[u8]>, seed: T) -> T
+where
+    T: FromSeededCityHash,
+{
+    T::from_city_hash_with_seed(data.as_ref(), seed)
+}
+
+/// Hashes `data` with two seed values, using version 1.0.2 of the
+/// [CityHash][cityhash] algorithm.
+///
+/// This function is equivalent to the C++ function `CityHash64WithSeeds`.
+/// [`hash_with_seed`] is equivalent to calling this function with a `seed0`
+/// of `0x9ae16a3b2f90404f`.
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_with_seeds(data: impl AsRef<[u8]>, seed0: u64, seed1: u64) -> u64 {
+    hash_len_16_u64(hash64(&Input(data.as_ref())).wrapping_sub(seed0), seed1)
+}
+
+/// A type that can be produced by version 1.0.2 of the CityHash algorithm.
+pub trait FromCityHash: sealed::Sealed {}
+
+/// A type that can be produced by version 1.0.2 of the CityHash algorithm
+/// using a seeded input.
+pub trait FromSeededCityHash: sealed::SealedSeeded {}
+
+mod sealed {
+    pub trait Sealed {
+        fn from_city_hash(data: &[u8]) -> Self;
+    }
+
+    pub trait SealedSeeded {
+        fn from_city_hash_with_seed(data: &[u8], seed: Self) -> Self;
+    }
+}
+
+impl FromCityHash for u64 {}
+
+impl sealed::Sealed for u64 {
+    #[inline]
+    fn from_city_hash(data: &[u8]) -> Self {
+        hash64(&Input(data))
+    }
+}
+
+impl FromSeededCityHash for u64 {}
+
+impl sealed::SealedSeeded for u64 {
+    #[inline]
+    fn from_city_hash_with_seed(data: &[u8], seed: Self) -> Self {
+        hash_with_seeds(data, K2, seed)
+    }
+}
+
+impl FromCityHash for u128 {}
+
+impl sealed::Sealed for u128 {
+    #[inline]
+    fn from_city_hash(data: &[u8]) -> Self {
+        u128_from_halves(hash128(&Input(data)))
+    }
+}
+
+impl FromSeededCityHash for u128 {}
+
+impl sealed::SealedSeeded for u128 {
+    #[inline]
+    fn from_city_hash_with_seed(data: &[u8], seed: Self) -> Self {
+        u128_from_halves(hash128_with_seed(&Input(data), u128_to_halves(seed)))
+    }
+}
+
+fn hash64(input: &Input<'_>) -> u64 {
+    let len = input.len();
+    if len <= 16 {
+        return hash64_len_0_to_16(input);
+    } else if len <= 32 {
+        return hash64_len_17_to_32(input);
+    } else if len <= 64 {
+        return hash64_len_33_to_64(input);
+    }
+
+    // For strings over 64 bytes we hash the end first, and then as we loop we
+    // keep 56 bytes of state: v, w, x, y, and z.
+    let mut x = input.fetch64(0);
+    let mut y = input.fetch64(len - 16) ^ K1;
+    let mut z = input.fetch64(len - 56) ^ K0;
+    let mut v = input.weak_hash_len_32_with_seeds(len - 64, len as u64, y);
+    let mut w = input.weak_hash_len_32_with_seeds(len - 32, (len as u64).wrapping_mul(K1), K0);
+    z = z.wrapping_add(shift_mix(v.1).wrapping_mul(K1));
+    x = rotate64(z.wrapping_add(x), 39).wrapping_mul(K1);
+    y = rotate64(y, 33).wrapping_mul(K1);
+
+    // Decrease len to the nearest multiple of 64, and operate on 64-byte chunks.
+    let end = (len - 1) & !63;
+    let mut offset = 0;
+    while offset < end {
+        (x, y, z, v, w) = long_chunk(input, offset, (x, y, z, v, w));
+        offset += 64;
+    }
+
+    hash_len_16_u64(
+        hash_len_16_u64(v.0, w.0)
+            .wrapping_add(shift_mix(y).wrapping_mul(K1))
+            .wrapping_add(z),
+        hash_len_16_u64(v.1, w.1).wrapping_add(x),
+    )
+}
+
+type LongState = (u64, u64, u64, (u64, u64), (u64, u64));
+
+/// Mixes the 64 bytes at `offset` into the state used for long inputs by both
+/// [`hash64`] and [`hash128_with_seed`].
+#[inline]
+fn long_chunk(
+    input: &Input<'_>,
+    offset: usize,
+    (mut x, mut y, mut z, v, w): LongState,
+) -> LongState {
+    x = rotate64(
+        x.wrapping_add(y)
+            .wrapping_add(v.0)
+            .wrapping_add(input.fetch64(offset + 16)),
+        37,
+    )
+    .wrapping_mul(K1);
+    y = rotate64(
+        y.wrapping_add(v.1).wrapping_add(input.fetch64(offset + 48)),
+        42,
+    )
+    .wrapping_mul(K1);
+    x ^= w.1;
+    y ^= v.0;
+    z = rotate64(z ^ w.0, 33);
+    let v = input.weak_hash_len_32_with_seeds(offset, v.1.wrapping_mul(K1), x.wrapping_add(w.0));
+    let w = input.weak_hash_len_32_with_seeds(offset + 32, z.wrapping_add(w.1), y);
+    mem::swap(&mut z, &mut x);
+    (x, y, z, v, w)
+}
+
+fn hash64_len_0_to_16(input: &Input<'_>) -> u64 {
+    let len = input.len();
+    if len > 8 {
+        let a = input.fetch64(0);
+        let b = input.fetch64(len - 8);
+        hash_len_16_u64(a, rotate64(b.wrapping_add(len as u64), len as u32)) ^ b
+    } else if len >= 4 {
+        let a = u64::from(input.fetch32(0));
+        hash_len_16_u64(
+            (len as u64).wrapping_add(a << 3),
+            u64::from(input.fetch32(len - 4)),
+        )
+    } else if len > 0 {
+        let a = input.0[0];
+        let b = input.0[len >> 1];
+        let c = input.0[len - 1];
+        let y = u32::from(a).wrapping_add(u32::from(b) << 8);
+        let z = (len as u32).wrapping_add(u32::from(c) << 2);
+        shift_mix(u64::from(y).wrapping_mul(K2) ^ u64::from(z).wrapping_mul(K3)).wrapping_mul(K2)
+    } else {
+        K2
+    }
+}
+
+fn hash64_len_17_to_32(input: &Input<'_>) -> u64 {
+    let len = input.len();
+    let a = input.fetch64(0).wrapping_mul(K1);
+    let b = input.fetch64(8);
+    let c = input.fetch64(len - 8).wrapping_mul(K2);
+    let d = input.fetch64(len - 16).wrapping_mul(K0);
+    hash_len_16_u64(
+        rotate64(a.wrapping_sub(b), 43)
+            .wrapping_add(rotate64(c, 30))
+            .wrapping_add(d),
+        a.wrapping_add(rotate64(b ^ K3, 20))
+            .wrapping_sub(c)
+            .wrapping_add(len as u64),
+    )
+}
+
+fn hash64_len_33_to_64(input: &Input<'_>) -> u64 {
+    let len = input.len();
+    let mut z = input.fetch64(24);
+    let mut a = input.fetch64(0).wrapping_add(
+        (len as u64)
+            .wrapping_add(input.fetch64(len - 16))
+            .wrapping_mul(K0),
+    );
+    let mut b = rotate64(a.wrapping_add(z), 52);
+    let mut c = rotate64(a, 37);
+    a = a.wrapping_add(input.fetch64(8));
+    c = c.wrapping_add(rotate64(a, 7));
+    a = a.wrapping_add(input.fetch64(16));
+    let vf = a.wrapping_add(z);
+    let vs = b.wrapping_add(rotate64(a, 31)).wrapping_add(c);
+    a = input.fetch64(16).wrapping_add(input.fetch64(len - 32));
+    z = input.fetch64(len - 8);
+    b = rotate64(a.wrapping_add(z), 52);
+    c = rotate64(a, 37);
+    a = a.wrapping_add(input.fetch64(len - 24));
+    c = c.wrapping_add(rotate64(a, 7));
+    a = a.wrapping_add(input.fetch64(len - 16));
+    let wf = a.wrapping_add(z);
+    let ws = b.wrapping_add(rotate64(a, 31)).wrapping_add(c);
+    let r = shift_mix(
+        vf.wrapping_add(ws)
+            .wrapping_mul(K2)
+            .wrapping_add(wf.wrapping_add(vs).wrapping_mul(K0)),
+    );
+    shift_mix(r.wrapping_mul(K0).wrapping_add(vs)).wrapping_mul(K2)
+}
+
+fn city_murmur(input: &Input<'_>, (mut a, mut b): (u64, u64)) -> (u64, u64) {
+    let len = input.len();
+    let mut c;
+    let mut d;
+    if len <= 16 {
+        a = shift_mix(a.wrapping_mul(K1)).wrapping_mul(K1);
+        c = b.wrapping_mul(K1).wrapping_add(hash64_len_0_to_16(input));
+        d = shift_mix(a.wrapping_add(if len >= 8 { input.fetch64(0) } else { c }));
+    } else {
+        c = hash_len_16_u64(input.fetch64(len - 8).wrapping_add(K1), a);
+        d = hash_len_16_u64(
+            b.wrapping_add(len as u64),
+            c.wrapping_add(input.fetch64(len - 16)),
+        );
+        a = a.wrapping_add(d);
+        let mut offset = 0;
+        while offset + 16 < len {
+            a ^= shift_mix(input.fetch64(offset).wrapping_mul(K1)).wrapping_mul(K1);
+            a = a.wrapping_mul(K1);
+            b ^= a;
+            c ^= shift_mix(input.fetch64(offset + 8).wrapping_mul(K1)).wrapping_mul(K1);
+            c = c.wrapping_mul(K1);
+            d ^= c;
+            offset += 16;
+        }
+    }
+    let a = hash_len_16_u64(a, c);
+    let b = hash_len_16_u64(d, b);
+    (a ^ b, hash_len_16_u64(b, a))
+}
+
+fn hash128(input: &Input<'_>) -> (u64, u64) {
+    let len = input.len();
+    if len >= 16 {
+        hash128_with_seed(
+            &Input(&input.0[16..]),
+            (input.fetch64(0) ^ K3, input.fetch64(8)),
+        )
+    } else if len >= 8 {
+        hash128_with_seed(
+            &Input(&[]),
+            (
+                input.fetch64(0) ^ (len as u64).wrapping_mul(K0),
+                input.fetch64(len - 8) ^ K1,
+            ),
+        )
+    } else {
+        hash128_with_seed(input, (K0, K1))
+    }
+}
+
+fn hash128_with_seed(input: &Input<'_>, (mut x, mut y): (u64, u64)) -> (u64, u64) {
+    let len = input.len();
+    if len < 128 {
+        return city_murmur(input, (x, y));
+    }
+
+    // We expect len >= 128 to be the common case.  Keep 56 bytes of state:
+    // v, w, x, y, and z.
+    let mut z = (len as u64).wrapping_mul(K1);
+    let v0 = rotate64(y ^ K1, 49)
+        .wrapping_mul(K1)
+        .wrapping_add(input.fetch64(0));
+    let mut v = (
+        v0,
+        rotate64(v0, 42)
+            .wrapping_mul(K1)
+            .wrapping_add(input.fetch64(8)),
+    );
+    let mut w = (
+        rotate64(y.wrapping_add(z), 35)
+            .wrapping_mul(K1)
+            .wrapping_add(x),
+        rotate64(x.wrapping_add(input.fetch64(88)), 53).wrapping_mul(K1),
+    );
+
+    // This is the same inner loop as hash64(), manually unrolled.
+    let mut offset = 0;
+    let mut len = len;
+    while len >= 128 {
+        (x, y, z, v, w) = long_chunk(input, offset, (x, y, z, v, w));
+        (x, y, z, v, w) = long_chunk(input, offset + 64, (x, y, z, v, w));
+        offset += 128;
+        len -= 128;
+    }
+    y = y.wrapping_add(rotate64(w.0, 37).wrapping_mul(K0).wrapping_add(z));
+    x = x.wrapping_add(rotate64(v.0.wrapping_add(z), 49).wrapping_mul(K0));
+
+    // If 0 < len < 128, hash up to 4 chunks of 32 bytes each from the end.
+    let mut tail_done = 0;
+    while tail_done < len {
+        tail_done += 32;
+        let tail = offset + len - tail_done;
+        y = rotate64(y.wrapping_sub(x), 42)
+            .wrapping_mul(K0)
+            .wrapping_add(v.1);
+        w.0 = w.0.wrapping_add(input.fetch64(tail + 16));
+        x = rotate64(x, 49).wrapping_mul(K0).wrapping_add(w.0);
+        w.0 = w.0.wrapping_add(v.0);
+        v = input.weak_hash_len_32_with_seeds(tail, v.0, v.1);
+    }
+
+    // At this point our 48 bytes of state should contain more than enough
+    // information for a strong 128-bit hash.  We use two different
+    // 48-byte-to-8-byte hashes to get a 16-byte final result.
+    x = hash_len_16_u64(x, v.0);
+    y = hash_len_16_u64(y, w.0);
+    (
+        hash_len_16_u64(x.wrapping_add(v.1), w.1).wrapping_add(y),
+        hash_len_16_u64(x.wrapping_add(w.1), y.wrapping_add(v.1)),
+    )
+}
@@ -0,0 +1,171 @@
+use core::hash::{Hash, Hasher};
+
+use crate::city::{hash_len_16_u64, K2};
+use crate::vectored::{Segmented, Segments};
+
+/// The bytes written by a value's [`Hash`] implementation.
+struct ValueSegments<'a, T: ?Sized>(&'a T);
+
+impl<T> Segments for ValueSegments<'_, T>
+where
+    T: Hash + ?Sized,
+{
+    fn for_each_segment<F>(&self, f: F)
+    where
+        F: FnMut(&[u8]),
+    {
+        self.0.hash(&mut SegmentHasher(f));
+    }
+}
+
+/// A [`Hasher`] that passes the encoding of each call to `F`.
+struct SegmentHasher<F>(F);
+
+impl<F> Hasher for SegmentHasher<F>
+where
+    F: FnMut(&[u8]),
+{
+    fn finish(&self) -> u64 {
+        // The hash isn't known until the whole value has been written, so there
+        // is nothing meaningful to report midway through.
+        0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        (self.0)(bytes);
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        (self.0)(&[i]);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        (self.0)(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        (self.0)(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        (self.0)(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        (self.0)(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.write_u8(i as u8);
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as u64);
+    }
+}
+
+/// Hashes `value` using the `CityHash64` function of the
+/// [CityHash][cityhash] algorithm.
+///
+/// Unlike hashing with [`CityHasher`](crate::CityHasher), the result is
+/// identical to [`hash`](crate::hash) of the bytes that `value`'s [`Hash`]
+/// implementation writes, concatenated in the order they are written. Each
+/// [`Hasher`] call is encoded as:
+///
+/// | Call                            | Encoding                                   |
+/// |---------------------------------|--------------------------------------------|
+/// | `write(bytes)`                  | `bytes`, unchanged                         |
+/// | `write_u8` through `write_u128` | the integer's little-endian bytes          |
+/// | `write_i8` through `write_i128` | the two's complement little-endian bytes   |
+/// | `write_usize`, `write_isize`    | the integer's 8 little-endian bytes        |
+///
+/// Pointer-sized integers are always encoded using 8 bytes, so lengths hash
+/// the same on 32-bit and 64-bit targets. The remaining [`Hasher`] methods use
+/// their default implementations, which the standard library defines in terms
+/// of the calls above. For example, a `str` is written as its bytes followed
+/// by a `0xff` byte, and the length of a slice is written using
+/// `write_usize`.
+///
+/// The result is reproducible for a given sequence of calls, which is
+/// determined by `value`'s [`Hash`] implementation. Note that the standard
+/// library writes slices of integers, such as `[u32]` and `Vec<u32>`, as their
+/// native-endian bytes in a single `write` call, so hashes of those types
+/// differ between little-endian and big-endian targets.
+///
+/// The input is never buffered. Instead, values that write more than 64 bytes
+/// are hashed twice: once to find the length and the final bytes of the input,
+/// which CityHash reads first, and once to hash the remaining input. `value`'s
+/// [`Hash`] implementation must write the same bytes each time it is called.
+///
+/// ```rust
+/// let hash = cityhasher::hash_value(&(1_u32, "hello"));
+///
+/// let mut bytes = 1_u32.to_le_bytes().to_vec();
+/// bytes.extend_from_slice(b"hello\xff");
+/// let expected: u64 = cityhasher::hash(&bytes);
+///
+/// assert_eq!(hash, expected);
+/// ```
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_value<T>(value: &T) -> u64
+where
+    T: Hash + ?Sized,
+{
+    Segmented::<64, _>::new(ValueSegments(value)).hash64()
+}
+
+/// Hashes `value` with a seed, using the `CityHash64WithSeed` function of the
+/// [CityHash][cityhash] algorithm.
+///
+/// The result is identical to [`hash_with_seed`](crate::hash_with_seed) of the
+/// bytes described by [`hash_value`].
+///
+/// ```rust
+/// let hash = cityhasher::hash_value_with_seed(&(1_u32, "hello"), 1);
+///
+/// assert_ne!(hash, cityhasher::hash_value(&(1_u32, "hello")));
+/// ```
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_value_with_seed<T>(value: &T, seed: u64) -> u64
+where
+    T: Hash + ?Sized,
+{
+    hash_len_16_u64(hash_value(value).wrapping_sub(K2), seed)
+}
@@ -0,0 +1,225 @@
+use crate::city::{Hash32Long, Hash64Long, Input};
+
+/// Input split across segments, which can be produced more than once.
+pub(crate) trait Segments {
+    /// Invokes `f` with each segment of the input, in order. Every call must
+    /// produce the same input.
+    fn for_each_segment<F>(&self, f: F)
+    where
+        F: FnMut(&[u8]);
+}
+
+/// The segments produced by a cloneable iterator.
+struct IterSegments<I>(I);
+
+impl<'a, I> Segments for IterSegments<I>
+where
+    I: Iterator<Item = &'a [u8]> + Clone,
+{
+    fn for_each_segment<F>(&self, mut f: F)
+    where
+        F: FnMut(&[u8]),
+    {
+        for segment in self.0.clone() {
+            f(segment);
+        }
+    }
+}
+
+/// Hashes the concatenation of `segments` without concatenating them.
+///
+/// `segments` is produced once to find the total length and the final bytes
+/// of the input, which CityHash reads before the rest of the input. Inputs
+/// longer than `TAIL` bytes are then hashed by producing `segments` a second
+/// time in chunks of `CHUNK` bytes. Chunks are read directly from the
+/// segments, and are only copied when they span a segment boundary.
+pub(crate) struct Segmented<const TAIL: usize, S> {
+    segments: S,
+    len: usize,
+    tail: [u8; TAIL],
+}
+
+impl<const TAIL: usize, S> Segmented<TAIL, S>
+where
+    S: Segments,
+{
+    pub(crate) fn new(segments: S) -> Self {
+        let mut len = 0;
+        let mut tail = [0; TAIL];
+        segments.for_each_segment(|segment| {
+            len += segment.len();
+            if segment.len() >= TAIL {
+                tail.copy_from_slice(&segment[segment.len() - TAIL..]);
+            } else {
+                tail.copy_within(segment.len().., 0);
+                tail[TAIL - segment.len()..].copy_from_slice(segment);
+            }
+        });
+        Self {
+            segments,
+            len,
+            tail,
+        }
+    }
+
+    /// Returns the input if it is no longer than `TAIL` bytes.
+    fn short_input(&self) -> Option<Input<'_>> {
+        (self.len <= TAIL).then(|| Input(&self.tail[TAIL - self.len..]))
+    }
+
+    /// Invokes `f` with each `CHUNK`-byte chunk of the input, excluding the
+    /// final byte.
+    fn for_each_chunk<const CHUNK: usize, F>(&self, mut f: F)
+    where
+        F: FnMut(&Input<'_>),
+    {
+        let mut remaining = (self.len - 1) / CHUNK;
+        let mut buffer = [0; CHUNK];
+        let mut buffered = 0;
+        self.segments.for_each_segment(|mut segment| {
+            if remaining == 0 {
+                return;
+            }
+            if buffered > 0 {
+                let copied = segment.len().min(CHUNK - buffered);
+                buffer[buffered..buffered + copied].copy_from_slice(&segment[..copied]);
+                buffered += copied;
+                segment = &segment[copied..];
+                if buffered < CHUNK {
+                    return;
+                }
+                f(&Input(&buffer));
+                buffered = 0;
+                remaining -= 1;
+            }
+
+            let mut chunks = segment.chunks_exact(CHUNK);
+            for chunk in chunks.by_ref().take(remaining) {
+                f(&Input(chunk));
+                remaining -= 1;
+            }
+            let rest = chunks.remainder();
+            if remaining > 0 {
+                buffer[..rest.len()].copy_from_slice(rest);
+                buffered = rest.len();
+            }
+        });
+    }
+
+    pub(crate) fn hash32(&self) -> u32 {
+        if let Some(input) = self.short_input() {
+            return input.hash32();
+        }
+        let mut state = Hash32Long::new(&Input(&self.tail), self.len);
+        self.for_each_chunk::<20, _>(|chunk| state.update(chunk));
+        state.finish()
+    }
+
+    pub(crate) fn hash64(&self) -> u64 {
+        if let Some(input) = self.short_input() {
+            return input.hash64();
+        }
+        let mut state = None;
+        self.for_each_chunk::<64, _>(|chunk| {
+            state
+                .get_or_insert_with(|| {
+                    Hash64Long::new(&Input(&self.tail), self.len as u64, chunk.fetch64(0))
+                })
+                .update(chunk);
+        });
+        state.expect("inputs over 64 bytes have a chunk").finish()
+    }
+}
+
+/// A type that can be produced by the CityHash algorithm from input split
+/// across multiple segments.
+pub trait FromCityHashVectored: sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {
+        fn from_city_hash_vectored<'a>(segments: impl Iterator<Item = &'a [u8]> + Clone) -> Self;
+    }
+}
+
+impl FromCityHashVectored for u32 {}
+
+impl sealed::Sealed for u32 {
+    fn from_city_hash_vectored<'a>(segments: impl Iterator<Item = &'a [u8]> + Clone) -> Self {
+        Segmented::<24, _>::new(IterSegments(segments)).hash32()
+    }
+}
+
+impl FromCityHashVectored for u64 {}
+
+impl sealed::Sealed for u64 {
+    fn from_city_hash_vectored<'a>(segments: impl Iterator<Item = &'a [u8]> + Clone) -> Self {
+        Segmented::<64, _>::new(IterSegments(segments)).hash64()
+    }
+}
+
+impl FromCityHashVectored for crate::Fingerprint64 {}
+
+impl sealed::Sealed for crate::Fingerprint64 {
+    fn from_city_hash_vectored<'a>(segments: impl Iterator<Item = &'a [u8]> + Clone) -> Self {
+        Self::new(u64::from_city_hash_vectored(segments))
+    }
+}
+
+/// Hashes the concatenation of `segments` using the [CityHash][cityhash]
+/// algorithm, without copying the segments into a single buffer.
+///
+/// The result is identical to [`hash`](crate::hash) of the concatenated
+/// segments. The exact implementation is decided upon by `T`:
+///
+/// |         `T`          | C++ Function Equivalent |
+/// |----------------------|-------------------------|
+/// | `u32`                | `CityHash32`            |
+/// | `u64`                | `CityHash64`            |
+/// | [`Fingerprint64`]    | `CityHash64`            |
+///
+/// ```rust
+/// let vectored: u64 = cityhasher::hash_vectored(&["hello, ".as_bytes(), b"world"]);
+/// let hash: u64 = cityhasher::hash("hello, world");
+///
+/// assert_eq!(vectored, hash);
+/// ```
+///
+/// [cityhash]: https://github.com/google/cityhash
+/// [`Fingerprint64`]: crate::Fingerprint64
+#[inline]
+pub fn hash_vectored<T>(segments: &[&[u8]]) -> T
+where
+    T: FromCityHashVectored,
+{
+    T::from_city_hash_vectored(segments.iter().copied())
+}
+
+/// Hashes the concatenation of the segments produced by `segments` using the
+/// [CityHash][cityhash] algorithm, without copying the segments into a single
+/// buffer.
+///
+/// The result is identical to [`hash`](crate::hash) of the concatenated
+/// segments. The iterator is cloned and iterated twice: once to find the length
+/// and the final bytes of the input, which CityHash reads first, and once to
+/// hash the remaining input.
+///
+/// ```rust
+/// use std::io::IoSlice;
+///
+/// let slices = [IoSlice::new(b"hello, "), IoSlice::new(b"world")];
+/// let vectored: u32 = cityhasher::hash_vectored_iter(slices.iter().map(|slice| &**slice));
+/// let hash: u32 = cityhasher::hash("hello, world");
+///
+/// assert_eq!(vectored, hash);
+/// ```
+///
+/// [cityhash]: https://github.com/google/cityhash
+#[inline]
+pub fn hash_vectored_iter<'a, T, I>(segments: I) -> T
+where
+    T: FromCityHashVectored,
+    I: IntoIterator<Item = &'a [u8]>,
+    I::IntoIter: Clone,
+{
+    T::from_city_hash_vectored(segments.into_iter())
+}